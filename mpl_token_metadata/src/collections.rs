@@ -0,0 +1,271 @@
+use substreams::errors::Error;
+use substreams::store::{
+    DeltaString, Deltas, StoreAdd, StoreAddInt64, StoreGet, StoreGetInt64, StoreGetString, StoreNew, StoreSet, StoreSetInt64,
+    StoreSetString,
+};
+
+use crate::pb::mpl_token_metadata::*;
+use crate::pb::mpl_token_metadata::mpl_token_metadata_event::Event;
+use crate::token_metadata::get_metadata_mint;
+
+/// Collection verifications and size changes in the block.
+#[substreams::handlers::map]
+fn collection_events(events: MplTokenMetadataBlockEvents, metadata_mints: StoreGetString) -> Result<CollectionBlockEvents, Error> {
+    let mut collection_events: Vec<CollectionEvent> = Vec::new();
+    for transaction in events.transactions.iter() {
+        for event in transaction.events.iter() {
+            if let Some(mut collection_event) = get_collection_event(event) {
+                collection_event.signature = transaction.signature.clone();
+                collection_event.item_mint = collection_event
+                    .item_metadata
+                    .as_ref()
+                    .and_then(|x| get_metadata_mint(&metadata_mints, x));
+                collection_events.push(collection_event);
+            }
+        }
+    }
+    Ok(CollectionBlockEvents { events: collection_events })
+}
+
+/// Verified collection mint of every item, keyed by the item metadata account, or an empty string once
+/// it's unverified or burned.
+#[substreams::handlers::store]
+fn store_collection_items(events: MplTokenMetadataBlockEvents, store: StoreSetString) {
+    for transaction in events.transactions.iter() {
+        for event in transaction.events.iter() {
+            if let Some((item_metadata, collection_mint)) = get_item_collection_change(event) {
+                store.set(0, &item_metadata, &collection_mint.unwrap_or_default());
+            }
+        }
+    }
+}
+
+/// Number of verified items of every collection mint. Use [`get_collection_item_count`] to read it along
+/// with the collection size.
+///
+/// Verifications are counted from their changes in `store_collection_items`, since the program accepts
+/// verifying an item twice. Items verified before the start block are only counted once re-verified,
+/// unless the collection size is set afterwards.
+#[substreams::handlers::store]
+fn store_collection_item_counts(items: Deltas<DeltaString>, store: StoreAddInt64) {
+    for delta in items.deltas.iter() {
+        if delta.old_value == delta.new_value {
+            continue;
+        }
+        if !delta.old_value.is_empty() {
+            store.add(0, &delta.old_value, -1);
+        }
+        if !delta.new_value.is_empty() {
+            store.add(0, &delta.new_value, 1);
+        }
+    }
+}
+
+/// Difference between the size last set on every collection mint, with `SetCollectionSize` or its
+/// Bubblegum counterpart, and its count in `store_collection_item_counts` at that time. It seeds the
+/// item count of collections whose items were verified before the start block.
+#[substreams::handlers::store]
+fn store_collection_size_offsets(events: MplTokenMetadataBlockEvents, counts: StoreGetInt64, store: StoreSetInt64) {
+    for transaction in events.transactions.iter() {
+        for event in transaction.events.iter() {
+            if let Some(CollectionEvent { collection_mint, size: Some(size), .. }) = get_collection_event(event) {
+                let count = counts.get_last(&collection_mint).unwrap_or(0);
+                store.set(0, &collection_mint, &(size as i64 - count));
+            }
+        }
+    }
+}
+
+/// Reads the verified collection mint of an item metadata account from `store_collection_items`.
+pub fn get_item_collection(store: &StoreGetString, metadata: &str) -> Option<String> {
+    store.get_last(metadata).filter(|x| !x.is_empty())
+}
+
+/// Reads the number of verified items of a collection mint from `store_collection_item_counts` and
+/// `store_collection_size_offsets`.
+pub fn get_collection_item_count(counts: &StoreGetInt64, size_offsets: &StoreGetInt64, collection_mint: &str) -> i64 {
+    counts.get_last(collection_mint).unwrap_or(0) + size_offsets.get_last(collection_mint).unwrap_or(0)
+}
+
+/// Item metadata account whose verified collection the event changes, with the new collection mint or
+/// `None` when it's unverified or the item burned.
+fn get_item_collection_change(event: &MplTokenMetadataEvent) -> Option<(String, Option<String>)> {
+    match &event.event {
+        // Burning an NFT closes its metadata, whether or not its collection is sized.
+        Some(Event::Burn(BurnEvent { metadata, edition: Some(_), .. }))
+        | Some(Event::Burn(BurnEvent { metadata, collection_metadata: Some(_), .. }))
+        | Some(Event::BurnNft(BurnNftEvent { metadata, .. }))
+        | Some(Event::BurnEditionNft(BurnEditionNftEvent { metadata, .. })) => Some((metadata.clone(), None)),
+        _ => {
+            let collection_event = get_collection_event(event)?;
+            let kind = collection_event.kind();
+            let item_metadata = collection_event.item_metadata?;
+            match kind {
+                CollectionEventKind::ItemVerified => Some((item_metadata, Some(collection_event.collection_mint))),
+                CollectionEventKind::ItemUnverified => Some((item_metadata, None)),
+                _ => None,
+            }
+        },
+    }
+}
+
+fn get_collection_event(event: &MplTokenMetadataEvent) -> Option<CollectionEvent> {
+    let (kind, collection_mint, item_metadata, authority, size) = match &event.event {
+        Some(Event::SetAndVerifyCollection(set_and_verify)) => (
+            CollectionEventKind::ItemVerified,
+            &set_and_verify.collection_mint,
+            Some(&set_and_verify.metadata),
+            &set_and_verify.collection_authority,
+            None,
+        ),
+        Some(Event::SetAndVerifySizedCollectionItem(set_and_verify)) => (
+            CollectionEventKind::ItemVerified,
+            &set_and_verify.collection_mint,
+            Some(&set_and_verify.metadata),
+            &set_and_verify.collection_authority,
+            None,
+        ),
+        Some(Event::VerifyCollection(verify)) => (
+            CollectionEventKind::ItemVerified,
+            &verify.collection_mint,
+            Some(&verify.metadata),
+            &verify.collection_authority,
+            None,
+        ),
+        Some(Event::VerifySizedCollectionItem(verify)) => (
+            CollectionEventKind::ItemVerified,
+            &verify.collection_mint,
+            Some(&verify.metadata),
+            &verify.collection_authority,
+            None,
+        ),
+        Some(Event::UnverifyCollection(unverify)) => (
+            CollectionEventKind::ItemUnverified,
+            &unverify.collection_mint,
+            Some(&unverify.metadata),
+            &unverify.collection_authority,
+            None,
+        ),
+        Some(Event::UnverifySizedCollectionItem(unverify)) => (
+            CollectionEventKind::ItemUnverified,
+            &unverify.collection_mint,
+            Some(&unverify.metadata),
+            &unverify.collection_authority,
+            None,
+        ),
+        Some(Event::Verify(verify)) if verify.verification_type() == VerificationType::CollectionV1 => (
+            CollectionEventKind::ItemVerified,
+            verify.collection_mint.as_ref()?,
+            Some(&verify.metadata),
+            &verify.authority,
+            None,
+        ),
+        Some(Event::Unverify(unverify)) if unverify.verification_type() == VerificationType::CollectionV1 => (
+            CollectionEventKind::ItemUnverified,
+            unverify.collection_mint.as_ref()?,
+            Some(&unverify.metadata),
+            &unverify.authority,
+            None,
+        ),
+        Some(Event::SetCollectionSize(set_size)) => (
+            CollectionEventKind::SizeSet,
+            &set_size.collection_mint,
+            None,
+            &set_size.collection_authority,
+            Some(set_size.size),
+        ),
+        Some(Event::BubblegumSetCollectionSize(set_size)) => (
+            CollectionEventKind::SizeSet,
+            &set_size.collection_mint,
+            None,
+            &set_size.collection_authority,
+            Some(set_size.size),
+        ),
+        _ => return None,
+    };
+
+    Some(CollectionEvent {
+        signature: String::new(),
+        kind: kind.into(),
+        collection_mint: collection_mint.clone(),
+        item_metadata: item_metadata.cloned(),
+        item_mint: None,
+        authority: authority.clone(),
+        size,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(event: Event) -> MplTokenMetadataEvent {
+        MplTokenMetadataEvent { event: Some(event) }
+    }
+
+    #[test]
+    fn test_verify_and_unverify_item() {
+        let verify = event(Event::Verify(VerifyEvent {
+            metadata: "item_metadata".to_string(),
+            collection_mint: Some("collection_mint".to_string()),
+            verification_type: VerificationType::CollectionV1.into(),
+            ..Default::default()
+        }));
+        assert_eq!(
+            get_item_collection_change(&verify),
+            Some(("item_metadata".to_string(), Some("collection_mint".to_string())))
+        );
+
+        let unverify = event(Event::UnverifySizedCollectionItem(UnverifySizedCollectionItemEvent {
+            metadata: "item_metadata".to_string(),
+            collection_mint: "collection_mint".to_string(),
+            ..Default::default()
+        }));
+        assert_eq!(get_item_collection_change(&unverify), Some(("item_metadata".to_string(), None)));
+    }
+
+    #[test]
+    fn test_burns_clear_item() {
+        let burn_edition = event(Event::BurnEditionNft(BurnEditionNftEvent {
+            metadata: "print_metadata".to_string(),
+            ..Default::default()
+        }));
+        assert_eq!(get_item_collection_change(&burn_edition), Some(("print_metadata".to_string(), None)));
+
+        // Items of unsized collections are burned without the collection metadata.
+        let burn_nft = event(Event::BurnNft(BurnNftEvent {
+            metadata: "item_metadata".to_string(),
+            collection_metadata: None,
+            ..Default::default()
+        }));
+        assert_eq!(get_item_collection_change(&burn_nft), Some(("item_metadata".to_string(), None)));
+
+        let burn = event(Event::Burn(BurnEvent {
+            metadata: "item_metadata".to_string(),
+            edition: Some("edition".to_string()),
+            ..Default::default()
+        }));
+        assert_eq!(get_item_collection_change(&burn), Some(("item_metadata".to_string(), None)));
+
+        // Burning fungible tokens leaves their metadata in place.
+        let burn_fungible = event(Event::Burn(BurnEvent {
+            metadata: "fungible_metadata".to_string(),
+            amount: 1,
+            ..Default::default()
+        }));
+        assert_eq!(get_item_collection_change(&burn_fungible), None);
+    }
+
+    #[test]
+    fn test_set_collection_size() {
+        let set_size = event(Event::SetCollectionSize(SetCollectionSizeEvent {
+            collection_mint: "collection_mint".to_string(),
+            size: 42,
+            ..Default::default()
+        }));
+        let collection_event = get_collection_event(&set_size).unwrap();
+        assert_eq!(collection_event.kind(), CollectionEventKind::SizeSet);
+        assert_eq!(collection_event.size, Some(42));
+        assert_eq!(get_item_collection_change(&set_size), None);
+    }
+}
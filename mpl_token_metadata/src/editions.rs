@@ -0,0 +1,238 @@
+use substreams::errors::Error;
+use substreams::store::{
+    StoreAdd, StoreAddInt64, StoreGet, StoreGetInt64, StoreGetString, StoreNew, StoreSetString,
+};
+
+use crate::fields::{get_field, set_field};
+use crate::mpl_token_metadata::state::EditionMarker;
+use crate::pb::mpl_token_metadata::*;
+use crate::pb::mpl_token_metadata::mpl_token_metadata_event::Event;
+
+const MINT: &str = "mint";
+const MAX_SUPPLY: &str = "max_supply";
+const MASTER_EDITION: &str = "master_edition";
+const EDITION_NUMBER: &str = "edition_number";
+
+/// Master edition creations, prints and print burns in the block, with their lineage.
+#[substreams::handlers::map]
+fn edition_events(events: MplTokenMetadataBlockEvents, editions: StoreGetString) -> Result<EditionBlockEvents, Error> {
+    let mut edition_events: Vec<EditionEvent> = Vec::new();
+    for transaction in events.transactions.iter() {
+        for event in transaction.events.iter() {
+            if let Some(mut edition_event) = get_edition_event(event) {
+                edition_event.signature = transaction.signature.clone();
+                if edition_event.master_mint.is_none() {
+                    edition_event.master_mint = get_field(&editions, &edition_event.master_edition, MINT);
+                }
+                if edition_event.edition_number.is_none() {
+                    edition_event.edition_number = edition_event
+                        .edition_mint
+                        .as_ref()
+                        .and_then(|x| get_field(&editions, x, EDITION_NUMBER))
+                        .and_then(|x| x.parse().ok());
+                }
+                if !is_programmable_edition(event) {
+                    edition_event.edition_marker_number = edition_event.edition_number.map(EditionMarker::get_marker_number);
+                }
+                let max_supply = get_field(&editions, &edition_event.master_edition, MAX_SUPPLY);
+                edition_event.unlimited_supply = max_supply.as_ref().map(|x| x.is_empty());
+                edition_event.max_supply = max_supply.and_then(|x| x.parse().ok());
+                edition_events.push(edition_event);
+            }
+        }
+    }
+    Ok(EditionBlockEvents { events: edition_events })
+}
+
+/// Master editions and print editions, kept one field per key (see [`crate::fields`]): the
+/// mint and max supply of master editions, where an empty max supply means unlimited and a missing one
+/// that the creation wasn't seen, and the master edition and edition number of print edition mints.
+#[substreams::handlers::store]
+fn store_editions(events: MplTokenMetadataBlockEvents, store: StoreSetString) {
+    for transaction in events.transactions.iter() {
+        for event in transaction.events.iter() {
+            let edition_event = match get_edition_event(event) {
+                Some(edition_event) => edition_event,
+                None => continue,
+            };
+            if let Some(master_mint) = &edition_event.master_mint {
+                set_field(&store, &edition_event.master_edition, MINT, master_mint);
+            }
+            if edition_event.kind == EditionEventKind::MasterEditionCreated as i32 {
+                let max_supply = edition_event.max_supply.map(|x| x.to_string()).unwrap_or_default();
+                set_field(&store, &edition_event.master_edition, MAX_SUPPLY, &max_supply);
+            }
+            if edition_event.kind == EditionEventKind::EditionPrinted as i32 {
+                if let (Some(edition_mint), Some(edition_number)) = (&edition_event.edition_mint, edition_event.edition_number) {
+                    set_field(&store, edition_mint, MASTER_EDITION, &edition_event.master_edition);
+                    set_field(&store, edition_mint, EDITION_NUMBER, &edition_number.to_string());
+                }
+            }
+        }
+    }
+}
+
+/// Supply of every master edition, i.e. the number of prints minted minus the prints burned.
+#[substreams::handlers::store]
+fn store_master_edition_supply(events: MplTokenMetadataBlockEvents, store: StoreAddInt64) {
+    for transaction in events.transactions.iter() {
+        for event in transaction.events.iter() {
+            match get_edition_event(event) {
+                Some(edition_event) if edition_event.kind == EditionEventKind::EditionPrinted as i32 => {
+                    store.add(0, &edition_event.master_edition, 1);
+                },
+                Some(edition_event) if edition_event.kind == EditionEventKind::EditionBurned as i32 => {
+                    store.add(0, &edition_event.master_edition, -1);
+                },
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Reads a master edition from `store_editions` and `store_master_edition_supply`, or `None` if its
+/// creation wasn't seen.
+pub fn get_master_edition(editions: &StoreGetString, supply: &StoreGetInt64, address: &str) -> Option<MasterEdition> {
+    let max_supply = get_field(editions, address, MAX_SUPPLY)?;
+
+    Some(MasterEdition {
+        address: address.to_string(),
+        mint: get_field(editions, address, MINT).unwrap_or_default(),
+        max_supply: max_supply.parse().ok(),
+        unlimited_supply: max_supply.is_empty(),
+        supply: supply.get_last(address).unwrap_or(0),
+    })
+}
+
+/// Whether the event prints or burns an edition of a programmable asset. Those editions all share the
+/// master edition's single `EditionMarkerV2`, so edition marker numbers don't apply to them.
+fn is_programmable_edition(event: &MplTokenMetadataEvent) -> bool {
+    match &event.event {
+        Some(Event::Print(print)) => print.edition_token_record.is_some(),
+        Some(Event::Burn(burn)) => burn.token_record.is_some(),
+        _ => false,
+    }
+}
+
+fn get_edition_event(event: &MplTokenMetadataEvent) -> Option<EditionEvent> {
+    let edition_event = match &event.event {
+        Some(Event::CreateMasterEditionV3(create)) => EditionEvent {
+            kind: EditionEventKind::MasterEditionCreated.into(),
+            master_edition: create.edition.clone(),
+            master_mint: Some(create.mint.clone()),
+            max_supply: create.max_supply,
+            unlimited_supply: Some(create.max_supply.is_none()),
+            ..Default::default()
+        },
+        Some(Event::MintNewEditionFromMasterEditionViaToken(mint_new_edition)) => EditionEvent {
+            kind: EditionEventKind::EditionPrinted.into(),
+            master_edition: mint_new_edition.master_edition.clone(),
+            edition_mint: Some(mint_new_edition.new_mint.clone()),
+            edition_number: Some(mint_new_edition.edition),
+            edition_marker: Some(mint_new_edition.edition_mark_pda.clone()),
+            ..Default::default()
+        },
+        Some(Event::MintNewEditionFromMasterEditionViaVaultProxy(mint_new_edition)) => EditionEvent {
+            kind: EditionEventKind::EditionPrinted.into(),
+            master_edition: mint_new_edition.master_edition.clone(),
+            edition_mint: Some(mint_new_edition.new_mint.clone()),
+            edition_number: Some(mint_new_edition.edition),
+            edition_marker: Some(mint_new_edition.edition_mark_pda.clone()),
+            ..Default::default()
+        },
+        Some(Event::Print(print)) => EditionEvent {
+            kind: EditionEventKind::EditionPrinted.into(),
+            master_edition: print.master_edition.clone(),
+            edition_mint: Some(print.edition_mint.clone()),
+            edition_number: Some(print.edition_number),
+            edition_marker: Some(print.edition_marker_pda.clone()),
+            ..Default::default()
+        },
+        Some(Event::BurnEditionNft(burn)) => EditionEvent {
+            kind: EditionEventKind::EditionBurned.into(),
+            master_edition: burn.master_edition.clone(),
+            master_mint: Some(burn.master_edition_mint.clone()),
+            edition_mint: Some(burn.print_edition_mint.clone()),
+            edition_marker: Some(burn.edition_marker.clone()),
+            ..Default::default()
+        },
+        // Only print editions are burned with their master edition and edition marker.
+        Some(Event::Burn(BurnEvent {
+            master_edition: Some(master_edition),
+            edition_marker: Some(edition_marker),
+            master_edition_mint,
+            mint,
+            ..
+        })) => EditionEvent {
+            kind: EditionEventKind::EditionBurned.into(),
+            master_edition: master_edition.clone(),
+            master_mint: master_edition_mint.clone(),
+            edition_mint: Some(mint.clone()),
+            edition_marker: Some(edition_marker.clone()),
+            ..Default::default()
+        },
+        _ => return None,
+    };
+    Some(edition_event)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(event: Event) -> MplTokenMetadataEvent {
+        MplTokenMetadataEvent { event: Some(event) }
+    }
+
+    #[test]
+    fn test_master_edition_created() {
+        let unlimited = get_edition_event(&event(Event::CreateMasterEditionV3(CreateMasterEditionV3Event {
+            edition: "master_edition".to_string(),
+            mint: "master_mint".to_string(),
+            max_supply: None,
+            ..Default::default()
+        })))
+        .unwrap();
+        assert_eq!(unlimited.kind(), EditionEventKind::MasterEditionCreated);
+        assert_eq!(unlimited.max_supply, None);
+        assert_eq!(unlimited.unlimited_supply, Some(true));
+
+        let limited = get_edition_event(&event(Event::CreateMasterEditionV3(CreateMasterEditionV3Event {
+            edition: "master_edition".to_string(),
+            mint: "master_mint".to_string(),
+            max_supply: Some(100),
+            ..Default::default()
+        })))
+        .unwrap();
+        assert_eq!(limited.max_supply, Some(100));
+        assert_eq!(limited.unlimited_supply, Some(false));
+    }
+
+    #[test]
+    fn test_programmable_editions() {
+        let print = event(Event::Print(PrintEvent {
+            master_edition: "master_edition".to_string(),
+            edition_mint: "edition_mint".to_string(),
+            edition_number: 300,
+            ..Default::default()
+        }));
+        assert!(!is_programmable_edition(&print));
+        assert_eq!(get_edition_event(&print).unwrap().edition_number.map(EditionMarker::get_marker_number), Some(1));
+
+        let programmable_print = event(Event::Print(PrintEvent {
+            edition_token_record: Some("edition_token_record".to_string()),
+            edition_number: 300,
+            ..Default::default()
+        }));
+        assert!(is_programmable_edition(&programmable_print));
+
+        let programmable_burn = event(Event::Burn(BurnEvent {
+            master_edition: Some("master_edition".to_string()),
+            edition_marker: Some("edition_marker".to_string()),
+            token_record: Some("token_record".to_string()),
+            ..Default::default()
+        }));
+        assert!(is_programmable_edition(&programmable_burn));
+        assert_eq!(get_edition_event(&programmable_burn).unwrap().kind(), EditionEventKind::EditionBurned);
+    }
+}
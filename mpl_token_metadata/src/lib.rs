@@ -17,6 +17,12 @@ pub mod pb;
 use pb::mpl_token_metadata::*;
 use pb::mpl_token_metadata::mpl_token_metadata_event::Event;
 
+pub mod collections;
+pub mod fields;
+pub mod editions;
+pub mod token_metadata;
+pub mod token_records;
+
 #[substreams::handlers::map]
 fn mpl_token_metadata_events(block: Block) -> Result<MplTokenMetadataBlockEvents, Error> {
     let transactions = parse_block(&block);
@@ -59,201 +65,203 @@ pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<MplTo
 
 pub fn parse_instruction(
     instruction: &StructuredInstruction,
-    context: &TransactionContext
+    _context: &TransactionContext
 ) -> Result<Option<Event>, String> {
     if instruction.program_id() != MPL_TOKEN_METADATA_PROGRAM_ID {
         return Err("Not a Metaplex Token Metadata instruction.".into());
     }
-    let unpacked = MetadataInstruction::try_from_slice(instruction.data()).map_err(|_| "Failed to parse MetadataInstruction.")?;
+    parse_instruction_data(instruction.data(), instruction.accounts())
+}
+
+/// Decodes a Token Metadata instruction from its data and accounts. Instructions with fewer accounts
+/// than they require fail rather than being read out of bounds.
+fn parse_instruction_data<A: ToString>(data: &[u8], accounts: &[A]) -> Result<Option<Event>, String> {
+    let unpacked = MetadataInstruction::try_from_slice(data).map_err(|_| "Failed to parse MetadataInstruction.")?;
     match unpacked {
         MetadataInstruction::ApproveCollectionAuthority => {
-            Ok(Some(Event::ApproveCollectionAuthority(ApproveCollectionAuthorityEvent {})))
+            _parse_approve_collection_authority_instruction(accounts).map(|x| Some(Event::ApproveCollectionAuthority(x)))
         },
-        MetadataInstruction::ApproveUseAuthority(_) => {
-            Ok(Some(Event::ApproveUseAuthority(ApproveUseAuthorityEvent {})))
+        MetadataInstruction::ApproveUseAuthority(approve_use_authority) => {
+            _parse_approve_use_authority_instruction(accounts, approve_use_authority).map(|x| Some(Event::ApproveUseAuthority(x)))
         },
-        MetadataInstruction::BubblegumSetCollectionSize(_) => {
-            Ok(Some(Event::BubblegumSetCollectionSize(BubblegumSetCollectionSizeEvent {})))
+        MetadataInstruction::BubblegumSetCollectionSize(set_collection_size) => {
+            _parse_bubblegum_set_collection_size_instruction(accounts, set_collection_size).map(|x| Some(Event::BubblegumSetCollectionSize(x)))
         },
-        MetadataInstruction::Burn(_) => {
-            Ok(Some(Event::Burn(BurnEvent {})))
+        MetadataInstruction::Burn(burn) => {
+            _parse_burn_instruction(accounts, burn).map(|x| Some(Event::Burn(x)))
         },
         MetadataInstruction::BurnEditionNft => {
-            Ok(Some(Event::BurnEditionNft(BurnEditionNftEvent {})))
+            _parse_burn_edition_nft_instruction(accounts).map(|x| Some(Event::BurnEditionNft(x)))
         },
         MetadataInstruction::BurnNft => {
-            Ok(Some(Event::BurnNft(BurnNftEvent {})))
+            _parse_burn_nft_instruction(accounts).map(|x| Some(Event::BurnNft(x)))
         },
         MetadataInstruction::CloseEscrowAccount => {
-            Ok(Some(Event::CloseEscrowAccount(CloseEscrowAccountEvent {})))
+            _parse_close_escrow_account_instruction(accounts).map(|x| Some(Event::CloseEscrowAccount(x)))
         },
         MetadataInstruction::ConvertMasterEditionV1ToV2 => {
-            Ok(Some(Event::ConvertMasterEditionV1ToV2(ConvertMasterEditionV1ToV2Event {})))
+            _parse_convert_master_edition_v1_to_v2_instruction(accounts).map(|x| Some(Event::ConvertMasterEditionV1ToV2(x)))
         },
-        MetadataInstruction::Create(_) => {
-            // _parse_create_instruction(instruction, context, create).map(|x| Some(Event::Create(x)))
-            Ok(Some(Event::Create(CreateEvent {})))
+        MetadataInstruction::Create(create) => {
+            _parse_create_instruction(accounts, create).map(|x| Some(Event::Create(x)))
         },
         MetadataInstruction::CreateEscrowAccount => {
-            Ok(Some(Event::CreateEscrowAccount(CreateEscrowAccountEvent {})))
+            _parse_create_escrow_account_instruction(accounts).map(|x| Some(Event::CreateEscrowAccount(x)))
         },
         MetadataInstruction::CreateMasterEdition => {
-            Ok(Some(Event::CreateMasterEdition(CreateMasterEditionEvent {})))
+            _parse_create_master_edition_instruction(accounts).map(|x| Some(Event::CreateMasterEdition(x)))
         },
-        MetadataInstruction::CreateMasterEditionV3(_) => {
-            Ok(Some(Event::CreateMasterEditionV3(CreateMasterEditionV3Event {})))
+        MetadataInstruction::CreateMasterEditionV3(create_master_edition) => {
+            _parse_create_master_edition_v3_instruction(accounts, create_master_edition).map(|x| Some(Event::CreateMasterEditionV3(x)))
         },
         MetadataInstruction::CreateMetadataAccount => {
-            Ok(Some(Event::CreateMetadataAccount(CreateMetadataAccountEvent {})))
+            _parse_create_metadata_account_instruction(accounts).map(|x| Some(Event::CreateMetadataAccount(x)))
         },
         MetadataInstruction::CreateMetadataAccountV2 => {
-            Ok(Some(Event::CreateMetadataAccountV2(CreateMetadataAccountV2Event {})))
+            _parse_create_metadata_account_v2_instruction(accounts).map(|x| Some(Event::CreateMetadataAccountV2(x)))
         },
         MetadataInstruction::CreateMetadataAccountV3(create_metadata_account_v3) => {
-            _parse_create_metadata_account_v3_instruction(instruction, context, create_metadata_account_v3).map(|x| Some(Event::CreateMetadataAccountV3(x)))
+            _parse_create_metadata_account_v3_instruction(accounts, create_metadata_account_v3).map(|x| Some(Event::CreateMetadataAccountV3(x)))
         },
-        MetadataInstruction::Delegate(_) => {
-            Ok(Some(Event::Delegate(DelegateEvent {})))
+        MetadataInstruction::Delegate(delegate) => {
+            _parse_delegate_instruction(accounts, delegate).map(|x| Some(Event::Delegate(x)))
         },
         MetadataInstruction::DeprecatedCreateMasterEdition => {
-            Ok(Some(Event::DeprecatedCreateMasterEdition(DeprecatedCreateMasterEditionEvent {})))
+            _parse_deprecated_create_master_edition_instruction(accounts).map(|x| Some(Event::DeprecatedCreateMasterEdition(x)))
         },
         MetadataInstruction::DeprecatedCreateReservationList => {
-            Ok(Some(Event::DeprecatedCreateReservationList(DeprecatedCreateReservationListEvent {})))
+            _parse_deprecated_create_reservation_list_instruction(accounts).map(|x| Some(Event::DeprecatedCreateReservationList(x)))
         },
         MetadataInstruction::DeprecatedMintNewEditionFromMasterEditionViaPrintingToken => {
-            Ok(Some(Event::DeprecatedMintNewEditionFromMasterEditionViaPrintingToken(DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenEvent {})))
+            _parse_deprecated_mint_new_edition_from_master_edition_via_printing_token_instruction(accounts).map(|x| Some(Event::DeprecatedMintNewEditionFromMasterEditionViaPrintingToken(x)))
         },
         MetadataInstruction::DeprecatedMintPrintingTokens => {
-            Ok(Some(Event::DeprecatedMintPrintingTokens(DeprecatedMintPrintingTokensEvent {})))
+            _parse_deprecated_mint_printing_tokens_instruction(accounts).map(|x| Some(Event::DeprecatedMintPrintingTokens(x)))
         },
         MetadataInstruction::DeprecatedMintPrintingTokensViaToken => {
-            Ok(Some(Event::DeprecatedMintPrintingTokensViaToken(DeprecatedMintPrintingTokensViaTokenEvent {})))
+            _parse_deprecated_mint_printing_tokens_via_token_instruction(accounts).map(|x| Some(Event::DeprecatedMintPrintingTokensViaToken(x)))
         },
         MetadataInstruction::DeprecatedSetReservationList => {
-            Ok(Some(Event::DeprecatedSetReservationList(DeprecatedSetReservationListEvent {})))
+            _parse_deprecated_set_reservation_list_instruction(accounts).map(|x| Some(Event::DeprecatedSetReservationList(x)))
         },
         MetadataInstruction::FreezeDelegatedAccount => {
-            Ok(Some(Event::FreezeDelegatedAccount(FreezeDelegatedAccountEvent {})))
+            _parse_freeze_delegated_account_instruction(accounts).map(|x| Some(Event::FreezeDelegatedAccount(x)))
         },
         MetadataInstruction::Lock(_) => {
-            Ok(Some(Event::Lock(LockEvent {})))
+            _parse_lock_instruction(accounts).map(|x| Some(Event::Lock(x)))
         },
         MetadataInstruction::Migrate => {
-            Ok(Some(Event::Migrate(MigrateEvent {})))
+            _parse_migrate_instruction(accounts).map(|x| Some(Event::Migrate(x)))
         },
-        MetadataInstruction::MintNewEditionFromMasterEditionViaToken(_) => {
-            Ok(Some(Event::MintNewEditionFromMasterEditionViaToken(MintNewEditionFromMasterEditionViaTokenEvent {})))
+        MetadataInstruction::MintNewEditionFromMasterEditionViaToken(mint_new_edition) => {
+            _parse_mint_new_edition_from_master_edition_via_token_instruction(accounts, mint_new_edition).map(|x| Some(Event::MintNewEditionFromMasterEditionViaToken(x)))
         },
-        MetadataInstruction::MintNewEditionFromMasterEditionViaVaultProxy(_) => {
-            Ok(Some(Event::MintNewEditionFromMasterEditionViaVaultProxy(MintNewEditionFromMasterEditionViaVaultProxyEvent {})))
+        MetadataInstruction::MintNewEditionFromMasterEditionViaVaultProxy(mint_new_edition) => {
+            _parse_mint_new_edition_from_master_edition_via_vault_proxy_instruction(accounts, mint_new_edition).map(|x| Some(Event::MintNewEditionFromMasterEditionViaVaultProxy(x)))
         },
         MetadataInstruction::PuffMetadata => {
-            Ok(Some(Event::PuffMetadata(PuffMetadataEvent {})))
+            _parse_puff_metadata_instruction(accounts).map(|x| Some(Event::PuffMetadata(x)))
         },
         MetadataInstruction::RemoveCreatorVerification => {
-            Ok(Some(Event::RemoveCreatorVerification(RemoveCreatorVerificationEvent {})))
+            _parse_remove_creator_verification_instruction(accounts).map(|x| Some(Event::RemoveCreatorVerification(x)))
         },
-        MetadataInstruction::Revoke(_) => {
-            Ok(Some(Event::Revoke(RevokeEvent {})))
+        MetadataInstruction::Revoke(revoke) => {
+            _parse_revoke_instruction(accounts, revoke).map(|x| Some(Event::Revoke(x)))
         },
         MetadataInstruction::RevokeCollectionAuthority => {
-            Ok(Some(Event::RevokeCollectionAuthority(RevokeCollectionAuthorityEvent {})))
+            _parse_revoke_collection_authority_instruction(accounts).map(|x| Some(Event::RevokeCollectionAuthority(x)))
         },
         MetadataInstruction::RevokeUseAuthority => {
-            Ok(Some(Event::RevokeUseAuthority(RevokeUseAuthorityEvent {})))
+            _parse_revoke_use_authority_instruction(accounts).map(|x| Some(Event::RevokeUseAuthority(x)))
         },
         MetadataInstruction::SetAndVerifyCollection => {
-            Ok(Some(Event::SetAndVerifyCollection(SetAndVerifyCollectionEvent {})))
+            _parse_set_and_verify_collection_instruction(accounts).map(|x| Some(Event::SetAndVerifyCollection(x)))
         },
         MetadataInstruction::SetAndVerifySizedCollectionItem => {
-            Ok(Some(Event::SetAndVerifySizedCollectionItem(SetAndVerifySizedCollectionItemEvent {})))
+            _parse_set_and_verify_sized_collection_item_instruction(accounts).map(|x| Some(Event::SetAndVerifySizedCollectionItem(x)))
         },
         MetadataInstruction::SetTokenStandard => {
-            Ok(Some(Event::SetTokenStandard(SetTokenStandardEvent {})))
+            _parse_set_token_standard_instruction(accounts).map(|x| Some(Event::SetTokenStandard(x)))
         },
         MetadataInstruction::SignMetadata => {
-            Ok(Some(Event::SignMetadata(SignMetadataEvent {})))
+            _parse_sign_metadata_instruction(accounts).map(|x| Some(Event::SignMetadata(x)))
         },
         MetadataInstruction::ThawDelegatedAccount => {
-            Ok(Some(Event::ThawDelegatedAccount(ThawDelegatedAccountEvent {})))
+            _parse_thaw_delegated_account_instruction(accounts).map(|x| Some(Event::ThawDelegatedAccount(x)))
         },
-        MetadataInstruction::Transfer(_) => {
-            Ok(Some(Event::Transfer(TransferEvent {})))
+        MetadataInstruction::Transfer(transfer) => {
+            _parse_transfer_instruction(accounts, transfer).map(|x| Some(Event::Transfer(x)))
         },
-        MetadataInstruction::TransferOutOfEscrow(_) => {
-            Ok(Some(Event::TransferOutOfEscrow(TransferOutOfEscrowEvent {})))
+        MetadataInstruction::TransferOutOfEscrow(transfer_out_of_escrow) => {
+            _parse_transfer_out_of_escrow_instruction(accounts, transfer_out_of_escrow).map(|x| Some(Event::TransferOutOfEscrow(x)))
         },
         MetadataInstruction::Unlock(_) => {
-            Ok(Some(Event::Unlock(UnlockEvent {})))
+            _parse_unlock_instruction(accounts).map(|x| Some(Event::Unlock(x)))
         },
-        MetadataInstruction::Unverify(_) => {
-            Ok(Some(Event::Unverify(UnverifyEvent {})))
+        MetadataInstruction::Unverify(unverify) => {
+            _parse_unverify_instruction(accounts, unverify).map(|x| Some(Event::Unverify(x)))
         },
         MetadataInstruction::UnverifyCollection => {
-            Ok(Some(Event::UnverifyCollection(UnverifyCollectionEvent {})))
+            _parse_unverify_collection_instruction(accounts).map(|x| Some(Event::UnverifyCollection(x)))
         },
         MetadataInstruction::UnverifySizedCollectionItem => {
-            Ok(Some(Event::UnverifySizedCollectionItem(UnverifySizedCollectionItemEvent {})))
+            _parse_unverify_sized_collection_item_instruction(accounts).map(|x| Some(Event::UnverifySizedCollectionItem(x)))
         },
-        MetadataInstruction::Update(_) => {
-            // _parse_update_instruction(instruction, context, update).map(|x| Some(Event::Update(x)))
-            Ok(Some(Event::Update(UpdateEvent {})))
+        MetadataInstruction::Update(update) => {
+            _parse_update_instruction(accounts, update).map(|x| Some(Event::Update(x)))
         },
         MetadataInstruction::UpdateMetadataAccount => {
-            Ok(Some(Event::UpdateMetadataAccount(UpdateMetadataAccountEvent {})))
+            _parse_update_metadata_account_instruction(accounts).map(|x| Some(Event::UpdateMetadataAccount(x)))
         },
-        MetadataInstruction::UpdateMetadataAccountV2(_) => {
-            // _parse_update_metadata_account_v2_instruction(instruction, context, update_metadata_account_v2).map(|x| Some(Event::UpdateMetadataAccountV2(x)))
-            Ok(Some(Event::UpdateMetadataAccountV2(UpdateMetadataAccountV2Event {})))
+        MetadataInstruction::UpdateMetadataAccountV2(update_metadata_account_v2) => {
+            _parse_update_metadata_account_v2_instruction(accounts, update_metadata_account_v2).map(|x| Some(Event::UpdateMetadataAccountV2(x)))
         },
         MetadataInstruction::UpdatePrimarySaleHappenedViaToken => {
-            Ok(Some(Event::UpdatePrimarySaleHappenedViaToken(UpdatePrimarySaleHappenedViaTokenEvent {})))
+            _parse_update_primary_sale_happened_via_token_instruction(accounts).map(|x| Some(Event::UpdatePrimarySaleHappenedViaToken(x)))
         },
-        MetadataInstruction::Utilize(_) => {
-            Ok(Some(Event::Utilize(UtilizeEvent {})))
+        MetadataInstruction::Utilize(utilize) => {
+            _parse_utilize_instruction(accounts, utilize).map(|x| Some(Event::Utilize(x)))
         },
-        MetadataInstruction::Print(_) => {
-            Ok(Some(Event::Print(PrintEvent {})))
+        MetadataInstruction::Print(print) => {
+            _parse_print_instruction(accounts, print).map(|x| Some(Event::Print(x)))
         },
-        MetadataInstruction::Verify(_) => {
-            Ok(Some(Event::Verify(VerifyEvent {})))
+        MetadataInstruction::Verify(verify) => {
+            _parse_verify_instruction(accounts, verify).map(|x| Some(Event::Verify(x)))
         },
-        MetadataInstruction::Mint(_) => {
-            Ok(Some(Event::Mint(MintEvent {})))
+        MetadataInstruction::Mint(mint) => {
+            _parse_mint_instruction(accounts, mint).map(|x| Some(Event::Mint(x)))
         },
-        MetadataInstruction::SetCollectionSize(_) => {
-            Ok(Some(Event::SetCollectionSize(SetCollectionSizeEvent {})))
+        MetadataInstruction::SetCollectionSize(set_collection_size) => {
+            _parse_set_collection_size_instruction(accounts, set_collection_size).map(|x| Some(Event::SetCollectionSize(x)))
         },
         MetadataInstruction::Collect => {
-            Ok(Some(Event::Collect(CollectEvent {})))
+            _parse_collect_instruction(accounts).map(|x| Some(Event::Collect(x)))
         },
         MetadataInstruction::Use(_) => {
-            Ok(Some(Event::Use(UseEvent {})))
+            _parse_use_instruction(accounts).map(|x| Some(Event::Use(x)))
         },
         MetadataInstruction::VerifySizedCollectionItem => {
-            Ok(Some(Event::VerifySizedCollectionItem(VerifySizedCollectionItemEvent {})))
+            _parse_verify_sized_collection_item_instruction(accounts).map(|x| Some(Event::VerifySizedCollectionItem(x)))
         },
         MetadataInstruction::VerifyCollection => {
-            Ok(Some(Event::VerifyCollection(VerifyCollectionEvent {})))
+            _parse_verify_collection_instruction(accounts).map(|x| Some(Event::VerifyCollection(x)))
         },
         MetadataInstruction::Resize => {
-            Ok(Some(Event::Resize(ResizeEvent {})))
+            _parse_resize_instruction(accounts).map(|x| Some(Event::Resize(x)))
         },
         MetadataInstruction::CloseAccounts => {
-            Ok(Some(Event::CloseAccounts(CloseAccountsEvent {})))
+            _parse_close_accounts_instruction(accounts).map(|x| Some(Event::CloseAccounts(x)))
         }
     }
 }
 
-fn _parse_create_metadata_account_v3_instruction<'a>(
-    instruction: &StructuredInstruction<'a>,
-    _context: &TransactionContext,
+fn _parse_create_metadata_account_v3_instruction<A: ToString>(
+    accounts: &[A],
     create_metadata_account_v3: mpl_token_metadata::instruction::CreateMetadataAccountArgsV3,
 ) -> Result<CreateMetadataAccountV3Event, String> {
-    let metadata = instruction.accounts()[0].to_string();
-    let mint = instruction.accounts()[1].to_string();
-    let update_authority = instruction.accounts()[4].to_string();
+    let metadata = get_account(accounts, 0)?;
+    let mint = get_account(accounts, 1)?;
+    let update_authority = get_account(accounts, 4)?;
     let data = Some(create_metadata_account_v3.data.into());
     let is_mutable = create_metadata_account_v3.is_mutable;
     let collection_details = create_metadata_account_v3.collection_details.map(|x| x.into());
@@ -268,99 +276,1028 @@ fn _parse_create_metadata_account_v3_instruction<'a>(
     })
 }
 
-// fn _parse_create_instruction<'a>(
-//     instruction: &StructuredInstruction<'a>,
-//     _context: &TransactionContext,
-//     create: mpl_token_metadata::instruction::CreateArgs,
-// ) -> Result<CreateEvent, String> {
-//     unimplemented!()
-// }
-
-// fn _parse_update_instruction<'a>(
-//     instruction: &StructuredInstruction,
-//     context: &TransactionContext,
-//     update: mpl_token_metadata::instruction::UpdateArgs,
-// ) -> Result<UpdateEvent, String> {
-//     let metadata = instruction.accounts()[0].to_string();
-//     let delta = (instruction.accounts()[6] == SYSTEM_PROGRAM_ID) as usize;
-//     let mint = instruction.accounts()[1 + delta].to_string();
-//     let authority = instruction.accounts()[2 + delta].to_string();
-
-//     let update_args = match update {
-//         mpl_token_metadata::instruction::UpdateArgs::V1 { new_update_authority, data, primary_sale_happened, is_mutable, collection, collection_details, uses, rule_set, authorization_data } => {
-//             Some(update_event::UpdateArgs::V1(UpdateArgsV1 {
-//                 new_update_authority: new_update_authority.map(|x| x.to_string()),
-//                 data: data.map(|x| x.into()),
-//                 primary_sale_happened,
-//                 is_mutable,
-//                 collection: Some(CollectionToggle {}),
-//                 collection_details: Some(CollectionDetailsToggle {}),
-//                 uses: Some(UsesToggle {}),
-//                 rule_set: Some(RuleSetToggle {}),
-//                 authorization_data: Some(AuthorizationData {}),
-//             }))
-//         },
-//         mpl_token_metadata::instruction::UpdateArgs::AsUpdateAuthorityV2 { new_update_authority, data, primary_sale_happened, is_mutable, collection, collection_details, uses, rule_set, token_standard, authorization_data } => {
-//             Some(update_event::UpdateArgs::AsUpdateAuthorityV2(UpdateArgsAsUpdateAuthorityV2 {
-//                 new_update_authority: None,
-//                 data: None,
-//                 primary_sale_happened: None,
-//                 is_mutable: None,
-//                 collection: None,
-//                 collection_details: None,
-//                 uses: None,
-//                 rule_set: None,
-//                 token_standard: None,
-//                 authorization_data: None,
-//             }))
-//         },
-//         mpl_token_metadata::instruction::UpdateArgs::AsAuthorityItemDelegateV2 { new_update_authority, primary_sale_happened, is_mutable, token_standard, authorization_data } => {
-//             Some(update_event::UpdateArgs::AsAuthorityItemDelegateV2(UpdateArgsAsAuthorityItemDelegateV2 {
-//                 new_update_authority: None,
-//                 primary_sale_happened: None,
-//                 is_mutable: None,
-//                 token_standard: None,
-//                 authorization_data: None,
-//             }))
-//         },
-//         mpl_token_metadata::instruction::UpdateArgs::AsCollectionDelegateV2 { collection, authorization_data } => {
-//             None
-//         },
-//         mpl_token_metadata::instruction::UpdateArgs::AsDataDelegateV2 { data, authorization_data } => {
-//             None
-//         },
-//         mpl_token_metadata::instruction::UpdateArgs::AsProgrammableConfigDelegateV2 { rule_set, authorization_data } => {
-//             None
-//         },
-//         mpl_token_metadata::instruction::UpdateArgs::AsDataItemDelegateV2 { data, authorization_data } => {
-//             Some(update_event::UpdateArgs::AsDataItemDelegateV2(UpdateArgsAsDataItemDelegateV2  {
-//                 data: data.map(|x| x.into()),
-//                 authorization_data: None,
-//             }))
-//         },
-//         mpl_token_metadata::instruction::UpdateArgs::AsCollectionItemDelegateV2 { collection, authorization_data } => {
-//             None
-//         },
-//         mpl_token_metadata::instruction::UpdateArgs::AsProgrammableConfigItemDelegateV2 { rule_set, authorization_data } => {
-//             None
-//         },
-//     };
-
-//     Ok(UpdateEvent {
-//         authority,
-//         metadata,
-//         mint,
-//         update_args,
-//     })
-// }
-
-// fn _parse_update_metadata_account_v2_instruction<'a>(
-//     instruction: &StructuredInstruction,
-//     context: &TransactionContext,
-//     update_metadata_account_v2: mpl_token_metadata::instruction::UpdateMetadataAccountArgsV2,
-// ) -> Result<UpdateMetadataAccountV2Event, String> {
-//     unimplemented!()
-// }
+fn _parse_approve_collection_authority_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<ApproveCollectionAuthorityEvent, String> {
+    Ok(ApproveCollectionAuthorityEvent {
+        collection_authority_record: get_account(accounts, 0)?,
+        new_collection_authority: get_account(accounts, 1)?,
+        update_authority: get_account(accounts, 2)?,
+        payer: get_account(accounts, 3)?,
+        metadata: get_account(accounts, 4)?,
+        mint: get_account(accounts, 5)?,
+    })
+}
+
+fn _parse_approve_use_authority_instruction<A: ToString>(
+    accounts: &[A],
+    approve_use_authority: mpl_token_metadata::instruction::ApproveUseAuthorityArgs,
+) -> Result<ApproveUseAuthorityEvent, String> {
+    Ok(ApproveUseAuthorityEvent {
+        use_authority_record: get_account(accounts, 0)?,
+        owner: get_account(accounts, 1)?,
+        payer: get_account(accounts, 2)?,
+        user: get_account(accounts, 3)?,
+        owner_token_account: get_account(accounts, 4)?,
+        metadata: get_account(accounts, 5)?,
+        mint: get_account(accounts, 6)?,
+        number_of_uses: approve_use_authority.number_of_uses,
+    })
+}
+
+fn _parse_bubblegum_set_collection_size_instruction<A: ToString>(
+    accounts: &[A],
+    set_collection_size: mpl_token_metadata::instruction::SetCollectionSizeArgs,
+) -> Result<BubblegumSetCollectionSizeEvent, String> {
+    Ok(BubblegumSetCollectionSizeEvent {
+        collection_metadata: get_account(accounts, 0)?,
+        collection_authority: get_account(accounts, 1)?,
+        collection_mint: get_account(accounts, 2)?,
+        bubblegum_signer: get_account(accounts, 3)?,
+        collection_authority_record: get_optional_account(accounts, 4),
+        size: set_collection_size.size,
+    })
+}
+
+fn _parse_burn_instruction<A: ToString>(
+    accounts: &[A],
+    burn: mpl_token_metadata::instruction::BurnArgs,
+) -> Result<BurnEvent, String> {
+    let amount = match burn {
+        mpl_token_metadata::instruction::BurnArgs::V1 { amount } => amount,
+    };
+
+    Ok(BurnEvent {
+        authority: get_account(accounts, 0)?,
+        collection_metadata: get_optional_account(accounts, 1),
+        metadata: get_account(accounts, 2)?,
+        edition: get_optional_account(accounts, 3),
+        mint: get_account(accounts, 4)?,
+        token: get_account(accounts, 5)?,
+        master_edition: get_optional_account(accounts, 6),
+        master_edition_mint: get_optional_account(accounts, 7),
+        master_edition_token: get_optional_account(accounts, 8),
+        edition_marker: get_optional_account(accounts, 9),
+        token_record: get_optional_account(accounts, 10),
+        amount,
+    })
+}
+
+fn _parse_burn_edition_nft_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<BurnEditionNftEvent, String> {
+    Ok(BurnEditionNftEvent {
+        metadata: get_account(accounts, 0)?,
+        owner: get_account(accounts, 1)?,
+        print_edition_mint: get_account(accounts, 2)?,
+        master_edition_mint: get_account(accounts, 3)?,
+        print_edition_token_account: get_account(accounts, 4)?,
+        master_edition_token_account: get_account(accounts, 5)?,
+        master_edition: get_account(accounts, 6)?,
+        print_edition: get_account(accounts, 7)?,
+        edition_marker: get_account(accounts, 8)?,
+    })
+}
+
+fn _parse_burn_nft_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<BurnNftEvent, String> {
+    Ok(BurnNftEvent {
+        metadata: get_account(accounts, 0)?,
+        owner: get_account(accounts, 1)?,
+        mint: get_account(accounts, 2)?,
+        token_account: get_account(accounts, 3)?,
+        master_edition: get_account(accounts, 4)?,
+        collection_metadata: get_optional_account(accounts, 6),
+    })
+}
+
+fn _parse_close_escrow_account_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<CloseEscrowAccountEvent, String> {
+    Ok(CloseEscrowAccountEvent {
+        escrow: get_account(accounts, 0)?,
+        metadata: get_account(accounts, 1)?,
+        mint: get_account(accounts, 2)?,
+        token_account: get_account(accounts, 3)?,
+        edition: get_account(accounts, 4)?,
+        payer: get_account(accounts, 5)?,
+    })
+}
+
+fn _parse_convert_master_edition_v1_to_v2_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<ConvertMasterEditionV1ToV2Event, String> {
+    Ok(ConvertMasterEditionV1ToV2Event {
+        master_edition: get_account(accounts, 0)?,
+        one_time_auth: get_account(accounts, 1)?,
+        printing_mint: get_account(accounts, 2)?,
+    })
+}
+
+fn _parse_create_instruction<A: ToString>(
+    accounts: &[A],
+    create: mpl_token_metadata::instruction::CreateArgs,
+) -> Result<CreateEvent, String> {
+    let (asset_data, decimals, print_supply) = match create {
+        mpl_token_metadata::instruction::CreateArgs::V1 { asset_data, decimals, print_supply } => (asset_data, decimals, print_supply),
+    };
+
+    Ok(CreateEvent {
+        metadata: get_account(accounts, 0)?,
+        master_edition: get_optional_account(accounts, 1),
+        mint: get_account(accounts, 2)?,
+        mint_authority: get_account(accounts, 3)?,
+        payer: get_account(accounts, 4)?,
+        update_authority: get_account(accounts, 5)?,
+        asset_data: Some(asset_data.into()),
+        decimals: decimals.map(|x| x.into()),
+        print_supply: print_supply.map(|x| x.into()),
+    })
+}
+
+fn _parse_create_escrow_account_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<CreateEscrowAccountEvent, String> {
+    Ok(CreateEscrowAccountEvent {
+        escrow: get_account(accounts, 0)?,
+        metadata: get_account(accounts, 1)?,
+        mint: get_account(accounts, 2)?,
+        token_account: get_account(accounts, 3)?,
+        edition: get_account(accounts, 4)?,
+        payer: get_account(accounts, 5)?,
+        authority: get_optional_account(accounts, 8),
+    })
+}
+
+fn _parse_create_master_edition_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<CreateMasterEditionEvent, String> {
+    Ok(CreateMasterEditionEvent {
+        edition: get_account(accounts, 0)?,
+        mint: get_account(accounts, 1)?,
+        update_authority: get_account(accounts, 2)?,
+        mint_authority: get_account(accounts, 3)?,
+        payer: get_account(accounts, 4)?,
+        metadata: get_account(accounts, 5)?,
+    })
+}
+
+fn _parse_create_master_edition_v3_instruction<A: ToString>(
+    accounts: &[A],
+    create_master_edition: mpl_token_metadata::instruction::CreateMasterEditionArgs,
+) -> Result<CreateMasterEditionV3Event, String> {
+    Ok(CreateMasterEditionV3Event {
+        edition: get_account(accounts, 0)?,
+        mint: get_account(accounts, 1)?,
+        update_authority: get_account(accounts, 2)?,
+        mint_authority: get_account(accounts, 3)?,
+        payer: get_account(accounts, 4)?,
+        metadata: get_account(accounts, 5)?,
+        max_supply: create_master_edition.max_supply,
+    })
+}
+
+fn _parse_create_metadata_account_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<CreateMetadataAccountEvent, String> {
+    Ok(CreateMetadataAccountEvent {
+        metadata: get_account(accounts, 0)?,
+        mint: get_account(accounts, 1)?,
+        mint_authority: get_account(accounts, 2)?,
+        payer: get_account(accounts, 3)?,
+        update_authority: get_account(accounts, 4)?,
+    })
+}
+
+fn _parse_create_metadata_account_v2_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<CreateMetadataAccountV2Event, String> {
+    Ok(CreateMetadataAccountV2Event {
+        metadata: get_account(accounts, 0)?,
+        mint: get_account(accounts, 1)?,
+        mint_authority: get_account(accounts, 2)?,
+        payer: get_account(accounts, 3)?,
+        update_authority: get_account(accounts, 4)?,
+    })
+}
+
+fn _parse_delegate_instruction<A: ToString>(
+    accounts: &[A],
+    delegate: mpl_token_metadata::instruction::DelegateArgs,
+) -> Result<DelegateEvent, String> {
+    use mpl_token_metadata::instruction::DelegateArgs;
+
+    let (amount, locked_address) = match &delegate {
+        DelegateArgs::SaleV1 { amount, .. }
+        | DelegateArgs::TransferV1 { amount, .. }
+        | DelegateArgs::UtilityV1 { amount, .. }
+        | DelegateArgs::StakingV1 { amount, .. }
+        | DelegateArgs::StandardV1 { amount } => (Some(*amount), None),
+        DelegateArgs::LockedTransferV1 { amount, locked_address, .. } => (Some(*amount), Some(locked_address.to_string())),
+        _ => (None, None),
+    };
+    let token_record = get_optional_account(accounts, 4);
+    let token_state = match (&token_record, get_token_delegate_role(&delegate)) {
+        (Some(_), Some(mpl_token_metadata::state::TokenDelegateRole::Sale)) => Some(mpl_token_metadata::state::TokenState::Listed),
+        (Some(_), Some(_)) => Some(mpl_token_metadata::state::TokenState::Unlocked),
+        _ => None,
+    };
+
+    Ok(DelegateEvent {
+        delegate_record: get_optional_account(accounts, 0),
+        delegate: get_account(accounts, 1)?,
+        metadata: get_account(accounts, 2)?,
+        master_edition: get_optional_account(accounts, 3),
+        token_record,
+        mint: get_account(accounts, 5)?,
+        token: get_optional_account(accounts, 6),
+        authority: get_account(accounts, 7)?,
+        payer: get_account(accounts, 8)?,
+        role: DelegateRole::from(&delegate).into(),
+        amount,
+        locked_address,
+        token_state: token_state.map(|x| TokenState::from(x).into()),
+        rule_set: get_optional_account(accounts, 13),
+    })
+}
+
+fn _parse_deprecated_create_master_edition_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<DeprecatedCreateMasterEditionEvent, String> {
+    Ok(DeprecatedCreateMasterEditionEvent {
+        edition: get_account(accounts, 0)?,
+        mint: get_account(accounts, 1)?,
+        printing_mint: get_account(accounts, 2)?,
+        one_time_printing_authorization_mint: get_account(accounts, 3)?,
+        update_authority: get_account(accounts, 4)?,
+        printing_mint_authority: get_account(accounts, 5)?,
+        mint_authority: get_account(accounts, 6)?,
+        metadata: get_account(accounts, 7)?,
+        payer: get_account(accounts, 8)?,
+    })
+}
+
+fn _parse_deprecated_create_reservation_list_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<DeprecatedCreateReservationListEvent, String> {
+    Ok(DeprecatedCreateReservationListEvent {
+        reservation_list: get_account(accounts, 0)?,
+        payer: get_account(accounts, 1)?,
+        update_authority: get_account(accounts, 2)?,
+        master_edition: get_account(accounts, 3)?,
+        resource: get_account(accounts, 4)?,
+        metadata: get_account(accounts, 5)?,
+    })
+}
+
+fn _parse_deprecated_mint_new_edition_from_master_edition_via_printing_token_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenEvent, String> {
+    Ok(DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenEvent {
+        metadata: get_account(accounts, 0)?,
+        edition: get_account(accounts, 1)?,
+        master_edition: get_account(accounts, 2)?,
+        mint: get_account(accounts, 3)?,
+        mint_authority: get_account(accounts, 4)?,
+        printing_mint: get_account(accounts, 5)?,
+        master_token_account: get_account(accounts, 6)?,
+        edition_marker: get_account(accounts, 7)?,
+        burn_authority: get_account(accounts, 8)?,
+        payer: get_account(accounts, 9)?,
+        master_update_authority: get_account(accounts, 10)?,
+        master_metadata: get_account(accounts, 11)?,
+        reservation_list: get_optional_account(accounts, 15),
+    })
+}
+
+fn _parse_deprecated_mint_printing_tokens_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<DeprecatedMintPrintingTokensEvent, String> {
+    Ok(DeprecatedMintPrintingTokensEvent {
+        destination: get_account(accounts, 0)?,
+        printing_mint: get_account(accounts, 1)?,
+        update_authority: get_account(accounts, 2)?,
+        metadata: get_account(accounts, 3)?,
+        master_edition: get_account(accounts, 4)?,
+    })
+}
+
+fn _parse_deprecated_mint_printing_tokens_via_token_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<DeprecatedMintPrintingTokensViaTokenEvent, String> {
+    Ok(DeprecatedMintPrintingTokensViaTokenEvent {
+        destination: get_account(accounts, 0)?,
+        token: get_account(accounts, 1)?,
+        one_time_printing_authorization_mint: get_account(accounts, 2)?,
+        printing_mint: get_account(accounts, 3)?,
+        burn_authority: get_account(accounts, 4)?,
+        metadata: get_account(accounts, 5)?,
+        master_edition: get_account(accounts, 6)?,
+    })
+}
+
+fn _parse_deprecated_set_reservation_list_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<DeprecatedSetReservationListEvent, String> {
+    Ok(DeprecatedSetReservationListEvent {
+        master_edition: get_account(accounts, 0)?,
+        reservation_list: get_account(accounts, 1)?,
+        resource: get_account(accounts, 2)?,
+    })
+}
+
+fn _parse_freeze_delegated_account_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<FreezeDelegatedAccountEvent, String> {
+    Ok(FreezeDelegatedAccountEvent {
+        delegate: get_account(accounts, 0)?,
+        token_account: get_account(accounts, 1)?,
+        edition: get_account(accounts, 2)?,
+        mint: get_account(accounts, 3)?,
+    })
+}
+
+fn _parse_lock_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<LockEvent, String> {
+    let token_record = get_optional_account(accounts, 6);
+    let token_state = token_record.as_ref().map(|_| mpl_token_metadata::state::TokenState::Locked);
+
+    Ok(LockEvent {
+        authority: get_account(accounts, 0)?,
+        token_owner: get_optional_account(accounts, 1),
+        token: get_account(accounts, 2)?,
+        mint: get_account(accounts, 3)?,
+        metadata: get_account(accounts, 4)?,
+        edition: get_optional_account(accounts, 5),
+        token_record,
+        payer: get_account(accounts, 7)?,
+        token_state: token_state.map(|x| TokenState::from(x).into()),
+        rule_set: get_optional_account(accounts, 12),
+    })
+}
+
+fn _parse_migrate_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<MigrateEvent, String> {
+    Ok(MigrateEvent {
+        metadata: get_account(accounts, 0)?,
+        edition: get_account(accounts, 1)?,
+        token: get_account(accounts, 2)?,
+        token_owner: get_account(accounts, 3)?,
+        mint: get_account(accounts, 4)?,
+        payer: get_account(accounts, 5)?,
+        authority: get_account(accounts, 6)?,
+        collection_metadata: get_account(accounts, 7)?,
+        delegate_record: get_account(accounts, 8)?,
+        token_record: get_account(accounts, 9)?,
+    })
+}
+
+fn _parse_mint_new_edition_from_master_edition_via_token_instruction<A: ToString>(
+    accounts: &[A],
+    mint_new_edition: mpl_token_metadata::instruction::MintNewEditionFromMasterEditionViaTokenArgs,
+) -> Result<MintNewEditionFromMasterEditionViaTokenEvent, String> {
+    Ok(MintNewEditionFromMasterEditionViaTokenEvent {
+        new_metadata: get_account(accounts, 0)?,
+        new_edition: get_account(accounts, 1)?,
+        master_edition: get_account(accounts, 2)?,
+        new_mint: get_account(accounts, 3)?,
+        edition_mark_pda: get_account(accounts, 4)?,
+        new_mint_authority: get_account(accounts, 5)?,
+        payer: get_account(accounts, 6)?,
+        token_account_owner: get_account(accounts, 7)?,
+        token_account: get_account(accounts, 8)?,
+        new_metadata_update_authority: get_account(accounts, 9)?,
+        metadata: get_account(accounts, 10)?,
+        edition: mint_new_edition.edition,
+    })
+}
+
+fn _parse_mint_new_edition_from_master_edition_via_vault_proxy_instruction<A: ToString>(
+    accounts: &[A],
+    mint_new_edition: mpl_token_metadata::instruction::MintNewEditionFromMasterEditionViaTokenArgs,
+) -> Result<MintNewEditionFromMasterEditionViaVaultProxyEvent, String> {
+    Ok(MintNewEditionFromMasterEditionViaVaultProxyEvent {
+        new_metadata: get_account(accounts, 0)?,
+        new_edition: get_account(accounts, 1)?,
+        master_edition: get_account(accounts, 2)?,
+        new_mint: get_account(accounts, 3)?,
+        edition_mark_pda: get_account(accounts, 4)?,
+        new_mint_authority: get_account(accounts, 5)?,
+        payer: get_account(accounts, 6)?,
+        vault_authority: get_account(accounts, 7)?,
+        safety_deposit_store: get_account(accounts, 8)?,
+        safety_deposit_box: get_account(accounts, 9)?,
+        vault: get_account(accounts, 10)?,
+        new_metadata_update_authority: get_account(accounts, 11)?,
+        metadata: get_account(accounts, 12)?,
+        edition: mint_new_edition.edition,
+    })
+}
+
+fn _parse_puff_metadata_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<PuffMetadataEvent, String> {
+    Ok(PuffMetadataEvent {
+        metadata: get_account(accounts, 0)?,
+    })
+}
+
+fn _parse_remove_creator_verification_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<RemoveCreatorVerificationEvent, String> {
+    Ok(RemoveCreatorVerificationEvent {
+        metadata: get_account(accounts, 0)?,
+        creator: get_account(accounts, 1)?,
+    })
+}
+
+fn _parse_revoke_instruction<A: ToString>(
+    accounts: &[A],
+    revoke: mpl_token_metadata::instruction::RevokeArgs,
+) -> Result<RevokeEvent, String> {
+    let token_record = get_optional_account(accounts, 4);
+    // Revoking a token delegate resets the token record.
+    let token_state = match (&token_record, get_revoked_token_delegate_role(&revoke)) {
+        (Some(_), Some(_)) => Some(mpl_token_metadata::state::TokenState::Unlocked),
+        _ => None,
+    };
+
+    Ok(RevokeEvent {
+        delegate_record: get_optional_account(accounts, 0),
+        delegate: get_account(accounts, 1)?,
+        metadata: get_account(accounts, 2)?,
+        master_edition: get_optional_account(accounts, 3),
+        token_record,
+        mint: get_account(accounts, 5)?,
+        token: get_optional_account(accounts, 6),
+        authority: get_account(accounts, 7)?,
+        payer: get_account(accounts, 8)?,
+        role: DelegateRole::from(&revoke).into(),
+        token_state: token_state.map(|x| TokenState::from(x).into()),
+        rule_set: get_optional_account(accounts, 13),
+    })
+}
+
+fn _parse_revoke_collection_authority_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<RevokeCollectionAuthorityEvent, String> {
+    Ok(RevokeCollectionAuthorityEvent {
+        collection_authority_record: get_account(accounts, 0)?,
+        delegate_authority: get_account(accounts, 1)?,
+        revoke_authority: get_account(accounts, 2)?,
+        metadata: get_account(accounts, 3)?,
+        mint: get_account(accounts, 4)?,
+    })
+}
+
+fn _parse_revoke_use_authority_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<RevokeUseAuthorityEvent, String> {
+    Ok(RevokeUseAuthorityEvent {
+        use_authority_record: get_account(accounts, 0)?,
+        owner: get_account(accounts, 1)?,
+        user: get_account(accounts, 2)?,
+        owner_token_account: get_account(accounts, 3)?,
+        mint: get_account(accounts, 4)?,
+        metadata: get_account(accounts, 5)?,
+    })
+}
+
+fn _parse_set_and_verify_collection_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<SetAndVerifyCollectionEvent, String> {
+    Ok(SetAndVerifyCollectionEvent {
+        metadata: get_account(accounts, 0)?,
+        collection_authority: get_account(accounts, 1)?,
+        payer: get_account(accounts, 2)?,
+        update_authority: get_account(accounts, 3)?,
+        collection_mint: get_account(accounts, 4)?,
+        collection_metadata: get_account(accounts, 5)?,
+        collection_master_edition: get_account(accounts, 6)?,
+        collection_authority_record: get_optional_account(accounts, 7),
+    })
+}
+
+fn _parse_set_and_verify_sized_collection_item_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<SetAndVerifySizedCollectionItemEvent, String> {
+    Ok(SetAndVerifySizedCollectionItemEvent {
+        metadata: get_account(accounts, 0)?,
+        collection_authority: get_account(accounts, 1)?,
+        payer: get_account(accounts, 2)?,
+        update_authority: get_account(accounts, 3)?,
+        collection_mint: get_account(accounts, 4)?,
+        collection_metadata: get_account(accounts, 5)?,
+        collection_master_edition: get_account(accounts, 6)?,
+        collection_authority_record: get_optional_account(accounts, 7),
+    })
+}
+
+fn _parse_set_token_standard_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<SetTokenStandardEvent, String> {
+    Ok(SetTokenStandardEvent {
+        metadata: get_account(accounts, 0)?,
+        update_authority: get_account(accounts, 1)?,
+        mint: get_account(accounts, 2)?,
+        edition: get_optional_account(accounts, 3),
+    })
+}
+
+fn _parse_sign_metadata_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<SignMetadataEvent, String> {
+    Ok(SignMetadataEvent {
+        metadata: get_account(accounts, 0)?,
+        creator: get_account(accounts, 1)?,
+    })
+}
+
+fn _parse_thaw_delegated_account_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<ThawDelegatedAccountEvent, String> {
+    Ok(ThawDelegatedAccountEvent {
+        delegate: get_account(accounts, 0)?,
+        token_account: get_account(accounts, 1)?,
+        edition: get_account(accounts, 2)?,
+        mint: get_account(accounts, 3)?,
+    })
+}
+
+fn _parse_transfer_instruction<A: ToString>(
+    accounts: &[A],
+    transfer: mpl_token_metadata::instruction::TransferArgs,
+) -> Result<TransferEvent, String> {
+    let amount = match transfer {
+        mpl_token_metadata::instruction::TransferArgs::V1 { amount, .. } => amount,
+    };
+    let destination_token_record = get_optional_account(accounts, 8);
+    // The destination token record is created or reset by the transfer.
+    let token_state = destination_token_record.as_ref().map(|_| mpl_token_metadata::state::TokenState::Unlocked);
+
+    Ok(TransferEvent {
+        token: get_account(accounts, 0)?,
+        token_owner: get_account(accounts, 1)?,
+        destination: get_account(accounts, 2)?,
+        destination_owner: get_account(accounts, 3)?,
+        mint: get_account(accounts, 4)?,
+        metadata: get_account(accounts, 5)?,
+        edition: get_optional_account(accounts, 6),
+        owner_token_record: get_optional_account(accounts, 7),
+        destination_token_record,
+        authority: get_account(accounts, 9)?,
+        payer: get_account(accounts, 10)?,
+        amount,
+        token_state: token_state.map(|x| TokenState::from(x).into()),
+        rule_set: get_optional_account(accounts, 16),
+    })
+}
+
+fn _parse_transfer_out_of_escrow_instruction<A: ToString>(
+    accounts: &[A],
+    transfer_out_of_escrow: mpl_token_metadata::instruction::TransferOutOfEscrowArgs,
+) -> Result<TransferOutOfEscrowEvent, String> {
+    Ok(TransferOutOfEscrowEvent {
+        escrow: get_account(accounts, 0)?,
+        metadata: get_account(accounts, 1)?,
+        payer: get_account(accounts, 2)?,
+        attribute_mint: get_account(accounts, 3)?,
+        attribute_src: get_account(accounts, 4)?,
+        attribute_dst: get_account(accounts, 5)?,
+        escrow_mint: get_account(accounts, 6)?,
+        escrow_account: get_account(accounts, 7)?,
+        authority: get_optional_account(accounts, 12),
+        amount: transfer_out_of_escrow.amount,
+    })
+}
+
+fn _parse_unlock_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<UnlockEvent, String> {
+    let token_record = get_optional_account(accounts, 6);
+    let token_state = token_record.as_ref().map(|_| mpl_token_metadata::state::TokenState::Unlocked);
+
+    Ok(UnlockEvent {
+        authority: get_account(accounts, 0)?,
+        token_owner: get_optional_account(accounts, 1),
+        token: get_account(accounts, 2)?,
+        mint: get_account(accounts, 3)?,
+        metadata: get_account(accounts, 4)?,
+        edition: get_optional_account(accounts, 5),
+        token_record,
+        payer: get_account(accounts, 7)?,
+        token_state: token_state.map(|x| TokenState::from(x).into()),
+        rule_set: get_optional_account(accounts, 12),
+    })
+}
+
+fn _parse_unverify_instruction<A: ToString>(
+    accounts: &[A],
+    unverify: mpl_token_metadata::instruction::VerificationArgs,
+) -> Result<UnverifyEvent, String> {
+    Ok(UnverifyEvent {
+        authority: get_account(accounts, 0)?,
+        delegate_record: get_optional_account(accounts, 1),
+        metadata: get_account(accounts, 2)?,
+        collection_mint: get_optional_account(accounts, 3),
+        collection_metadata: get_optional_account(accounts, 4),
+        verification_type: VerificationType::from(unverify).into(),
+    })
+}
+
+fn _parse_unverify_collection_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<UnverifyCollectionEvent, String> {
+    Ok(UnverifyCollectionEvent {
+        metadata: get_account(accounts, 0)?,
+        collection_authority: get_account(accounts, 1)?,
+        collection_mint: get_account(accounts, 2)?,
+        collection_metadata: get_account(accounts, 3)?,
+        collection_master_edition: get_account(accounts, 4)?,
+        collection_authority_record: get_optional_account(accounts, 5),
+    })
+}
+
+fn _parse_unverify_sized_collection_item_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<UnverifySizedCollectionItemEvent, String> {
+    Ok(UnverifySizedCollectionItemEvent {
+        metadata: get_account(accounts, 0)?,
+        collection_authority: get_account(accounts, 1)?,
+        payer: get_account(accounts, 2)?,
+        collection_mint: get_account(accounts, 3)?,
+        collection_metadata: get_account(accounts, 4)?,
+        collection_master_edition: get_account(accounts, 5)?,
+        collection_authority_record: get_optional_account(accounts, 6),
+    })
+}
+
+fn _parse_update_metadata_account_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<UpdateMetadataAccountEvent, String> {
+    Ok(UpdateMetadataAccountEvent {
+        metadata: get_account(accounts, 0)?,
+        update_authority: get_account(accounts, 1)?,
+    })
+}
+
+fn _parse_update_primary_sale_happened_via_token_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<UpdatePrimarySaleHappenedViaTokenEvent, String> {
+    Ok(UpdatePrimarySaleHappenedViaTokenEvent {
+        metadata: get_account(accounts, 0)?,
+        owner: get_account(accounts, 1)?,
+        token: get_account(accounts, 2)?,
+    })
+}
+
+fn _parse_utilize_instruction<A: ToString>(
+    accounts: &[A],
+    utilize: mpl_token_metadata::instruction::UtilizeArgs,
+) -> Result<UtilizeEvent, String> {
+    Ok(UtilizeEvent {
+        metadata: get_account(accounts, 0)?,
+        token_account: get_account(accounts, 1)?,
+        mint: get_account(accounts, 2)?,
+        use_authority: get_account(accounts, 3)?,
+        owner: get_account(accounts, 4)?,
+        use_authority_record: get_optional_account(accounts, 9),
+        burner: get_optional_account(accounts, 10),
+        number_of_uses: utilize.number_of_uses,
+    })
+}
+
+fn _parse_print_instruction<A: ToString>(
+    accounts: &[A],
+    print: mpl_token_metadata::instruction::PrintArgs,
+) -> Result<PrintEvent, String> {
+    let edition_number = match print {
+        mpl_token_metadata::instruction::PrintArgs::V1 { edition } => edition,
+        mpl_token_metadata::instruction::PrintArgs::V2 { edition } => edition,
+    };
+
+    Ok(PrintEvent {
+        edition_metadata: get_account(accounts, 0)?,
+        edition: get_account(accounts, 1)?,
+        edition_mint: get_account(accounts, 2)?,
+        edition_token_account_owner: get_account(accounts, 3)?,
+        edition_token_account: get_account(accounts, 4)?,
+        edition_mint_authority: get_account(accounts, 5)?,
+        edition_token_record: get_optional_account(accounts, 6),
+        master_edition: get_account(accounts, 7)?,
+        edition_marker_pda: get_account(accounts, 8)?,
+        payer: get_account(accounts, 9)?,
+        master_token_account_owner: get_account(accounts, 10)?,
+        master_token_account: get_account(accounts, 11)?,
+        master_metadata: get_account(accounts, 12)?,
+        update_authority: get_account(accounts, 13)?,
+        holder_delegate_record: get_optional_account(accounts, 18),
+        delegate: get_optional_account(accounts, 19),
+        edition_number,
+    })
+}
+
+fn _parse_verify_instruction<A: ToString>(
+    accounts: &[A],
+    verify: mpl_token_metadata::instruction::VerificationArgs,
+) -> Result<VerifyEvent, String> {
+    Ok(VerifyEvent {
+        authority: get_account(accounts, 0)?,
+        delegate_record: get_optional_account(accounts, 1),
+        metadata: get_account(accounts, 2)?,
+        collection_mint: get_optional_account(accounts, 3),
+        collection_metadata: get_optional_account(accounts, 4),
+        collection_master_edition: get_optional_account(accounts, 5),
+        verification_type: VerificationType::from(verify).into(),
+    })
+}
+
+fn _parse_mint_instruction<A: ToString>(
+    accounts: &[A],
+    mint: mpl_token_metadata::instruction::MintArgs,
+) -> Result<MintEvent, String> {
+    let amount = match mint {
+        mpl_token_metadata::instruction::MintArgs::V1 { amount, .. } => amount,
+    };
+
+    Ok(MintEvent {
+        token: get_account(accounts, 0)?,
+        token_owner: get_optional_account(accounts, 1),
+        metadata: get_account(accounts, 2)?,
+        master_edition: get_optional_account(accounts, 3),
+        token_record: get_optional_account(accounts, 4),
+        mint: get_account(accounts, 5)?,
+        authority: get_account(accounts, 6)?,
+        delegate_record: get_optional_account(accounts, 7),
+        payer: get_account(accounts, 8)?,
+        amount,
+    })
+}
+
+fn _parse_set_collection_size_instruction<A: ToString>(
+    accounts: &[A],
+    set_collection_size: mpl_token_metadata::instruction::SetCollectionSizeArgs,
+) -> Result<SetCollectionSizeEvent, String> {
+    Ok(SetCollectionSizeEvent {
+        collection_metadata: get_account(accounts, 0)?,
+        collection_authority: get_account(accounts, 1)?,
+        collection_mint: get_account(accounts, 2)?,
+        collection_authority_record: get_optional_account(accounts, 3),
+        size: set_collection_size.size,
+    })
+}
+
+fn _parse_collect_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<CollectEvent, String> {
+    Ok(CollectEvent {
+        authority: get_account(accounts, 0)?,
+        recipient: get_account(accounts, 1)?,
+    })
+}
+
+fn _parse_use_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<UseEvent, String> {
+    Ok(UseEvent {
+        authority: get_account(accounts, 0)?,
+        delegate_record: get_optional_account(accounts, 1),
+        token: get_optional_account(accounts, 2),
+        mint: get_account(accounts, 3)?,
+        metadata: get_account(accounts, 4)?,
+        edition: get_optional_account(accounts, 5),
+        payer: get_account(accounts, 6)?,
+    })
+}
+
+fn _parse_verify_sized_collection_item_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<VerifySizedCollectionItemEvent, String> {
+    Ok(VerifySizedCollectionItemEvent {
+        metadata: get_account(accounts, 0)?,
+        collection_authority: get_account(accounts, 1)?,
+        payer: get_account(accounts, 2)?,
+        collection_mint: get_account(accounts, 3)?,
+        collection_metadata: get_account(accounts, 4)?,
+        collection_master_edition: get_account(accounts, 5)?,
+        collection_authority_record: get_optional_account(accounts, 6),
+    })
+}
+
+fn _parse_verify_collection_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<VerifyCollectionEvent, String> {
+    Ok(VerifyCollectionEvent {
+        metadata: get_account(accounts, 0)?,
+        collection_authority: get_account(accounts, 1)?,
+        payer: get_account(accounts, 2)?,
+        collection_mint: get_account(accounts, 3)?,
+        collection_metadata: get_account(accounts, 4)?,
+        collection_master_edition: get_account(accounts, 5)?,
+        collection_authority_record: get_optional_account(accounts, 6),
+    })
+}
+
+fn _parse_resize_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<ResizeEvent, String> {
+    Ok(ResizeEvent {
+        metadata: get_account(accounts, 0)?,
+        edition: get_account(accounts, 1)?,
+        mint: get_account(accounts, 2)?,
+        payer: get_account(accounts, 3)?,
+        authority: get_optional_account(accounts, 4),
+        token: get_optional_account(accounts, 5),
+    })
+}
+
+fn _parse_close_accounts_instruction<A: ToString>(
+    accounts: &[A],
+) -> Result<CloseAccountsEvent, String> {
+    Ok(CloseAccountsEvent {
+        metadata: get_account(accounts, 0)?,
+        edition: get_account(accounts, 1)?,
+        mint: get_account(accounts, 2)?,
+        authority: get_account(accounts, 3)?,
+        destination: get_account(accounts, 4)?,
+    })
+}
+
+fn _parse_update_instruction<A: ToString>(
+    accounts: &[A],
+    update: mpl_token_metadata::instruction::UpdateArgs,
+) -> Result<UpdateEvent, String> {
+    use mpl_token_metadata::instruction::UpdateArgs;
+
+    let delegate_record = get_optional_account(accounts, 1);
+
+    let (authority_type, delegate_role) = match &update {
+        // The role of a V1 delegate is only recorded in the delegate record's data, and the record address
+        // can't be matched against the role seeds since the update authority isn't among the accounts.
+        UpdateArgs::V1 { .. } if delegate_record.is_some() => (UpdateAuthorityType::Delegate, None),
+        UpdateArgs::V1 { .. } | UpdateArgs::AsUpdateAuthorityV2 { .. } => (UpdateAuthorityType::UpdateAuthority, None),
+        UpdateArgs::AsAuthorityItemDelegateV2 { .. } => (UpdateAuthorityType::Delegate, Some(DelegateRole::AuthorityItem)),
+        UpdateArgs::AsCollectionDelegateV2 { .. } => (UpdateAuthorityType::Delegate, Some(DelegateRole::Collection)),
+        UpdateArgs::AsDataDelegateV2 { .. } => (UpdateAuthorityType::Delegate, Some(DelegateRole::Data)),
+        UpdateArgs::AsProgrammableConfigDelegateV2 { .. } => (UpdateAuthorityType::Delegate, Some(DelegateRole::ProgrammableConfig)),
+        UpdateArgs::AsDataItemDelegateV2 { .. } => (UpdateAuthorityType::Delegate, Some(DelegateRole::DataItem)),
+        UpdateArgs::AsCollectionItemDelegateV2 { .. } => (UpdateAuthorityType::Delegate, Some(DelegateRole::CollectionItem)),
+        UpdateArgs::AsProgrammableConfigItemDelegateV2 { .. } => (UpdateAuthorityType::Delegate, Some(DelegateRole::ProgrammableConfigItem)),
+    };
+
+    let update_args = match update {
+        UpdateArgs::V1 { new_update_authority, data, primary_sale_happened, is_mutable, collection, collection_details, uses, rule_set, authorization_data } => {
+            update_event::UpdateArgs::V1(UpdateArgsV1 {
+                new_update_authority: new_update_authority.map(|x| x.to_string()),
+                data: data.map(|x| x.into()),
+                primary_sale_happened,
+                is_mutable,
+                collection: Some(collection.into()),
+                collection_details: Some(collection_details.into()),
+                uses: Some(uses.into()),
+                rule_set: Some(rule_set.into()),
+                authorization_data: authorization_data.map(|x| x.into()),
+            })
+        },
+        UpdateArgs::AsUpdateAuthorityV2 { new_update_authority, data, primary_sale_happened, is_mutable, collection, collection_details, uses, rule_set, token_standard, authorization_data } => {
+            update_event::UpdateArgs::AsUpdateAuthorityV2(UpdateArgsAsUpdateAuthorityV2 {
+                new_update_authority: new_update_authority.map(|x| x.to_string()),
+                data: data.map(|x| x.into()),
+                primary_sale_happened,
+                is_mutable,
+                collection: Some(collection.into()),
+                collection_details: Some(collection_details.into()),
+                uses: Some(uses.into()),
+                rule_set: Some(rule_set.into()),
+                token_standard: token_standard.map(|x| TokenStandard::from(x).into()),
+                authorization_data: authorization_data.map(|x| x.into()),
+            })
+        },
+        UpdateArgs::AsAuthorityItemDelegateV2 { new_update_authority, primary_sale_happened, is_mutable, token_standard, authorization_data } => {
+            update_event::UpdateArgs::AsAuthorityItemDelegateV2(UpdateArgsAsAuthorityItemDelegateV2 {
+                new_update_authority: new_update_authority.map(|x| x.to_string()),
+                primary_sale_happened,
+                is_mutable,
+                token_standard: token_standard.map(|x| TokenStandard::from(x).into()),
+                authorization_data: authorization_data.map(|x| x.into()),
+            })
+        },
+        UpdateArgs::AsCollectionDelegateV2 { collection, authorization_data } => {
+            update_event::UpdateArgs::AsCollectionDelegateV2(UpdateArgsAsCollectionDelegateV2 {
+                collection: Some(collection.into()),
+                authorization_data: authorization_data.map(|x| x.into()),
+            })
+        },
+        UpdateArgs::AsDataDelegateV2 { data, authorization_data } => {
+            update_event::UpdateArgs::AsDataDelegateV2(UpdateArgsAsDataDelegateV2 {
+                data: data.map(|x| x.into()),
+                authorization_data: authorization_data.map(|x| x.into()),
+            })
+        },
+        UpdateArgs::AsProgrammableConfigDelegateV2 { rule_set, authorization_data } => {
+            update_event::UpdateArgs::AsProgrammableConfigDelegateV2(UpdateArgsAsProgrammableConfigDelegateV2 {
+                rule_set: Some(rule_set.into()),
+                authorization_data: authorization_data.map(|x| x.into()),
+            })
+        },
+        UpdateArgs::AsDataItemDelegateV2 { data, authorization_data } => {
+            update_event::UpdateArgs::AsDataItemDelegateV2(UpdateArgsAsDataItemDelegateV2 {
+                data: data.map(|x| x.into()),
+                authorization_data: authorization_data.map(|x| x.into()),
+            })
+        },
+        UpdateArgs::AsCollectionItemDelegateV2 { collection, authorization_data } => {
+            update_event::UpdateArgs::AsCollectionItemDelegateV2(UpdateArgsAsCollectionItemDelegateV2 {
+                collection: Some(collection.into()),
+                authorization_data: authorization_data.map(|x| x.into()),
+            })
+        },
+        UpdateArgs::AsProgrammableConfigItemDelegateV2 { rule_set, authorization_data } => {
+            update_event::UpdateArgs::AsProgrammableConfigItemDelegateV2(UpdateArgsAsProgrammableConfigItemDelegateV2 {
+                rule_set: Some(rule_set.into()),
+                authorization_data: authorization_data.map(|x| x.into()),
+            })
+        },
+    };
+
+    Ok(UpdateEvent {
+        authority: get_account(accounts, 0)?,
+        delegate_record,
+        token: get_optional_account(accounts, 2),
+        mint: get_account(accounts, 3)?,
+        metadata: get_account(accounts, 4)?,
+        edition: get_optional_account(accounts, 5),
+        payer: get_account(accounts, 6)?,
+        authority_type: authority_type.into(),
+        delegate_role: delegate_role.map(|x| x.into()),
+        update_args: Some(update_args),
+    })
+}
+
+fn _parse_update_metadata_account_v2_instruction<A: ToString>(
+    accounts: &[A],
+    update_metadata_account_v2: mpl_token_metadata::instruction::UpdateMetadataAccountArgsV2,
+) -> Result<UpdateMetadataAccountV2Event, String> {
+    Ok(UpdateMetadataAccountV2Event {
+        metadata: get_account(accounts, 0)?,
+        update_authority: get_account(accounts, 1)?,
+        data: update_metadata_account_v2.data.map(|x| x.into()),
+        new_update_authority: update_metadata_account_v2.update_authority.map(|x| x.to_string()),
+        primary_sale_happened: update_metadata_account_v2.primary_sale_happened,
+        is_mutable: update_metadata_account_v2.is_mutable,
+    })
+}
+
+/// Required account at `index`, or an error if the instruction has fewer accounts.
+fn get_account<A: ToString>(accounts: &[A], index: usize) -> Result<String, String> {
+    accounts
+        .get(index)
+        .map(|x| x.to_string())
+        .ok_or_else(|| format!("Missing instruction account {}.", index))
+}
+
+/// Optional accounts are either left out, for legacy instructions, or replaced by the program id.
+fn get_optional_account<A: ToString>(accounts: &[A], index: usize) -> Option<String> {
+    accounts
+        .get(index)
+        .map(|x| x.to_string())
+        .filter(|x| *x != MPL_TOKEN_METADATA_PROGRAM_ID.to_string())
+}
+
+/// Role of the delegate if it's a token delegate, which is recorded in the token record of programmable assets.
+fn get_token_delegate_role(delegate: &mpl_token_metadata::instruction::DelegateArgs) -> Option<mpl_token_metadata::state::TokenDelegateRole> {
+    use mpl_token_metadata::instruction::DelegateArgs;
+    use mpl_token_metadata::state::TokenDelegateRole;
+
+    match delegate {
+        DelegateArgs::SaleV1 { .. } => Some(TokenDelegateRole::Sale),
+        DelegateArgs::TransferV1 { .. } => Some(TokenDelegateRole::Transfer),
+        DelegateArgs::UtilityV1 { .. } => Some(TokenDelegateRole::Utility),
+        DelegateArgs::StakingV1 { .. } => Some(TokenDelegateRole::Staking),
+        DelegateArgs::StandardV1 { .. } => Some(TokenDelegateRole::Standard),
+        DelegateArgs::LockedTransferV1 { .. } => Some(TokenDelegateRole::LockedTransfer),
+        _ => None,
+    }
+}
+
+fn get_revoked_token_delegate_role(revoke: &mpl_token_metadata::instruction::RevokeArgs) -> Option<mpl_token_metadata::state::TokenDelegateRole> {
+    use mpl_token_metadata::instruction::RevokeArgs;
+    use mpl_token_metadata::state::TokenDelegateRole;
+
+    match revoke {
+        RevokeArgs::SaleV1 => Some(TokenDelegateRole::Sale),
+        RevokeArgs::TransferV1 => Some(TokenDelegateRole::Transfer),
+        RevokeArgs::UtilityV1 => Some(TokenDelegateRole::Utility),
+        RevokeArgs::StakingV1 => Some(TokenDelegateRole::Staking),
+        RevokeArgs::StandardV1 => Some(TokenDelegateRole::Standard),
+        RevokeArgs::LockedTransferV1 => Some(TokenDelegateRole::LockedTransfer),
+        RevokeArgs::MigrationV1 => Some(TokenDelegateRole::Migration),
+        _ => None,
+    }
+}
 
 impl From<mpl_token_metadata::state::Data> for Data {
     fn from(value: mpl_token_metadata::state::Data) -> Self {
@@ -441,3 +1378,543 @@ impl From<mpl_token_metadata::state::CollectionDetails> for CollectionDetails {
         }
     }
 }
+
+impl From<mpl_token_metadata::state::AssetData> for AssetData {
+    fn from(value: mpl_token_metadata::state::AssetData) -> Self {
+        AssetData {
+            name: value.name,
+            symbol: value.symbol,
+            uri: value.uri,
+            seller_fee_basis_points: value.seller_fee_basis_points.into(),
+            creators: value.creators.unwrap_or_else(Vec::new).iter().map(|x| x.into()).collect(),
+            primary_sale_happened: value.primary_sale_happened,
+            is_mutable: value.is_mutable,
+            token_standard: TokenStandard::from(value.token_standard).into(),
+            collection: value.collection.map(|x| x.into()),
+            uses: value.uses.map(|x| x.into()),
+            collection_details: value.collection_details.map(|x| x.into()),
+            rule_set: value.rule_set.map(|x| x.to_string()),
+        }
+    }
+}
+
+impl From<mpl_token_metadata::state::TokenStandard> for TokenStandard {
+    fn from(value: mpl_token_metadata::state::TokenStandard) -> Self {
+        match value {
+            mpl_token_metadata::state::TokenStandard::NonFungible => Self::NonFungible,
+            mpl_token_metadata::state::TokenStandard::FungibleAsset => Self::FungibleAsset,
+            mpl_token_metadata::state::TokenStandard::Fungible => Self::Fungible,
+            mpl_token_metadata::state::TokenStandard::NonFungibleEdition => Self::NonFungibleEdition,
+            mpl_token_metadata::state::TokenStandard::ProgrammableNonFungible => Self::ProgrammableNonFungible,
+            mpl_token_metadata::state::TokenStandard::ProgrammableNonFungibleEdition => Self::ProgrammableNonFungibleEdition,
+        }
+    }
+}
+
+impl From<mpl_token_metadata::state::PrintSupply> for PrintSupply {
+    fn from(value: mpl_token_metadata::state::PrintSupply) -> Self {
+        let max_supply = match value {
+            mpl_token_metadata::state::PrintSupply::Zero => Some(0),
+            mpl_token_metadata::state::PrintSupply::Limited(supply) => Some(supply),
+            mpl_token_metadata::state::PrintSupply::Unlimited => None,
+        };
+        PrintSupply { max_supply }
+    }
+}
+
+impl From<&mpl_token_metadata::instruction::DelegateArgs> for DelegateRole {
+    fn from(value: &mpl_token_metadata::instruction::DelegateArgs) -> Self {
+        match value {
+            mpl_token_metadata::instruction::DelegateArgs::CollectionV1 { .. } => Self::Collection,
+            mpl_token_metadata::instruction::DelegateArgs::SaleV1 { .. } => Self::Sale,
+            mpl_token_metadata::instruction::DelegateArgs::TransferV1 { .. } => Self::Transfer,
+            mpl_token_metadata::instruction::DelegateArgs::DataV1 { .. } => Self::Data,
+            mpl_token_metadata::instruction::DelegateArgs::UtilityV1 { .. } => Self::Utility,
+            mpl_token_metadata::instruction::DelegateArgs::StakingV1 { .. } => Self::Staking,
+            mpl_token_metadata::instruction::DelegateArgs::StandardV1 { .. } => Self::Standard,
+            mpl_token_metadata::instruction::DelegateArgs::LockedTransferV1 { .. } => Self::LockedTransfer,
+            mpl_token_metadata::instruction::DelegateArgs::ProgrammableConfigV1 { .. } => Self::ProgrammableConfig,
+            mpl_token_metadata::instruction::DelegateArgs::AuthorityItemV1 { .. } => Self::AuthorityItem,
+            mpl_token_metadata::instruction::DelegateArgs::DataItemV1 { .. } => Self::DataItem,
+            mpl_token_metadata::instruction::DelegateArgs::CollectionItemV1 { .. } => Self::CollectionItem,
+            mpl_token_metadata::instruction::DelegateArgs::ProgrammableConfigItemV1 { .. } => Self::ProgrammableConfigItem,
+            mpl_token_metadata::instruction::DelegateArgs::PrintDelegateV1 { .. } => Self::PrintDelegate,
+        }
+    }
+}
+
+impl From<&mpl_token_metadata::instruction::RevokeArgs> for DelegateRole {
+    fn from(value: &mpl_token_metadata::instruction::RevokeArgs) -> Self {
+        match value {
+            mpl_token_metadata::instruction::RevokeArgs::CollectionV1 => Self::Collection,
+            mpl_token_metadata::instruction::RevokeArgs::SaleV1 => Self::Sale,
+            mpl_token_metadata::instruction::RevokeArgs::TransferV1 => Self::Transfer,
+            mpl_token_metadata::instruction::RevokeArgs::DataV1 => Self::Data,
+            mpl_token_metadata::instruction::RevokeArgs::UtilityV1 => Self::Utility,
+            mpl_token_metadata::instruction::RevokeArgs::StakingV1 => Self::Staking,
+            mpl_token_metadata::instruction::RevokeArgs::StandardV1 => Self::Standard,
+            mpl_token_metadata::instruction::RevokeArgs::LockedTransferV1 => Self::LockedTransfer,
+            mpl_token_metadata::instruction::RevokeArgs::ProgrammableConfigV1 => Self::ProgrammableConfig,
+            mpl_token_metadata::instruction::RevokeArgs::MigrationV1 => Self::Migration,
+            mpl_token_metadata::instruction::RevokeArgs::AuthorityItemV1 => Self::AuthorityItem,
+            mpl_token_metadata::instruction::RevokeArgs::DataItemV1 => Self::DataItem,
+            mpl_token_metadata::instruction::RevokeArgs::CollectionItemV1 => Self::CollectionItem,
+            mpl_token_metadata::instruction::RevokeArgs::ProgrammableConfigItemV1 => Self::ProgrammableConfigItem,
+            mpl_token_metadata::instruction::RevokeArgs::PrintDelegateV1 => Self::PrintDelegate,
+        }
+    }
+}
+
+impl From<mpl_token_metadata::state::TokenState> for TokenState {
+    fn from(value: mpl_token_metadata::state::TokenState) -> Self {
+        match value {
+            mpl_token_metadata::state::TokenState::Unlocked => Self::Unlocked,
+            mpl_token_metadata::state::TokenState::Locked => Self::Locked,
+            mpl_token_metadata::state::TokenState::Listed => Self::Listed,
+        }
+    }
+}
+
+impl From<mpl_token_metadata::state::TokenDelegateRole> for DelegateRole {
+    fn from(value: mpl_token_metadata::state::TokenDelegateRole) -> Self {
+        match value {
+            mpl_token_metadata::state::TokenDelegateRole::Sale => Self::Sale,
+            mpl_token_metadata::state::TokenDelegateRole::Transfer => Self::Transfer,
+            mpl_token_metadata::state::TokenDelegateRole::Utility => Self::Utility,
+            mpl_token_metadata::state::TokenDelegateRole::Staking => Self::Staking,
+            mpl_token_metadata::state::TokenDelegateRole::Standard => Self::Standard,
+            mpl_token_metadata::state::TokenDelegateRole::LockedTransfer => Self::LockedTransfer,
+            mpl_token_metadata::state::TokenDelegateRole::Migration => Self::Migration,
+        }
+    }
+}
+
+impl From<mpl_token_metadata::instruction::VerificationArgs> for VerificationType {
+    fn from(value: mpl_token_metadata::instruction::VerificationArgs) -> Self {
+        match value {
+            mpl_token_metadata::instruction::VerificationArgs::CreatorV1 => Self::CreatorV1,
+            mpl_token_metadata::instruction::VerificationArgs::CollectionV1 => Self::CollectionV1,
+        }
+    }
+}
+
+impl From<mpl_token_metadata::instruction::CollectionToggle> for CollectionToggle {
+    fn from(value: mpl_token_metadata::instruction::CollectionToggle) -> Self {
+        match value {
+            mpl_token_metadata::instruction::CollectionToggle::None => Self { toggle: Toggle::None.into(), collection: None },
+            mpl_token_metadata::instruction::CollectionToggle::Clear => Self { toggle: Toggle::Clear.into(), collection: None },
+            mpl_token_metadata::instruction::CollectionToggle::Set(collection) => Self { toggle: Toggle::Set.into(), collection: Some(collection.into()) },
+        }
+    }
+}
+
+impl From<mpl_token_metadata::instruction::CollectionDetailsToggle> for CollectionDetailsToggle {
+    fn from(value: mpl_token_metadata::instruction::CollectionDetailsToggle) -> Self {
+        match value {
+            mpl_token_metadata::instruction::CollectionDetailsToggle::None => Self { toggle: Toggle::None.into(), collection_details: None },
+            mpl_token_metadata::instruction::CollectionDetailsToggle::Clear => Self { toggle: Toggle::Clear.into(), collection_details: None },
+            mpl_token_metadata::instruction::CollectionDetailsToggle::Set(collection_details) => Self { toggle: Toggle::Set.into(), collection_details: Some(collection_details.into()) },
+        }
+    }
+}
+
+impl From<mpl_token_metadata::instruction::UsesToggle> for UsesToggle {
+    fn from(value: mpl_token_metadata::instruction::UsesToggle) -> Self {
+        match value {
+            mpl_token_metadata::instruction::UsesToggle::None => Self { toggle: Toggle::None.into(), uses: None },
+            mpl_token_metadata::instruction::UsesToggle::Clear => Self { toggle: Toggle::Clear.into(), uses: None },
+            mpl_token_metadata::instruction::UsesToggle::Set(uses) => Self { toggle: Toggle::Set.into(), uses: Some(uses.into()) },
+        }
+    }
+}
+
+impl From<mpl_token_metadata::instruction::RuleSetToggle> for RuleSetToggle {
+    fn from(value: mpl_token_metadata::instruction::RuleSetToggle) -> Self {
+        match value {
+            mpl_token_metadata::instruction::RuleSetToggle::None => Self { toggle: Toggle::None.into(), rule_set: None },
+            mpl_token_metadata::instruction::RuleSetToggle::Clear => Self { toggle: Toggle::Clear.into(), rule_set: None },
+            mpl_token_metadata::instruction::RuleSetToggle::Set(rule_set) => Self { toggle: Toggle::Set.into(), rule_set: Some(rule_set.to_string()) },
+        }
+    }
+}
+
+impl From<mpl_token_metadata::processor::AuthorizationData> for AuthorizationData {
+    fn from(value: mpl_token_metadata::processor::AuthorizationData) -> Self {
+        let mut payload: Vec<PayloadEntry> = value
+            .payload
+            .inner()
+            .iter()
+            .map(|(key, value)| PayloadEntry {
+                key: key.clone(),
+                value: Some(value.into()),
+            })
+            .collect();
+        // The payload is a map, so its entries are sorted to keep the output deterministic.
+        payload.sort_by(|a, b| a.key.cmp(&b.key));
+        Self { payload }
+    }
+}
+
+impl From<&mpl_token_metadata::processor::PayloadType> for payload_entry::Value {
+    fn from(value: &mpl_token_metadata::processor::PayloadType) -> Self {
+        match value {
+            mpl_token_metadata::processor::PayloadType::Pubkey(pubkey) => Self::Pubkey(pubkey.to_string()),
+            mpl_token_metadata::processor::PayloadType::Seeds(seeds) => Self::Seeds(PayloadSeeds { seeds: seeds.seeds.clone() }),
+            mpl_token_metadata::processor::PayloadType::MerkleProof(proof) => Self::MerkleProof(PayloadMerkleProof { proof: proof.proof.iter().map(|x| x.to_vec()).collect() }),
+            mpl_token_metadata::processor::PayloadType::Number(number) => Self::Number(*number),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Account list made of the given names, with the omitted optional accounts replaced by the program id.
+    fn accounts(names: &[&str]) -> Vec<String> {
+        names
+            .iter()
+            .map(|x| if x.is_empty() { MPL_TOKEN_METADATA_PROGRAM_ID.to_string() } else { x.to_string() })
+            .collect()
+    }
+
+    fn borsh_string(value: &str) -> Vec<u8> {
+        [&(value.len() as u32).to_le_bytes()[..], value.as_bytes()].concat()
+    }
+
+    #[test]
+    fn test_parse_create() {
+        let creator = [7u8; 32];
+        let data = [
+            &[42, 0][..],
+            &borsh_string("Frens #1"),
+            &borsh_string("FRENS"),
+            &borsh_string("https://arweave.net/frens-1.json"),
+            &500u16.to_le_bytes(),
+            &[1], &1u32.to_le_bytes(), &creator, &[1, 100],
+            &[0, 1],
+            &[4],
+            &[0, 0, 0, 0],
+            &[1, 0],
+            &[1, 0],
+        ]
+        .concat();
+        let names = ["metadata", "master_edition", "mint", "mint_authority", "payer", "update_authority", "system_program", "sysvar_instructions", "spl_token_program"];
+
+        let create = match parse_instruction_data(&data, &accounts(&names)).unwrap() {
+            Some(Event::Create(create)) => create,
+            event => panic!("Unexpected event {:?}", event),
+        };
+        assert_eq!(create.metadata, "metadata");
+        assert_eq!(create.master_edition.as_deref(), Some("master_edition"));
+        assert_eq!(create.mint, "mint");
+        assert_eq!(create.mint_authority, "mint_authority");
+        assert_eq!(create.payer, "payer");
+        assert_eq!(create.update_authority, "update_authority");
+        assert_eq!(create.decimals, Some(0));
+        assert_eq!(create.print_supply, Some(PrintSupply { max_supply: Some(0) }));
+        let asset_data = create.asset_data.unwrap();
+        assert_eq!(asset_data.name, "Frens #1");
+        assert_eq!(asset_data.symbol, "FRENS");
+        assert_eq!(asset_data.uri, "https://arweave.net/frens-1.json");
+        assert_eq!(asset_data.seller_fee_basis_points, 500);
+        assert_eq!(asset_data.creators.len(), 1);
+        assert_eq!(asset_data.creators[0].address, bs58::encode(creator).into_string());
+        assert!(asset_data.creators[0].verified);
+        assert_eq!(asset_data.creators[0].share, 100);
+        assert!(!asset_data.primary_sale_happened);
+        assert!(asset_data.is_mutable);
+        assert_eq!(asset_data.token_standard, TokenStandard::ProgrammableNonFungible as i32);
+
+        // Fungible assets have no master edition.
+        let mut names = names;
+        names[1] = "";
+        match parse_instruction_data(&data, &accounts(&names)).unwrap() {
+            Some(Event::Create(create)) => assert_eq!(create.master_edition, None),
+            event => panic!("Unexpected event {:?}", event),
+        }
+    }
+
+    #[test]
+    fn test_parse_mint() {
+        let data = [&[43, 0][..], &1u64.to_le_bytes(), &[0]].concat();
+        let names = [
+            "token", "token_owner", "metadata", "master_edition", "token_record", "mint", "authority", "", "payer",
+            "system_program", "sysvar_instructions", "spl_token_program", "spl_ata_program", "", "",
+        ];
+        let mint = match parse_instruction_data(&data, &accounts(&names)).unwrap() {
+            Some(Event::Mint(mint)) => mint,
+            event => panic!("Unexpected event {:?}", event),
+        };
+        assert_eq!(mint.token, "token");
+        assert_eq!(mint.token_owner.as_deref(), Some("token_owner"));
+        assert_eq!(mint.metadata, "metadata");
+        assert_eq!(mint.master_edition.as_deref(), Some("master_edition"));
+        assert_eq!(mint.token_record.as_deref(), Some("token_record"));
+        assert_eq!(mint.mint, "mint");
+        assert_eq!(mint.authority, "authority");
+        assert_eq!(mint.delegate_record, None);
+        assert_eq!(mint.payer, "payer");
+        assert_eq!(mint.amount, 1);
+    }
+
+    #[test]
+    fn test_parse_transfer() {
+        let data = [&[49, 0][..], &1u64.to_le_bytes(), &[0]].concat();
+        let names = [
+            "token", "token_owner", "destination", "destination_owner", "mint", "metadata", "edition",
+            "owner_token_record", "destination_token_record", "authority", "payer", "system_program",
+            "sysvar_instructions", "spl_token_program", "spl_ata_program", "authorization_rules_program", "rule_set",
+        ];
+        let transfer = match parse_instruction_data(&data, &accounts(&names)).unwrap() {
+            Some(Event::Transfer(transfer)) => transfer,
+            event => panic!("Unexpected event {:?}", event),
+        };
+        assert_eq!(transfer.token, "token");
+        assert_eq!(transfer.token_owner, "token_owner");
+        assert_eq!(transfer.destination, "destination");
+        assert_eq!(transfer.destination_owner, "destination_owner");
+        assert_eq!(transfer.mint, "mint");
+        assert_eq!(transfer.metadata, "metadata");
+        assert_eq!(transfer.edition.as_deref(), Some("edition"));
+        assert_eq!(transfer.owner_token_record.as_deref(), Some("owner_token_record"));
+        assert_eq!(transfer.destination_token_record.as_deref(), Some("destination_token_record"));
+        assert_eq!(transfer.authority, "authority");
+        assert_eq!(transfer.payer, "payer");
+        assert_eq!(transfer.amount, 1);
+        assert_eq!(transfer.token_state, Some(TokenState::Unlocked as i32));
+        assert_eq!(transfer.rule_set.as_deref(), Some("rule_set"));
+    }
+
+    #[test]
+    fn test_parse_delegate() {
+        let names = [
+            "", "delegate", "metadata", "master_edition", "token_record", "mint", "token", "authority", "payer",
+            "system_program", "sysvar_instructions", "spl_token_program", "", "",
+        ];
+        // TransferV1 { amount: 1, authorization_data: None }
+        let data = [&[44, 2][..], &1u64.to_le_bytes(), &[0]].concat();
+        let delegate = match parse_instruction_data(&data, &accounts(&names)).unwrap() {
+            Some(Event::Delegate(delegate)) => delegate,
+            event => panic!("Unexpected event {:?}", event),
+        };
+        assert_eq!(delegate.delegate_record, None);
+        assert_eq!(delegate.delegate, "delegate");
+        assert_eq!(delegate.metadata, "metadata");
+        assert_eq!(delegate.master_edition.as_deref(), Some("master_edition"));
+        assert_eq!(delegate.token_record.as_deref(), Some("token_record"));
+        assert_eq!(delegate.mint, "mint");
+        assert_eq!(delegate.token.as_deref(), Some("token"));
+        assert_eq!(delegate.authority, "authority");
+        assert_eq!(delegate.payer, "payer");
+        assert_eq!(delegate.role, DelegateRole::Transfer as i32);
+        assert_eq!(delegate.amount, Some(1));
+        assert_eq!(delegate.token_state, Some(TokenState::Unlocked as i32));
+        assert_eq!(delegate.rule_set, None);
+
+        // SaleV1 lists the asset.
+        let data = [&[44, 1][..], &1u64.to_le_bytes(), &[0]].concat();
+        match parse_instruction_data(&data, &accounts(&names)).unwrap() {
+            Some(Event::Delegate(delegate)) => {
+                assert_eq!(delegate.role, DelegateRole::Sale as i32);
+                assert_eq!(delegate.token_state, Some(TokenState::Listed as i32));
+            },
+            event => panic!("Unexpected event {:?}", event),
+        }
+    }
+
+    #[test]
+    fn test_parse_burn() {
+        let data = [&[41, 0][..], &1u64.to_le_bytes()].concat();
+        let names = [
+            "authority", "", "metadata", "edition", "mint", "token", "", "", "", "", "token_record",
+            "system_program", "sysvar_instructions", "spl_token_program",
+        ];
+        let burn = match parse_instruction_data(&data, &accounts(&names)).unwrap() {
+            Some(Event::Burn(burn)) => burn,
+            event => panic!("Unexpected event {:?}", event),
+        };
+        assert_eq!(burn.authority, "authority");
+        assert_eq!(burn.collection_metadata, None);
+        assert_eq!(burn.metadata, "metadata");
+        assert_eq!(burn.edition.as_deref(), Some("edition"));
+        assert_eq!(burn.mint, "mint");
+        assert_eq!(burn.token, "token");
+        assert_eq!(burn.master_edition, None);
+        assert_eq!(burn.edition_marker, None);
+        assert_eq!(burn.token_record.as_deref(), Some("token_record"));
+        assert_eq!(burn.amount, 1);
+    }
+
+    fn update_data() -> Vec<u8> {
+        [&[1][..], &borsh_string("Frens #2"), &borsh_string("FRENS"), &borsh_string("https://arweave.net/frens-2.json"), &250u16.to_le_bytes(), &[0]].concat()
+    }
+
+    fn parse_update(args: &[u8], delegate_record: &str) -> UpdateEvent {
+        let names = [
+            "authority", delegate_record, "token", "mint", "metadata", "edition", "payer", "system_program",
+            "sysvar_instructions", "", "",
+        ];
+        let data = [&[50][..], args].concat();
+        match parse_instruction_data(&data, &accounts(&names)).unwrap() {
+            Some(Event::Update(update)) => {
+                assert_eq!(update.authority, "authority");
+                assert_eq!(update.token.as_deref(), Some("token"));
+                assert_eq!(update.mint, "mint");
+                assert_eq!(update.metadata, "metadata");
+                assert_eq!(update.edition.as_deref(), Some("edition"));
+                assert_eq!(update.payer, "payer");
+                update
+            },
+            event => panic!("Unexpected event {:?}", event),
+        }
+    }
+
+    #[test]
+    fn test_parse_update_v1() {
+        let new_update_authority = [9u8; 32];
+        let collection = [5u8; 32];
+        let rule_set = [6u8; 32];
+        let args = [
+            &[0, 1][..], &new_update_authority,
+            &update_data(),
+            &[1, 1],
+            &[1, 0],
+            &[2, 0], &collection,
+            &[0],
+            &[1],
+            &[2], &rule_set,
+            &[0],
+        ]
+        .concat();
+
+        let update = parse_update(&args, "");
+        assert_eq!(update.delegate_record, None);
+        assert_eq!(update.authority_type, UpdateAuthorityType::UpdateAuthority as i32);
+        assert_eq!(update.delegate_role, None);
+        let v1 = match update.update_args {
+            Some(update_event::UpdateArgs::V1(v1)) => v1,
+            args => panic!("Unexpected update args {:?}", args),
+        };
+        assert_eq!(v1.new_update_authority, Some(bs58::encode(new_update_authority).into_string()));
+        assert_eq!(v1.data.as_ref().map(|x| x.name.as_str()), Some("Frens #2"));
+        assert_eq!(v1.data.as_ref().map(|x| x.seller_fee_basis_points), Some(250));
+        assert_eq!(v1.primary_sale_happened, Some(true));
+        assert_eq!(v1.is_mutable, Some(false));
+        let collection_toggle = v1.collection.unwrap();
+        assert_eq!(collection_toggle.toggle, Toggle::Set as i32);
+        assert_eq!(collection_toggle.collection.map(|x| x.key), Some(bs58::encode(collection).into_string()));
+        assert_eq!(v1.collection_details.unwrap().toggle, Toggle::None as i32);
+        assert_eq!(v1.uses.unwrap().toggle, Toggle::Clear as i32);
+        assert_eq!(v1.rule_set.unwrap().rule_set, Some(bs58::encode(rule_set).into_string()));
+        assert_eq!(v1.authorization_data, None);
+
+        // A V1 update through a delegate record doesn't tell the delegate role.
+        let update = parse_update(&args, "delegate_record");
+        assert_eq!(update.delegate_record.as_deref(), Some("delegate_record"));
+        assert_eq!(update.authority_type, UpdateAuthorityType::Delegate as i32);
+        assert_eq!(update.delegate_role, None);
+    }
+
+    #[test]
+    fn test_parse_update_as_update_authority_v2() {
+        let args = [&[1, 0][..], &[0], &[1, 1], &[0], &[0], &[0], &[0], &[0], &[1, 4], &[0]].concat();
+        let update = parse_update(&args, "");
+        assert_eq!(update.authority_type, UpdateAuthorityType::UpdateAuthority as i32);
+        assert_eq!(update.delegate_role, None);
+        match update.update_args {
+            Some(update_event::UpdateArgs::AsUpdateAuthorityV2(args)) => {
+                assert_eq!(args.new_update_authority, None);
+                assert_eq!(args.data, None);
+                assert_eq!(args.primary_sale_happened, Some(true));
+                assert_eq!(args.collection.unwrap().toggle, Toggle::None as i32);
+                assert_eq!(args.token_standard, Some(TokenStandard::ProgrammableNonFungible as i32));
+            },
+            args => panic!("Unexpected update args {:?}", args),
+        }
+    }
+
+    #[test]
+    fn test_parse_update_as_delegate_v2() {
+        let collection = [5u8; 32];
+        let rule_set = [6u8; 32];
+        let cases = [
+            ([&[2][..], &[0], &[1, 1], &[0], &[0], &[0]].concat(), DelegateRole::AuthorityItem),
+            ([&[3][..], &[1], &[0]].concat(), DelegateRole::Collection),
+            ([&[4][..], &update_data(), &[0]].concat(), DelegateRole::Data),
+            ([&[5][..], &[2], &rule_set, &[0]].concat(), DelegateRole::ProgrammableConfig),
+            ([&[6][..], &[0], &[0]].concat(), DelegateRole::DataItem),
+            ([&[7][..], &[2, 1], &collection, &[0]].concat(), DelegateRole::CollectionItem),
+            ([&[8][..], &[1], &[0]].concat(), DelegateRole::ProgrammableConfigItem),
+        ];
+        for (args, role) in cases {
+            let update = parse_update(&args, "delegate_record");
+            assert_eq!(update.delegate_record.as_deref(), Some("delegate_record"));
+            assert_eq!(update.authority_type, UpdateAuthorityType::Delegate as i32);
+            assert_eq!(update.delegate_role, Some(role as i32));
+
+            match (update.update_args.unwrap(), role) {
+                (update_event::UpdateArgs::AsAuthorityItemDelegateV2(args), DelegateRole::AuthorityItem) => {
+                    assert_eq!(args.primary_sale_happened, Some(true));
+                    assert_eq!(args.is_mutable, None);
+                },
+                (update_event::UpdateArgs::AsCollectionDelegateV2(args), DelegateRole::Collection) => {
+                    assert_eq!(args.collection.unwrap().toggle, Toggle::Clear as i32);
+                },
+                (update_event::UpdateArgs::AsDataDelegateV2(args), DelegateRole::Data) => {
+                    assert_eq!(args.data.map(|x| x.uri), Some("https://arweave.net/frens-2.json".to_string()));
+                },
+                (update_event::UpdateArgs::AsProgrammableConfigDelegateV2(args), DelegateRole::ProgrammableConfig) => {
+                    assert_eq!(args.rule_set.unwrap().rule_set, Some(bs58::encode(rule_set).into_string()));
+                },
+                (update_event::UpdateArgs::AsDataItemDelegateV2(args), DelegateRole::DataItem) => {
+                    assert_eq!(args.data, None);
+                },
+                (update_event::UpdateArgs::AsCollectionItemDelegateV2(args), DelegateRole::CollectionItem) => {
+                    let collection_toggle = args.collection.unwrap();
+                    assert_eq!(collection_toggle.toggle, Toggle::Set as i32);
+                    assert_eq!(collection_toggle.collection.map(|x| (x.key, x.verified)), Some((bs58::encode(collection).into_string(), true)));
+                },
+                (update_event::UpdateArgs::AsProgrammableConfigItemDelegateV2(args), DelegateRole::ProgrammableConfigItem) => {
+                    assert_eq!(args.rule_set.unwrap().toggle, Toggle::Clear as i32);
+                },
+                (args, role) => panic!("Unexpected update args {:?} for {:?}", args, role),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_update_metadata_account_v2() {
+        let new_update_authority = [9u8; 32];
+        let data = [
+            &[15, 1][..],
+            &borsh_string("Frens #3"), &borsh_string("FRENS"), &borsh_string("https://arweave.net/frens-3.json"), &0u16.to_le_bytes(),
+            &[0, 0, 0],
+            &[1], &new_update_authority,
+            &[0],
+            &[1, 1],
+        ]
+        .concat();
+        let update = match parse_instruction_data(&data, &accounts(&["metadata", "update_authority"])).unwrap() {
+            Some(Event::UpdateMetadataAccountV2(update)) => update,
+            event => panic!("Unexpected event {:?}", event),
+        };
+        assert_eq!(update.metadata, "metadata");
+        assert_eq!(update.update_authority, "update_authority");
+        assert_eq!(update.data.map(|x| x.name), Some("Frens #3".to_string()));
+        assert_eq!(update.new_update_authority, Some(bs58::encode(new_update_authority).into_string()));
+        assert_eq!(update.primary_sale_happened, None);
+        assert_eq!(update.is_mutable, Some(true));
+
+        assert!(parse_instruction_data(&data, &accounts(&["metadata"])).is_err());
+    }
+
+    #[test]
+    fn test_missing_accounts() {
+        let burn = [&[41, 0][..], &1u64.to_le_bytes()].concat();
+        assert!(parse_instruction_data(&burn, &accounts(&["authority", "", "metadata", "edition", "mint"])).is_err());
+        let transfer = [&[49, 0][..], &1u64.to_le_bytes(), &[0]].concat();
+        assert!(parse_instruction_data(&transfer, &accounts(&["token", "token_owner", "destination"])).is_err());
+        assert!(parse_instruction_data(&[43], &accounts(&["token"])).is_err());
+    }
+}
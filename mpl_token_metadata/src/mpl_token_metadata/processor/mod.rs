@@ -103,4 +103,9 @@ impl Payload {
             map: HashMap::new(),
         }
     }
+
+    /// Get a reference to the inner `HashMap`.
+    pub fn inner(&self) -> &HashMap<String, PayloadType> {
+        &self.map
+    }
 }
@@ -32,6 +32,11 @@ impl TokenMetadataAccount for EditionMarker {
 }
 
 impl EditionMarker {
+    /// Number of the marker holding an edition's bit, which is part of the marker's PDA seeds.
+    pub fn get_marker_number(edition: u64) -> u64 {
+        edition / EDITION_MARKER_BIT_SIZE
+    }
+
     // fn get_edition_offset_from_starting_index(edition: u64) -> Result<usize, ProgramError> {
     //     Ok(edition
     //         .checked_rem(EDITION_MARKER_BIT_SIZE)
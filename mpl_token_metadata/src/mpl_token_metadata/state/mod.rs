@@ -11,7 +11,7 @@ pub mod fee;
 pub(crate) mod master_edition;
 pub(crate) mod metadata;
 pub(crate) mod migrate;
-// pub(crate) mod programmable;
+pub(crate) mod programmable;
 // pub(crate) mod reservation;
 pub(crate) mod token_auth_payload;
 pub(crate) mod uses;
@@ -32,7 +32,7 @@ pub use metadata::*;
 pub use migrate::*;
 use num_derive::FromPrimitive;
 use num_traits::FromPrimitive;
-// pub use programmable::*;
+pub use programmable::*;
 // pub use reservation::*;
 pub use uses::*;
 use super::error::{ProgramError, MetadataError};
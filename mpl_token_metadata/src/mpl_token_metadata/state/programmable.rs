@@ -1,27 +1,5 @@
-use std::io::Error;
-
-use borsh::{BorshDeserialize, BorshSerialize};
-use mpl_utils::cmp_pubkeys;
-#[cfg(feature = "serde-feature")]
-use serde::{Deserialize, Serialize};
-use shank::ShankAccount;
-use solana_program::{
-    account_info::AccountInfo, instruction::AccountMeta, program_error::ProgramError,
-    program_option::COption, pubkey::Pubkey,
-};
-use spl_token_2022::state::Account;
-
 use super::*;
-use crate::{
-    error::MetadataError,
-    instruction::MetadataDelegateRole,
-    pda::{find_metadata_delegate_record_account, find_token_record_account},
-    processor::{DelegateScenario, TransferScenario, UpdateScenario},
-    utils::assert_owned_by,
-};
-
-/// Empty pubkey constant.
-// const DEFAULT_PUBKEY: Pubkey = Pubkey::new_from_array([0u8; 32]);
+use substreams_solana_utils::pubkey::Pubkey;
 
 pub const TOKEN_RECORD_SEED: &str = "token_record";
 
@@ -53,7 +31,7 @@ pub const TOKEN_RECORD_SIZE: usize = 1 // Key
 /// 5. token account id
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub struct TokenRecord {
     /// Account key.
     pub key: Key,
@@ -66,13 +44,6 @@ pub struct TokenRecord {
     pub rule_set_revision: Option<u64>,
     /// Pubkey of the current token delegate. This delegate key will match the spl-token
     /// delegate pubkey.
-    #[cfg_attr(
-        feature = "serde-feature",
-        serde(
-            deserialize_with = "deser_option_pubkey",
-            serialize_with = "ser_option_pubkey"
-        )
-    )]
     pub delegate: Option<Pubkey>,
     /// The role of the current token delegate.
     pub delegate_role: Option<TokenDelegateRole>,
@@ -82,7 +53,7 @@ pub struct TokenRecord {
         note = "The locked address is deprecated and will soon be removed."
     )]
     /// Stores the destination pubkey when a transfer is lock to an allowed address. This
-    /// pubkey gets set when a 'LockTransfer' delegate is approved.
+    /// pubkey gets set when a 'LockedTransfer' delegate is approved.
     pub locked_transfer: Option<Pubkey>,
 }
 
@@ -108,15 +79,6 @@ impl TokenMetadataAccount for TokenRecord {
     fn size() -> usize {
         TOKEN_RECORD_SIZE
     }
-
-    fn safe_deserialize(data: &[u8]) -> Result<Self, BorshError> {
-        Self::from_bytes(data).map_err(|e| Error::new(ErrorKind::Other, e.to_string()))
-    }
-
-    fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
-        let data = &account_info.try_borrow_data()?;
-        Self::from_bytes(data)
-    }
 }
 
 impl TokenRecord {
@@ -132,10 +94,8 @@ impl TokenRecord {
         self.delegate_role = None;
         self.locked_transfer = None;
     }
-}
 
-impl Resizable for TokenRecord {
-    fn from_bytes<'a>(account_data: &[u8]) -> Result<TokenRecord, ProgramError> {
+    pub fn from_bytes(account_data: &[u8]) -> Result<TokenRecord, ProgramError> {
         // we perform a manual deserialization since we are potentially dealing
         // with accounts of different sizes
         let length = TokenRecord::size() as i64 - account_data.len() as i64;
@@ -149,20 +109,21 @@ impl Resizable for TokenRecord {
                 account_data.len(),
             )
         {
-            return Err(MetadataError::DataTypeMismatch.into());
+            return Err(ProgramError::Custom(MetadataError::DataTypeMismatch as u32));
         }
         // mutable "pointer" to the account data
         let mut data = account_data;
+        let map_err = |e: std::io::Error| ProgramError::BorshIoError(e.to_string());
 
-        let key: Key = BorshDeserialize::deserialize(&mut data)?;
-        let bump: u8 = BorshDeserialize::deserialize(&mut data)?;
-        let state: TokenState = BorshDeserialize::deserialize(&mut data)?;
-        let rule_set_revision: Option<u64> = BorshDeserialize::deserialize(&mut data)?;
-        let delegate: Option<Pubkey> = BorshDeserialize::deserialize(&mut data)?;
-        let delegate_role: Option<TokenDelegateRole> = BorshDeserialize::deserialize(&mut data)?;
+        let key: Key = BorshDeserialize::deserialize(&mut data).map_err(map_err)?;
+        let bump: u8 = BorshDeserialize::deserialize(&mut data).map_err(map_err)?;
+        let state: TokenState = BorshDeserialize::deserialize(&mut data).map_err(map_err)?;
+        let rule_set_revision: Option<u64> = BorshDeserialize::deserialize(&mut data).map_err(map_err)?;
+        let delegate: Option<Pubkey> = BorshDeserialize::deserialize(&mut data).map_err(map_err)?;
+        let delegate_role: Option<TokenDelegateRole> = BorshDeserialize::deserialize(&mut data).map_err(map_err)?;
 
         let locked_transfer: Option<Pubkey> = if length == 0 {
-            BorshDeserialize::deserialize(&mut data)?
+            BorshDeserialize::deserialize(&mut data).map_err(map_err)?
         } else {
             None
         };
@@ -180,7 +141,7 @@ impl Resizable for TokenRecord {
 }
 
 /// Programmable account state.
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone, Copy)]
 pub enum TokenState {
     /// Token account is unlocked; operations are allowed on this account.
     Unlocked,
@@ -190,107 +151,22 @@ pub enum TokenState {
     Listed,
 }
 
-// #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Copy)]
-// pub enum TokenDelegateRole {
-//     Sale,
-//     Transfer,
-//     Utility,
-//     Staking,
-//     Standard,
-//     LockedTransfer,
-//     Migration = 255,
-// }
-
-pub struct AuthorityRequest<'a, 'b> {
-    /// Determines the precedence of authority types.
-    pub precedence: &'a [AuthorityType],
-    /// Pubkey of the authority.
-    pub authority: &'a Pubkey,
-    /// Metadata's update authority pubkey of the asset.
-    pub update_authority: &'b Pubkey,
-    /// Mint address.
-    pub mint: &'b Pubkey,
-    /// Collection mint address.
-    pub collection_mint: Option<&'b Pubkey>,
-    /// Holder's token account info.
-    pub token: Option<&'a Pubkey>,
-    /// Holder's token account.
-    pub token_account: Option<&'b Account>,
-    /// `MetadataDelegateRecord` account of the authority (when the authority is a delegate).
-    pub metadata_delegate_record_info: Option<&'a AccountInfo<'a>>,
-    /// Expected `MetadataDelegateRole` for the request.
-    pub metadata_delegate_roles: Vec<MetadataDelegateRole>,
-    /// Expected collection-level `MetadataDelegateRole` for the request.
-    pub collection_metadata_delegate_roles: Vec<MetadataDelegateRole>,
-    /// `TokenRecord` account.
-    pub token_record_info: Option<&'a AccountInfo<'a>>,
-    /// Expected `TokenDelegateRole` for the request.
-    pub token_delegate_roles: Vec<TokenDelegateRole>,
-}
-
-impl<'a, 'b> Default for AuthorityRequest<'a, 'b> {
-    fn default() -> Self {
-        Self {
-            precedence: &[
-                AuthorityType::TokenDelegate,
-                AuthorityType::Holder,
-                AuthorityType::MetadataDelegate,
-                AuthorityType::Metadata,
-            ],
-            authority: &DEFAULT_PUBKEY,
-            update_authority: &DEFAULT_PUBKEY,
-            mint: &DEFAULT_PUBKEY,
-            collection_mint: None,
-            token: None,
-            token_account: None,
-            metadata_delegate_record_info: None,
-            metadata_delegate_roles: Vec::with_capacity(0),
-            collection_metadata_delegate_roles: Vec::with_capacity(0),
-            token_record_info: None,
-            token_delegate_roles: Vec::with_capacity(0),
-        }
-    }
-}
-
-/// Struct to represent the authority type identified from
-/// an authority request.
-#[derive(Default)]
-pub struct AuthorityResponse {
-    pub authority_type: AuthorityType,
-    pub token_delegate_role: Option<TokenDelegateRole>,
-    pub metadata_delegate_role: Option<MetadataDelegateRole>,
-}
-
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Default)]
-pub enum AuthorityType {
-    #[default]
-    None,
-    Metadata,
-    Holder,
-    MetadataDelegate,
-    TokenDelegate,
-}
-
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub enum Operation {
-    Transfer { scenario: TransferScenario },
-    Update { scenario: UpdateScenario },
-    Delegate { scenario: DelegateScenario },
-}
-
-impl ToString for Operation {
-    fn to_string(&self) -> String {
-        match self {
-            Self::Transfer { scenario } => format!("Transfer:{}", scenario),
-            Self::Update { scenario } => format!("Update:{}", scenario),
-            Self::Delegate { scenario } => format!("Delegate:{}", scenario),
-        }
-    }
+#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone, Copy)]
+// Serialized by variant index on chain, so `Migration` is 6.
+#[borsh(use_discriminant = false)]
+pub enum TokenDelegateRole {
+    Sale,
+    Transfer,
+    Utility,
+    Staking,
+    Standard,
+    LockedTransfer,
+    Migration = 255,
 }
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[derive(BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub enum PayloadKey {
     Amount,
     Authority,
@@ -321,17 +197,3 @@ impl ToString for PayloadKey {
         .to_string()
     }
 }
-
-pub trait ToAccountMeta {
-    fn to_account_meta(&self) -> AccountMeta;
-}
-
-impl<'info> ToAccountMeta for AccountInfo<'info> {
-    fn to_account_meta(&self) -> AccountMeta {
-        AccountMeta {
-            pubkey: *self.key,
-            is_signer: self.is_signer,
-            is_writable: self.is_writable,
-        }
-    }
-}
@@ -145,218 +145,992 @@ pub mod mpl_token_metadata_event {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApproveCollectionAuthorityEvent {
+    #[prost(string, tag="1")]
+    pub collection_authority_record: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub new_collection_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub mint: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApproveUseAuthorityEvent {
+    #[prost(string, tag="1")]
+    pub use_authority_record: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub user: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub owner_token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub number_of_uses: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BubblegumSetCollectionSizeEvent {
+    #[prost(string, tag="1")]
+    pub collection_metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub collection_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub collection_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub bubblegum_signer: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="5")]
+    pub collection_authority_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, tag="6")]
+    pub size: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BurnEvent {
+    #[prost(string, tag="1")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub collection_metadata: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="3")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="4")]
+    pub edition: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="5")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub token: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="7")]
+    pub master_edition: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="8")]
+    pub master_edition_mint: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="9")]
+    pub master_edition_token: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="10")]
+    pub edition_marker: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="11")]
+    pub token_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, tag="12")]
+    pub amount: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BurnEditionNftEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub print_edition_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub master_edition_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub print_edition_token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub master_edition_token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub master_edition: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub print_edition: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub edition_marker: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BurnNftEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub master_edition: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="6")]
+    pub collection_metadata: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CloseEscrowAccountEvent {
+    #[prost(string, tag="1")]
+    pub escrow: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub edition: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub payer: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConvertMasterEditionV1ToV2Event {
+    #[prost(string, tag="1")]
+    pub master_edition: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub one_time_auth: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub printing_mint: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateEscrowAccountEvent {
+    #[prost(string, tag="1")]
+    pub escrow: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub edition: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="7")]
+    pub authority: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateMasterEditionEvent {
+    #[prost(string, tag="1")]
+    pub edition: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub mint_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub metadata: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateMasterEditionV3Event {
+    #[prost(string, tag="1")]
+    pub edition: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub mint_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub metadata: ::prost::alloc::string::String,
+    /// Unset for an unlimited supply of prints.
+    #[prost(uint64, optional, tag="7")]
+    pub max_supply: ::core::option::Option<u64>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateMetadataAccountEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub mint_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub update_authority: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateMetadataAccountV2Event {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub mint_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub update_authority: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DelegateEvent {
+    #[prost(string, optional, tag="1")]
+    pub delegate_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="2")]
+    pub delegate: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="4")]
+    pub master_edition: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="5")]
+    pub token_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="6")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="7")]
+    pub token: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="8")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(enumeration="DelegateRole", tag="10")]
+    pub role: i32,
+    #[prost(uint64, optional, tag="11")]
+    pub amount: ::core::option::Option<u64>,
+    #[prost(string, optional, tag="12")]
+    pub locked_address: ::core::option::Option<::prost::alloc::string::String>,
+    /// State of the token record after a token delegate is set, for programmable assets.
+    #[prost(enumeration="TokenState", optional, tag="13")]
+    pub token_state: ::core::option::Option<i32>,
+    #[prost(string, optional, tag="14")]
+    pub rule_set: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeprecatedCreateMasterEditionEvent {
+    #[prost(string, tag="1")]
+    pub edition: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub printing_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub one_time_printing_authorization_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub printing_mint_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub mint_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub payer: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeprecatedCreateReservationListEvent {
+    #[prost(string, tag="1")]
+    pub reservation_list: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub master_edition: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub resource: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub metadata: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub edition: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub master_edition: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub mint_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub printing_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub master_token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub edition_marker: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub burn_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="11")]
+    pub master_update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="12")]
+    pub master_metadata: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="13")]
+    pub reservation_list: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeprecatedMintPrintingTokensEvent {
+    #[prost(string, tag="1")]
+    pub destination: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub printing_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub master_edition: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeprecatedMintPrintingTokensViaTokenEvent {
+    #[prost(string, tag="1")]
+    pub destination: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub token: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub one_time_printing_authorization_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub printing_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub burn_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub master_edition: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeprecatedSetReservationListEvent {
+    #[prost(string, tag="1")]
+    pub master_edition: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub reservation_list: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub resource: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FreezeDelegatedAccountEvent {
+    #[prost(string, tag="1")]
+    pub delegate: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub edition: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub mint: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LockEvent {
+    #[prost(string, tag="1")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub token_owner: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="3")]
+    pub token: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="6")]
+    pub edition: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="7")]
+    pub token_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="8")]
+    pub payer: ::prost::alloc::string::String,
+    /// State of the token record, for programmable assets.
+    #[prost(enumeration="TokenState", optional, tag="9")]
+    pub token_state: ::core::option::Option<i32>,
+    #[prost(string, optional, tag="10")]
+    pub rule_set: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MigrateEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub edition: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub token: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub token_owner: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub collection_metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub delegate_record: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub token_record: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MintNewEditionFromMasterEditionViaTokenEvent {
+    #[prost(string, tag="1")]
+    pub new_metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub new_edition: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub master_edition: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub new_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub edition_mark_pda: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub new_mint_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub token_account_owner: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub new_metadata_update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="11")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(uint64, tag="12")]
+    pub edition: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MintNewEditionFromMasterEditionViaVaultProxyEvent {
+    #[prost(string, tag="1")]
+    pub new_metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub new_edition: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub master_edition: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub new_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub edition_mark_pda: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub new_mint_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub vault_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub safety_deposit_store: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub safety_deposit_box: ::prost::alloc::string::String,
+    #[prost(string, tag="11")]
+    pub vault: ::prost::alloc::string::String,
+    #[prost(string, tag="12")]
+    pub new_metadata_update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="13")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(uint64, tag="14")]
+    pub edition: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PuffMetadataEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RemoveCreatorVerificationEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub creator: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RevokeEvent {
+    #[prost(string, optional, tag="1")]
+    pub delegate_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="2")]
+    pub delegate: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="4")]
+    pub master_edition: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="5")]
+    pub token_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="6")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="7")]
+    pub token: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="8")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(enumeration="DelegateRole", tag="10")]
+    pub role: i32,
+    /// State of the token record after a token delegate is revoked, for programmable assets.
+    #[prost(enumeration="TokenState", optional, tag="11")]
+    pub token_state: ::core::option::Option<i32>,
+    #[prost(string, optional, tag="12")]
+    pub rule_set: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RevokeCollectionAuthorityEvent {
+    #[prost(string, tag="1")]
+    pub collection_authority_record: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub delegate_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub revoke_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub mint: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RevokeUseAuthorityEvent {
+    #[prost(string, tag="1")]
+    pub use_authority_record: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub user: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub owner_token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub metadata: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SetAndVerifyCollectionEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub collection_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub collection_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub collection_metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub collection_master_edition: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="8")]
+    pub collection_authority_record: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SetAndVerifySizedCollectionItemEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub collection_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub collection_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub collection_metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub collection_master_edition: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="8")]
+    pub collection_authority_record: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SetTokenStandardEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="4")]
+    pub edition: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignMetadataEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub creator: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ThawDelegatedAccountEvent {
+    #[prost(string, tag="1")]
+    pub delegate: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub edition: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub mint: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TransferEvent {
+    #[prost(string, tag="1")]
+    pub token: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub token_owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub destination: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub destination_owner: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="7")]
+    pub edition: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="8")]
+    pub owner_token_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="9")]
+    pub destination_token_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="10")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, tag="11")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(uint64, tag="12")]
+    pub amount: u64,
+    /// State of the destination token record, for programmable assets.
+    #[prost(enumeration="TokenState", optional, tag="13")]
+    pub token_state: ::core::option::Option<i32>,
+    #[prost(string, optional, tag="14")]
+    pub rule_set: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TransferOutOfEscrowEvent {
+    #[prost(string, tag="1")]
+    pub escrow: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub attribute_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub attribute_src: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub attribute_dst: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub escrow_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub escrow_account: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="9")]
+    pub authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, tag="10")]
+    pub amount: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UnlockEvent {
+    #[prost(string, tag="1")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub token_owner: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="3")]
+    pub token: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="6")]
+    pub edition: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="7")]
+    pub token_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="8")]
+    pub payer: ::prost::alloc::string::String,
+    /// State of the token record, for programmable assets.
+    #[prost(enumeration="TokenState", optional, tag="9")]
+    pub token_state: ::core::option::Option<i32>,
+    #[prost(string, optional, tag="10")]
+    pub rule_set: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UnverifyEvent {
+    #[prost(string, tag="1")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub delegate_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="3")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="4")]
+    pub collection_mint: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="5")]
+    pub collection_metadata: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration="VerificationType", tag="6")]
+    pub verification_type: i32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UnverifyCollectionEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub collection_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub collection_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub collection_metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub collection_master_edition: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="6")]
+    pub collection_authority_record: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UnverifySizedCollectionItemEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub collection_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub collection_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub collection_metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub collection_master_edition: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="7")]
+    pub collection_authority_record: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateMetadataAccountEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub update_authority: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdatePrimarySaleHappenedViaTokenEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub token: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UtilizeEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub use_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="6")]
+    pub use_authority_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="7")]
+    pub burner: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, tag="8")]
+    pub number_of_uses: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PrintEvent {
+    #[prost(string, tag="1")]
+    pub edition_metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub edition: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub edition_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub edition_token_account_owner: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub edition_token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub edition_mint_authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="7")]
+    pub edition_token_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="8")]
+    pub master_edition: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub edition_marker_pda: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="11")]
+    pub master_token_account_owner: ::prost::alloc::string::String,
+    #[prost(string, tag="12")]
+    pub master_token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="13")]
+    pub master_metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="14")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="15")]
+    pub holder_delegate_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="16")]
+    pub delegate: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, tag="17")]
+    pub edition_number: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VerifyEvent {
+    #[prost(string, tag="1")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub delegate_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="3")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="4")]
+    pub collection_mint: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="5")]
+    pub collection_metadata: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="6")]
+    pub collection_master_edition: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration="VerificationType", tag="7")]
+    pub verification_type: i32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MintEvent {
+    #[prost(string, tag="1")]
+    pub token: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub token_owner: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="3")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="4")]
+    pub master_edition: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="5")]
+    pub token_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="6")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="8")]
+    pub delegate_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="9")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub amount: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SetCollectionSizeEvent {
+    #[prost(string, tag="1")]
+    pub collection_metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub collection_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub collection_mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="4")]
+    pub collection_authority_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, tag="5")]
+    pub size: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CollectEvent {
+    #[prost(string, tag="1")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub recipient: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UseEvent {
+    #[prost(string, tag="1")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub delegate_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="3")]
+    pub token: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="4")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="6")]
+    pub edition: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="7")]
+    pub payer: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VerifySizedCollectionItemEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub collection_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub collection_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub collection_metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub collection_master_edition: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="7")]
+    pub collection_authority_record: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VerifyCollectionEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub collection_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub collection_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub collection_metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub collection_master_edition: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="7")]
+    pub collection_authority_record: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResizeEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub edition: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="5")]
+    pub authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="6")]
+    pub token: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CloseAccountsEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub edition: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub destination: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -377,49 +1151,92 @@ pub struct CreateMetadataAccountV3Event {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub master_edition: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="3")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub mint_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub asset_data: ::core::option::Option<AssetData>,
+    #[prost(uint32, optional, tag="8")]
+    pub decimals: ::core::option::Option<u32>,
+    #[prost(message, optional, tag="9")]
+    pub print_supply: ::core::option::Option<PrintSupply>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateEvent {
+    #[prost(string, tag="1")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub delegate_record: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="3")]
+    pub token: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="4")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="6")]
+    pub edition: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="7")]
+    pub payer: ::prost::alloc::string::String,
+    #[prost(enumeration="UpdateAuthorityType", tag="8")]
+    pub authority_type: i32,
+    /// Role of the delegate for the V2 delegate updates. Unknown, and thus unset, for V1 updates through a
+    /// delegate record, since the role is only recorded in the delegate record's account data.
+    #[prost(enumeration="DelegateRole", optional, tag="9")]
+    pub delegate_role: ::core::option::Option<i32>,
+    #[prost(oneof="update_event::UpdateArgs", tags="10, 11, 12, 13, 14, 15, 16, 17, 18")]
+    pub update_args: ::core::option::Option<update_event::UpdateArgs>,
+}
+/// Nested message and enum types in `UpdateEvent`.
+pub mod update_event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum UpdateArgs {
+        #[prost(message, tag="10")]
+        V1(super::UpdateArgsV1),
+        #[prost(message, tag="11")]
+        AsUpdateAuthorityV2(super::UpdateArgsAsUpdateAuthorityV2),
+        #[prost(message, tag="12")]
+        AsAuthorityItemDelegateV2(super::UpdateArgsAsAuthorityItemDelegateV2),
+        #[prost(message, tag="13")]
+        AsCollectionDelegateV2(super::UpdateArgsAsCollectionDelegateV2),
+        #[prost(message, tag="14")]
+        AsDataDelegateV2(super::UpdateArgsAsDataDelegateV2),
+        #[prost(message, tag="15")]
+        AsProgrammableConfigDelegateV2(super::UpdateArgsAsProgrammableConfigDelegateV2),
+        #[prost(message, tag="16")]
+        AsDataItemDelegateV2(super::UpdateArgsAsDataItemDelegateV2),
+        #[prost(message, tag="17")]
+        AsCollectionItemDelegateV2(super::UpdateArgsAsCollectionItemDelegateV2),
+        #[prost(message, tag="18")]
+        AsProgrammableConfigItemDelegateV2(super::UpdateArgsAsProgrammableConfigItemDelegateV2),
+    }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateMetadataAccountV2Event {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="3")]
+    pub data: ::core::option::Option<DataV2>,
+    #[prost(string, optional, tag="4")]
+    pub new_update_authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, optional, tag="5")]
+    pub primary_sale_happened: ::core::option::Option<bool>,
+    #[prost(bool, optional, tag="6")]
+    pub is_mutable: ::core::option::Option<bool>,
 }
-// message CreateEvent {
-//      string metadata = 1;
-//      string mint = 2;
-//      string mint_authority = 3;
-//      string update_authority = 4;
-//      AssetData asset_data = 5;
-//      optional uint32 decimals = 6;
-//      optional PrintSupply print_supply = 7;
-// }
-
-// message UpdateEvent {
-//      string authority = 1;
-//      string mint = 2;
-//      string metadata = 3;
-//      oneof update_args {
-//          UpdateArgsV1 v1 = 4;
-//          UpdateArgsAsUpdateAuthorityV2 as_update_authority_v2 = 5;
-//          UpdateArgsAsAuthorityItemDelegateV2 as_authority_item_delegate_v2 = 6;
-//          UpdateArgsAsCollectionDelegateV2 as_collection_delegate_v2 = 7;
-//          UpdateArgsAsDataDelegateV2 as_data_delegate_v2 = 8;
-//          UpdateArgsAsProgrammableConfigDelegateV2 as_programmable_config_delegate_v2 = 9;
-//          UpdateArgsAsDataItemDelegateV2 as_data_item_delegate_v2 = 10;
-//          UpdateArgsAsCollectionItemDelegateV2 as_collection_item_delegate_v2 = 11;
-//          UpdateArgsAsProgrammableConfigItemDelegateV2 as_programmable_config_item_delegate_v2 = 12;
-//      }
-// }
-
-// message UpdateMetadataAccountV2Event {
-//      optional DataV2 data = 1;
-//      optional string update_authority = 2;
-//      optional bool primary_sale_happened = 3;
-//      optional bool is_mutable = 4;
-// }
-
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateArgsV1 {
@@ -461,8 +1278,8 @@ pub struct UpdateArgsAsUpdateAuthorityV2 {
     pub uses: ::core::option::Option<UsesToggle>,
     #[prost(message, optional, tag="8")]
     pub rule_set: ::core::option::Option<RuleSetToggle>,
-    #[prost(message, optional, tag="9")]
-    pub token_standard: ::core::option::Option<TokenStandard>,
+    #[prost(enumeration="TokenStandard", optional, tag="9")]
+    pub token_standard: ::core::option::Option<i32>,
     #[prost(message, optional, tag="10")]
     pub authorization_data: ::core::option::Option<AuthorizationData>,
 }
@@ -475,8 +1292,8 @@ pub struct UpdateArgsAsAuthorityItemDelegateV2 {
     pub primary_sale_happened: ::core::option::Option<bool>,
     #[prost(bool, optional, tag="3")]
     pub is_mutable: ::core::option::Option<bool>,
-    #[prost(message, optional, tag="4")]
-    pub token_standard: ::core::option::Option<TokenStandard>,
+    #[prost(enumeration="TokenStandard", optional, tag="4")]
+    pub token_standard: ::core::option::Option<i32>,
     #[prost(message, optional, tag="5")]
     pub authorization_data: ::core::option::Option<AuthorizationData>,
 }
@@ -545,6 +1362,9 @@ pub struct Data {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PrintSupply {
+    /// Unset for an unlimited supply of prints.
+    #[prost(uint64, optional, tag="1")]
+    pub max_supply: ::core::option::Option<u64>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -563,38 +1383,91 @@ pub struct AssetData {
     pub primary_sale_happened: bool,
     #[prost(bool, tag="7")]
     pub is_mutable: bool,
-    #[prost(message, optional, tag="8")]
-    pub token_standard: ::core::option::Option<TokenStandard>,
+    #[prost(enumeration="TokenStandard", tag="8")]
+    pub token_standard: i32,
     #[prost(message, optional, tag="9")]
     pub collection: ::core::option::Option<Collection>,
     #[prost(message, optional, tag="10")]
     pub uses: ::core::option::Option<Uses>,
     #[prost(message, optional, tag="11")]
     pub collection_details: ::core::option::Option<CollectionDetails>,
+    #[prost(string, optional, tag="12")]
+    pub rule_set: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RuleSetToggle {
+    #[prost(enumeration="Toggle", tag="1")]
+    pub toggle: i32,
+    #[prost(string, optional, tag="2")]
+    pub rule_set: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CollectionToggle {
+    #[prost(enumeration="Toggle", tag="1")]
+    pub toggle: i32,
+    #[prost(message, optional, tag="2")]
+    pub collection: ::core::option::Option<Collection>,
 }
+/// Payload passed to the rule set of a programmable asset, with its entries sorted by key.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AuthorizationData {
+    #[prost(message, repeated, tag="1")]
+    pub payload: ::prost::alloc::vec::Vec<PayloadEntry>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct CollectionDetailsToggle {
+pub struct PayloadEntry {
+    /// Rule field the value is checked against, e.g. `Destination` or `Amount`.
+    #[prost(string, tag="1")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(oneof="payload_entry::Value", tags="2, 3, 4, 5")]
+    pub value: ::core::option::Option<payload_entry::Value>,
+}
+/// Nested message and enum types in `PayloadEntry`.
+pub mod payload_entry {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Value {
+        #[prost(string, tag="2")]
+        Pubkey(::prost::alloc::string::String),
+        #[prost(message, tag="3")]
+        Seeds(super::PayloadSeeds),
+        #[prost(message, tag="4")]
+        MerkleProof(super::PayloadMerkleProof),
+        #[prost(uint64, tag="5")]
+        Number(u64),
+    }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UsesToggle {
+pub struct PayloadSeeds {
+    #[prost(bytes="vec", repeated, tag="1")]
+    pub seeds: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadMerkleProof {
+    #[prost(bytes="vec", repeated, tag="1")]
+    pub proof: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TokenStandard {
+pub struct CollectionDetailsToggle {
+    #[prost(enumeration="Toggle", tag="1")]
+    pub toggle: i32,
+    #[prost(message, optional, tag="2")]
+    pub collection_details: ::core::option::Option<CollectionDetails>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsesToggle {
+    #[prost(enumeration="Toggle", tag="1")]
+    pub toggle: i32,
+    #[prost(message, optional, tag="2")]
+    pub uses: ::core::option::Option<Uses>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -671,6 +1544,331 @@ pub struct Creator {
     #[prost(uint32, tag="3")]
     pub share: u32,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenMetadata {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub uri: ::prost::alloc::string::String,
+    #[prost(uint32, tag="6")]
+    pub seller_fee_basis_points: u32,
+    #[prost(message, repeated, tag="7")]
+    pub creators: ::prost::alloc::vec::Vec<Creator>,
+    #[prost(message, optional, tag="8")]
+    pub collection: ::core::option::Option<Collection>,
+    #[prost(enumeration="TokenStandard", optional, tag="9")]
+    pub token_standard: ::core::option::Option<i32>,
+    #[prost(string, tag="10")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(bool, tag="11")]
+    pub is_mutable: bool,
+    #[prost(bool, tag="12")]
+    pub primary_sale_happened: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollectionBlockEvents {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<CollectionEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollectionEvent {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(enumeration="CollectionEventKind", tag="2")]
+    pub kind: i32,
+    #[prost(string, tag="3")]
+    pub collection_mint: ::prost::alloc::string::String,
+    /// Unset for size changes, which apply to the collection itself.
+    #[prost(string, optional, tag="4")]
+    pub item_metadata: ::core::option::Option<::prost::alloc::string::String>,
+    /// Unset when the item mint isn't known, i.e. its metadata wasn't created or updated since the start block.
+    #[prost(string, optional, tag="5")]
+    pub item_mint: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="6")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag="7")]
+    pub size: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenRecord {
+    #[prost(string, tag="1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub token: ::prost::alloc::string::String,
+    #[prost(enumeration="TokenState", tag="4")]
+    pub state: i32,
+    #[prost(string, optional, tag="5")]
+    pub delegate: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration="DelegateRole", optional, tag="6")]
+    pub delegate_role: ::core::option::Option<i32>,
+    #[prost(string, optional, tag="7")]
+    pub rule_set: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EditionBlockEvents {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<EditionEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EditionEvent {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(enumeration="EditionEventKind", tag="2")]
+    pub kind: i32,
+    #[prost(string, tag="3")]
+    pub master_edition: ::prost::alloc::string::String,
+    /// Unset when the master edition wasn't created or printed from with a known mint since the start block.
+    #[prost(string, optional, tag="4")]
+    pub master_mint: ::core::option::Option<::prost::alloc::string::String>,
+    /// Unset for master edition creations.
+    #[prost(string, optional, tag="5")]
+    pub edition_mint: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, optional, tag="6")]
+    pub edition_number: ::core::option::Option<u64>,
+    /// Unset for an unlimited or unknown max supply, as told apart by `unlimited_supply`.
+    #[prost(uint64, optional, tag="7")]
+    pub max_supply: ::core::option::Option<u64>,
+    #[prost(string, optional, tag="8")]
+    pub edition_marker: ::core::option::Option<::prost::alloc::string::String>,
+    /// Marker of the edition among the master edition's markers. Unset for programmable editions, which
+    /// share a single marker.
+    #[prost(uint64, optional, tag="9")]
+    pub edition_marker_number: ::core::option::Option<u64>,
+    /// Whether the master edition can print an unlimited supply. Unset if its creation wasn't seen.
+    #[prost(bool, optional, tag="10")]
+    pub unlimited_supply: ::core::option::Option<bool>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MasterEdition {
+    #[prost(string, tag="1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    /// Unset for an unlimited supply of prints.
+    #[prost(uint64, optional, tag="3")]
+    pub max_supply: ::core::option::Option<u64>,
+    /// Prints minted minus prints burned since the start block.
+    #[prost(int64, tag="4")]
+    pub supply: i64,
+    #[prost(bool, tag="5")]
+    pub unlimited_supply: bool,
+}
+/// Who performed an `Update`. V1 args don't name the authority, so they are attributed to a delegate
+/// when a delegate record is given and to the update authority otherwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum UpdateAuthorityType {
+    Null = 0,
+    UpdateAuthority = 1,
+    Delegate = 2,
+}
+impl UpdateAuthorityType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            UpdateAuthorityType::Null => "UPDATE_AUTHORITY_TYPE_NULL",
+            UpdateAuthorityType::UpdateAuthority => "UPDATE_AUTHORITY",
+            UpdateAuthorityType::Delegate => "DELEGATE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "UPDATE_AUTHORITY_TYPE_NULL" => Some(Self::Null),
+            "UPDATE_AUTHORITY" => Some(Self::UpdateAuthority),
+            "DELEGATE" => Some(Self::Delegate),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TokenStandard {
+    Null = 0,
+    NonFungible = 1,
+    FungibleAsset = 2,
+    Fungible = 3,
+    NonFungibleEdition = 4,
+    ProgrammableNonFungible = 5,
+    ProgrammableNonFungibleEdition = 6,
+}
+impl TokenStandard {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TokenStandard::Null => "TOKEN_STANDARD_NULL",
+            TokenStandard::NonFungible => "NON_FUNGIBLE",
+            TokenStandard::FungibleAsset => "FUNGIBLE_ASSET",
+            TokenStandard::Fungible => "FUNGIBLE",
+            TokenStandard::NonFungibleEdition => "NON_FUNGIBLE_EDITION",
+            TokenStandard::ProgrammableNonFungible => "PROGRAMMABLE_NON_FUNGIBLE",
+            TokenStandard::ProgrammableNonFungibleEdition => "PROGRAMMABLE_NON_FUNGIBLE_EDITION",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TOKEN_STANDARD_NULL" => Some(Self::Null),
+            "NON_FUNGIBLE" => Some(Self::NonFungible),
+            "FUNGIBLE_ASSET" => Some(Self::FungibleAsset),
+            "FUNGIBLE" => Some(Self::Fungible),
+            "NON_FUNGIBLE_EDITION" => Some(Self::NonFungibleEdition),
+            "PROGRAMMABLE_NON_FUNGIBLE" => Some(Self::ProgrammableNonFungible),
+            "PROGRAMMABLE_NON_FUNGIBLE_EDITION" => Some(Self::ProgrammableNonFungibleEdition),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum DelegateRole {
+    Null = 0,
+    Collection = 1,
+    Sale = 2,
+    Transfer = 3,
+    Data = 4,
+    Utility = 5,
+    Staking = 6,
+    Standard = 7,
+    LockedTransfer = 8,
+    ProgrammableConfig = 9,
+    Migration = 10,
+    AuthorityItem = 11,
+    DataItem = 12,
+    CollectionItem = 13,
+    ProgrammableConfigItem = 14,
+    PrintDelegate = 15,
+}
+impl DelegateRole {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            DelegateRole::Null => "DELEGATE_ROLE_NULL",
+            DelegateRole::Collection => "COLLECTION",
+            DelegateRole::Sale => "SALE",
+            DelegateRole::Transfer => "TRANSFER",
+            DelegateRole::Data => "DATA",
+            DelegateRole::Utility => "UTILITY",
+            DelegateRole::Staking => "STAKING",
+            DelegateRole::Standard => "STANDARD",
+            DelegateRole::LockedTransfer => "LOCKED_TRANSFER",
+            DelegateRole::ProgrammableConfig => "PROGRAMMABLE_CONFIG",
+            DelegateRole::Migration => "MIGRATION",
+            DelegateRole::AuthorityItem => "AUTHORITY_ITEM",
+            DelegateRole::DataItem => "DATA_ITEM",
+            DelegateRole::CollectionItem => "COLLECTION_ITEM",
+            DelegateRole::ProgrammableConfigItem => "PROGRAMMABLE_CONFIG_ITEM",
+            DelegateRole::PrintDelegate => "PRINT_DELEGATE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DELEGATE_ROLE_NULL" => Some(Self::Null),
+            "COLLECTION" => Some(Self::Collection),
+            "SALE" => Some(Self::Sale),
+            "TRANSFER" => Some(Self::Transfer),
+            "DATA" => Some(Self::Data),
+            "UTILITY" => Some(Self::Utility),
+            "STAKING" => Some(Self::Staking),
+            "STANDARD" => Some(Self::Standard),
+            "LOCKED_TRANSFER" => Some(Self::LockedTransfer),
+            "PROGRAMMABLE_CONFIG" => Some(Self::ProgrammableConfig),
+            "MIGRATION" => Some(Self::Migration),
+            "AUTHORITY_ITEM" => Some(Self::AuthorityItem),
+            "DATA_ITEM" => Some(Self::DataItem),
+            "COLLECTION_ITEM" => Some(Self::CollectionItem),
+            "PROGRAMMABLE_CONFIG_ITEM" => Some(Self::ProgrammableConfigItem),
+            "PRINT_DELEGATE" => Some(Self::PrintDelegate),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TokenState {
+    Null = 0,
+    Unlocked = 1,
+    Locked = 2,
+    Listed = 3,
+}
+impl TokenState {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TokenState::Null => "TOKEN_STATE_NULL",
+            TokenState::Unlocked => "UNLOCKED",
+            TokenState::Locked => "LOCKED",
+            TokenState::Listed => "LISTED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TOKEN_STATE_NULL" => Some(Self::Null),
+            "UNLOCKED" => Some(Self::Unlocked),
+            "LOCKED" => Some(Self::Locked),
+            "LISTED" => Some(Self::Listed),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum VerificationType {
+    Null = 0,
+    CreatorV1 = 1,
+    CollectionV1 = 2,
+}
+impl VerificationType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            VerificationType::Null => "VERIFICATION_TYPE_NULL",
+            VerificationType::CreatorV1 => "CREATOR_V1",
+            VerificationType::CollectionV1 => "COLLECTION_V1",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "VERIFICATION_TYPE_NULL" => Some(Self::Null),
+            "CREATOR_V1" => Some(Self::CreatorV1),
+            "COLLECTION_V1" => Some(Self::CollectionV1),
+            _ => None,
+        }
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum UseMethod {
@@ -735,4 +1933,68 @@ impl Toggle {
         }
     }
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CollectionEventKind {
+    Null = 0,
+    ItemVerified = 1,
+    ItemUnverified = 2,
+    SizeSet = 3,
+}
+impl CollectionEventKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CollectionEventKind::Null => "COLLECTION_EVENT_KIND_NULL",
+            CollectionEventKind::ItemVerified => "ITEM_VERIFIED",
+            CollectionEventKind::ItemUnverified => "ITEM_UNVERIFIED",
+            CollectionEventKind::SizeSet => "SIZE_SET",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "COLLECTION_EVENT_KIND_NULL" => Some(Self::Null),
+            "ITEM_VERIFIED" => Some(Self::ItemVerified),
+            "ITEM_UNVERIFIED" => Some(Self::ItemUnverified),
+            "SIZE_SET" => Some(Self::SizeSet),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EditionEventKind {
+    Null = 0,
+    MasterEditionCreated = 1,
+    EditionPrinted = 2,
+    EditionBurned = 3,
+}
+impl EditionEventKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            EditionEventKind::Null => "EDITION_EVENT_KIND_NULL",
+            EditionEventKind::MasterEditionCreated => "MASTER_EDITION_CREATED",
+            EditionEventKind::EditionPrinted => "EDITION_PRINTED",
+            EditionEventKind::EditionBurned => "EDITION_BURNED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EDITION_EVENT_KIND_NULL" => Some(Self::Null),
+            "MASTER_EDITION_CREATED" => Some(Self::MasterEditionCreated),
+            "EDITION_PRINTED" => Some(Self::EditionPrinted),
+            "EDITION_BURNED" => Some(Self::EditionBurned),
+            _ => None,
+        }
+    }
+}
 // @@protoc_insertion_point(module)
@@ -0,0 +1,370 @@
+use substreams::store::{StoreGet, StoreGetString, StoreNew, StoreSet, StoreSetString};
+
+use crate::fields::{get_field, set_field};
+use crate::pb::mpl_token_metadata::*;
+use crate::pb::mpl_token_metadata::mpl_token_metadata_event::Event;
+use crate::pb::mpl_token_metadata::update_event::UpdateArgs;
+
+const METADATA: &str = "metadata";
+const NAME: &str = "name";
+const SYMBOL: &str = "symbol";
+const URI: &str = "uri";
+const SELLER_FEE_BASIS_POINTS: &str = "seller_fee_basis_points";
+const CREATORS: &str = "creators";
+const CREATOR: &str = "creator";
+const COLLECTION: &str = "collection";
+const COLLECTION_VERIFIED: &str = "collection_verified";
+const TOKEN_STANDARD: &str = "token_standard";
+const UPDATE_AUTHORITY: &str = "update_authority";
+const IS_MUTABLE: &str = "is_mutable";
+const PRIMARY_SALE_HAPPENED: &str = "primary_sale_happened";
+
+/// Mint of every metadata account, to resolve the instructions that only reference the metadata.
+#[substreams::handlers::store]
+fn store_metadata_mints(events: MplTokenMetadataBlockEvents, store: StoreSetString) {
+    for transaction in events.transactions.iter() {
+        for event in transaction.events.iter() {
+            if let Some((metadata, mint)) = get_metadata_and_mint(event) {
+                store.set(0, metadata, &mint.to_string());
+            }
+        }
+    }
+}
+
+/// Latest metadata of every mint, kept one field per key (see [`crate::fields`]). Use
+/// [`get_token_metadata`] to read a whole mint.
+///
+/// Creators are stored as `{address}:{share}` lists, with their verified flag under its own
+/// `{mint}:creator:{address}` key so that creator verifications don't need the current list.
+#[substreams::handlers::store]
+fn store_token_metadata(events: MplTokenMetadataBlockEvents, metadata_mints: StoreGetString, store: StoreSetString) {
+    for transaction in events.transactions.iter() {
+        for event in transaction.events.iter() {
+            match &event.event {
+                Some(Event::CreateMetadataAccountV3(create)) => {
+                    let mint = &create.mint;
+                    set_field(&store, mint, METADATA, &create.metadata);
+                    set_field(&store, mint, UPDATE_AUTHORITY, &create.update_authority);
+                    set_field(&store, mint, IS_MUTABLE, &create.is_mutable.to_string());
+                    set_field(&store, mint, PRIMARY_SALE_HAPPENED, &false.to_string());
+                    if let Some(data) = &create.data {
+                        set_data(&store, mint, &data.name, &data.symbol, &data.uri, data.seller_fee_basis_points, &data.creators);
+                        set_collection(&store, mint, data.collection.as_ref());
+                    }
+                },
+                Some(Event::Create(create)) => {
+                    let mint = &create.mint;
+                    set_field(&store, mint, METADATA, &create.metadata);
+                    set_field(&store, mint, UPDATE_AUTHORITY, &create.update_authority);
+                    if let Some(asset_data) = &create.asset_data {
+                        set_data(&store, mint, &asset_data.name, &asset_data.symbol, &asset_data.uri, asset_data.seller_fee_basis_points, &asset_data.creators);
+                        set_collection(&store, mint, asset_data.collection.as_ref());
+                        set_field(&store, mint, IS_MUTABLE, &asset_data.is_mutable.to_string());
+                        set_field(&store, mint, PRIMARY_SALE_HAPPENED, &asset_data.primary_sale_happened.to_string());
+                        set_field(&store, mint, TOKEN_STANDARD, asset_data.token_standard().as_str_name());
+                    }
+                },
+                Some(Event::Update(update)) => {
+                    let mint = &update.mint;
+                    set_field(&store, mint, METADATA, &update.metadata);
+                    match &update.update_args {
+                        Some(UpdateArgs::V1(args)) => {
+                            set_authority_and_flags(&store, mint, args.new_update_authority.as_ref(), args.primary_sale_happened, args.is_mutable);
+                            if let Some(data) = &args.data {
+                                set_data(&store, mint, &data.name, &data.symbol, &data.uri, data.seller_fee_basis_points, &data.creators);
+                            }
+                            set_collection_toggle(&store, mint, args.collection.as_ref());
+                        },
+                        Some(UpdateArgs::AsUpdateAuthorityV2(args)) => {
+                            set_authority_and_flags(&store, mint, args.new_update_authority.as_ref(), args.primary_sale_happened, args.is_mutable);
+                            if let Some(data) = &args.data {
+                                set_data(&store, mint, &data.name, &data.symbol, &data.uri, data.seller_fee_basis_points, &data.creators);
+                            }
+                            set_collection_toggle(&store, mint, args.collection.as_ref());
+                            if args.token_standard.is_some() {
+                                set_field(&store, mint, TOKEN_STANDARD, args.token_standard().as_str_name());
+                            }
+                        },
+                        // The program rejects a new update authority from an authority item delegate.
+                        Some(UpdateArgs::AsAuthorityItemDelegateV2(args)) => {
+                            set_authority_and_flags(&store, mint, None, args.primary_sale_happened, args.is_mutable);
+                            if args.token_standard.is_some() {
+                                set_field(&store, mint, TOKEN_STANDARD, args.token_standard().as_str_name());
+                            }
+                        },
+                        Some(UpdateArgs::AsCollectionDelegateV2(args)) => {
+                            set_collection_toggle(&store, mint, args.collection.as_ref());
+                        },
+                        Some(UpdateArgs::AsCollectionItemDelegateV2(args)) => {
+                            set_collection_toggle(&store, mint, args.collection.as_ref());
+                        },
+                        Some(UpdateArgs::AsDataDelegateV2(UpdateArgsAsDataDelegateV2 { data: Some(data), .. }))
+                        | Some(UpdateArgs::AsDataItemDelegateV2(UpdateArgsAsDataItemDelegateV2 { data: Some(data), .. })) => {
+                            set_data(&store, mint, &data.name, &data.symbol, &data.uri, data.seller_fee_basis_points, &data.creators);
+                        },
+                        _ => (),
+                    }
+                },
+                Some(Event::UpdateMetadataAccountV2(update)) => {
+                    let mint = match metadata_mints.get_last(&update.metadata) {
+                        Some(mint) => mint,
+                        None => continue,
+                    };
+                    set_authority_and_flags(&store, &mint, update.new_update_authority.as_ref(), update.primary_sale_happened, update.is_mutable);
+                    if let Some(data) = &update.data {
+                        set_data(&store, &mint, &data.name, &data.symbol, &data.uri, data.seller_fee_basis_points, &data.creators);
+                        set_collection(&store, &mint, data.collection.as_ref());
+                    }
+                },
+                Some(Event::UpdatePrimarySaleHappenedViaToken(update)) => {
+                    if let Some(mint) = metadata_mints.get_last(&update.metadata) {
+                        set_field(&store, &mint, PRIMARY_SALE_HAPPENED, &true.to_string());
+                    }
+                },
+                Some(Event::SignMetadata(sign)) => {
+                    if let Some(mint) = metadata_mints.get_last(&sign.metadata) {
+                        set_field(&store, &mint, &creator_field(&sign.creator), &true.to_string());
+                    }
+                },
+                Some(Event::RemoveCreatorVerification(remove)) => {
+                    if let Some(mint) = metadata_mints.get_last(&remove.metadata) {
+                        set_field(&store, &mint, &creator_field(&remove.creator), &false.to_string());
+                    }
+                },
+                Some(Event::Verify(verify)) => {
+                    if let Some(mint) = metadata_mints.get_last(&verify.metadata) {
+                        match verify.verification_type() {
+                            VerificationType::CreatorV1 => set_field(&store, &mint, &creator_field(&verify.authority), &true.to_string()),
+                            VerificationType::CollectionV1 => set_field(&store, &mint, COLLECTION_VERIFIED, &true.to_string()),
+                            _ => (),
+                        }
+                    }
+                },
+                Some(Event::Unverify(unverify)) => {
+                    if let Some(mint) = metadata_mints.get_last(&unverify.metadata) {
+                        match unverify.verification_type() {
+                            VerificationType::CreatorV1 => set_field(&store, &mint, &creator_field(&unverify.authority), &false.to_string()),
+                            VerificationType::CollectionV1 => set_field(&store, &mint, COLLECTION_VERIFIED, &false.to_string()),
+                            _ => (),
+                        }
+                    }
+                },
+                Some(Event::SetAndVerifyCollection(SetAndVerifyCollectionEvent { metadata, collection_mint, .. }))
+                | Some(Event::SetAndVerifySizedCollectionItem(SetAndVerifySizedCollectionItemEvent { metadata, collection_mint, .. })) => {
+                    if let Some(mint) = metadata_mints.get_last(metadata) {
+                        set_field(&store, &mint, COLLECTION, collection_mint);
+                        set_field(&store, &mint, COLLECTION_VERIFIED, &true.to_string());
+                    }
+                },
+                Some(Event::VerifyCollection(VerifyCollectionEvent { metadata, .. }))
+                | Some(Event::VerifySizedCollectionItem(VerifySizedCollectionItemEvent { metadata, .. })) => {
+                    if let Some(mint) = metadata_mints.get_last(metadata) {
+                        set_field(&store, &mint, COLLECTION_VERIFIED, &true.to_string());
+                    }
+                },
+                Some(Event::UnverifyCollection(UnverifyCollectionEvent { metadata, .. }))
+                | Some(Event::UnverifySizedCollectionItem(UnverifySizedCollectionItemEvent { metadata, .. })) => {
+                    if let Some(mint) = metadata_mints.get_last(metadata) {
+                        set_field(&store, &mint, COLLECTION_VERIFIED, &false.to_string());
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Reads the metadata of a mint from `store_token_metadata`, or `None` if it was never seen.
+///
+/// Instructions that only name the metadata account, i.e. `UpdateMetadataAccountV2`, `SignMetadata`,
+/// `RemoveCreatorVerification`, `Verify`, `Unverify` and the collection verifications, are resolved
+/// through `store_metadata_mints`. Their changes are missed for metadata accounts that no instruction
+/// naming the mint touched since the start block, so the fields they set can be stale for such mints.
+/// The deprecated `UpdateMetadataAccount` names neither the mint nor the new values and is ignored.
+pub fn get_token_metadata(store: &StoreGetString, mint: &str) -> Option<TokenMetadata> {
+    let metadata = get_field(store, mint, METADATA);
+    let name = get_field(store, mint, NAME);
+    if metadata.is_none() && name.is_none() {
+        return None;
+    }
+    let get_bool = |field: &str| get_field(store, mint, field).map_or(false, |x| x == "true");
+
+    let creators = decode_creators(&get_field(store, mint, CREATORS).unwrap_or_default())
+        .into_iter()
+        .map(|(address, share)| Creator {
+            address: address.to_string(),
+            verified: get_bool(&creator_field(address)),
+            share,
+        })
+        .collect();
+    let collection = get_field(store, mint, COLLECTION)
+        .filter(|x| !x.is_empty())
+        .map(|key| Collection { key, verified: get_bool(COLLECTION_VERIFIED) });
+    let token_standard = get_field(store, mint, TOKEN_STANDARD)
+        .and_then(|x| TokenStandard::from_str_name(&x))
+        .map(|x| x.into());
+
+    Some(TokenMetadata {
+        mint: mint.to_string(),
+        metadata: metadata.unwrap_or_default(),
+        name: name.unwrap_or_default(),
+        symbol: get_field(store, mint, SYMBOL).unwrap_or_default(),
+        uri: get_field(store, mint, URI).unwrap_or_default(),
+        seller_fee_basis_points: get_field(store, mint, SELLER_FEE_BASIS_POINTS).and_then(|x| x.parse().ok()).unwrap_or(0),
+        creators,
+        collection,
+        token_standard,
+        update_authority: get_field(store, mint, UPDATE_AUTHORITY).unwrap_or_default(),
+        is_mutable: get_bool(IS_MUTABLE),
+        primary_sale_happened: get_bool(PRIMARY_SALE_HAPPENED),
+    })
+}
+
+/// Reads the mint of a metadata account from `store_metadata_mints`.
+pub fn get_metadata_mint(store: &StoreGetString, metadata: &str) -> Option<String> {
+    store.get_last(metadata)
+}
+
+/// Metadata account and mint of the asset an event applies to, when it names both.
+fn get_metadata_and_mint(event: &MplTokenMetadataEvent) -> Option<(&str, &str)> {
+    match &event.event {
+        Some(Event::CreateMetadataAccountV3(create)) => Some((&create.metadata, &create.mint)),
+        Some(Event::CreateMetadataAccountV2(create)) => Some((&create.metadata, &create.mint)),
+        Some(Event::CreateMetadataAccount(create)) => Some((&create.metadata, &create.mint)),
+        Some(Event::Create(create)) => Some((&create.metadata, &create.mint)),
+        Some(Event::Update(update)) => Some((&update.metadata, &update.mint)),
+        Some(Event::CreateMasterEditionV3(create)) => Some((&create.metadata, &create.mint)),
+        Some(Event::MintNewEditionFromMasterEditionViaToken(mint_new_edition)) => Some((&mint_new_edition.new_metadata, &mint_new_edition.new_mint)),
+        Some(Event::Print(print)) => Some((&print.edition_metadata, &print.edition_mint)),
+        Some(Event::Mint(mint)) => Some((&mint.metadata, &mint.mint)),
+        Some(Event::Transfer(transfer)) => Some((&transfer.metadata, &transfer.mint)),
+        Some(Event::SetTokenStandard(set_token_standard)) => Some((&set_token_standard.metadata, &set_token_standard.mint)),
+        _ => None,
+    }
+}
+
+fn set_data(store: &StoreSetString, mint: &str, name: &str, symbol: &str, uri: &str, seller_fee_basis_points: u32, creators: &[Creator]) {
+    // Metadata accounts pad their strings with null characters.
+    set_field(store, mint, NAME, name.trim_end_matches('\0'));
+    set_field(store, mint, SYMBOL, symbol.trim_end_matches('\0'));
+    set_field(store, mint, URI, uri.trim_end_matches('\0'));
+    set_field(store, mint, SELLER_FEE_BASIS_POINTS, &seller_fee_basis_points.to_string());
+
+    set_field(store, mint, CREATORS, &encode_creators(creators));
+    for creator in creators {
+        set_field(store, mint, &creator_field(&creator.address), &creator.verified.to_string());
+    }
+}
+
+fn set_collection(store: &StoreSetString, mint: &str, collection: Option<&Collection>) {
+    match collection {
+        Some(collection) => {
+            set_field(store, mint, COLLECTION, &collection.key);
+            set_field(store, mint, COLLECTION_VERIFIED, &collection.verified.to_string());
+        },
+        None => {
+            set_field(store, mint, COLLECTION, "");
+            set_field(store, mint, COLLECTION_VERIFIED, &false.to_string());
+        },
+    }
+}
+
+fn set_collection_toggle(store: &StoreSetString, mint: &str, toggle: Option<&CollectionToggle>) {
+    match toggle.map(|x| (x.toggle(), x.collection.as_ref())) {
+        Some((Toggle::Set, collection)) => set_collection(store, mint, collection),
+        Some((Toggle::Clear, _)) => set_collection(store, mint, None),
+        _ => (),
+    }
+}
+
+fn set_authority_and_flags(
+    store: &StoreSetString,
+    mint: &str,
+    update_authority: Option<&String>,
+    primary_sale_happened: Option<bool>,
+    is_mutable: Option<bool>,
+) {
+    if let Some(update_authority) = update_authority {
+        set_field(store, mint, UPDATE_AUTHORITY, update_authority);
+    }
+    if let Some(primary_sale_happened) = primary_sale_happened {
+        set_field(store, mint, PRIMARY_SALE_HAPPENED, &primary_sale_happened.to_string());
+    }
+    if let Some(is_mutable) = is_mutable {
+        set_field(store, mint, IS_MUTABLE, &is_mutable.to_string());
+    }
+}
+
+/// Creators as the `{address}:{share}` list kept under `CREATORS`, whose verified flags are stored apart.
+fn encode_creators(creators: &[Creator]) -> String {
+    let creator_list: Vec<String> = creators.iter().map(|x| format!("{}:{}", x.address, x.share)).collect();
+    creator_list.join(",")
+}
+
+fn decode_creators(creators: &str) -> Vec<(&str, u32)> {
+    creators
+        .split(',')
+        .filter_map(|x| x.split_once(':'))
+        .map(|(address, share)| (address, share.parse().unwrap_or(0)))
+        .collect()
+}
+
+fn creator_field(creator: &str) -> String {
+    format!("{}:{}", CREATOR, creator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn creator(address: &str, share: u32, verified: bool) -> Creator {
+        Creator { address: address.to_string(), verified, share }
+    }
+
+    #[test]
+    fn test_creators_round_trip() {
+        let creators = vec![
+            creator("6YcXTv7U8KUZfgM7mFiDYXAE5CZAyMxsQ2VrtcHEFBjY", 95, true),
+            creator("2RtGg6fsFiiF1EQzHqbd66AhW7R5bWeQGpTbv2UMkCdW", 5, false),
+        ];
+        let encoded = encode_creators(&creators);
+        assert_eq!(
+            encoded,
+            "6YcXTv7U8KUZfgM7mFiDYXAE5CZAyMxsQ2VrtcHEFBjY:95,2RtGg6fsFiiF1EQzHqbd66AhW7R5bWeQGpTbv2UMkCdW:5"
+        );
+        assert_eq!(
+            decode_creators(&encoded),
+            vec![
+                ("6YcXTv7U8KUZfgM7mFiDYXAE5CZAyMxsQ2VrtcHEFBjY", 95),
+                ("2RtGg6fsFiiF1EQzHqbd66AhW7R5bWeQGpTbv2UMkCdW", 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_creators() {
+        assert_eq!(encode_creators(&[]), "");
+        assert!(decode_creators("").is_empty());
+    }
+
+    #[test]
+    fn test_metadata_and_mint() {
+        let print = MplTokenMetadataEvent {
+            event: Some(Event::Print(PrintEvent {
+                edition_metadata: "edition_metadata".to_string(),
+                edition_mint: "edition_mint".to_string(),
+                ..Default::default()
+            })),
+        };
+        assert_eq!(get_metadata_and_mint(&print), Some(("edition_metadata", "edition_mint")));
+
+        // Instructions naming only the metadata account can't map it to its mint.
+        let sign = MplTokenMetadataEvent {
+            event: Some(Event::SignMetadata(SignMetadataEvent {
+                metadata: "metadata".to_string(),
+                creator: "creator".to_string(),
+            })),
+        };
+        assert_eq!(get_metadata_and_mint(&sign), None);
+    }
+}
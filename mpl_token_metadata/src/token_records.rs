@@ -0,0 +1,222 @@
+use substreams::store::{StoreGetString, StoreNew, StoreSetString};
+
+use crate::fields::{get_field, set_field};
+use crate::pb::mpl_token_metadata::*;
+use crate::pb::mpl_token_metadata::mpl_token_metadata_event::Event;
+
+const MINT: &str = "mint";
+const TOKEN: &str = "token";
+const STATE: &str = "state";
+const DELEGATE: &str = "delegate";
+const DELEGATE_ROLE: &str = "delegate_role";
+const RULE_SET: &str = "rule_set";
+
+/// Token records of programmable assets, kept one field per key (see [`crate::fields`]).
+/// Use [`get_token_record`] to read a whole record.
+///
+/// Records are derived from the instructions that change them rather than decoded from the accounts. An
+/// empty delegate means none is set, and an empty state that the record was closed. The rule set is the
+/// last one passed to an instruction on the record.
+#[substreams::handlers::store]
+fn store_token_records(events: MplTokenMetadataBlockEvents, store: StoreSetString) {
+    for transaction in events.transactions.iter() {
+        for event in transaction.events.iter() {
+            for (token_record, field, value) in get_token_record_changes(event) {
+                set_field(&store, &token_record, field, &value);
+            }
+        }
+    }
+}
+
+/// Reads a token record from `store_token_records`, or `None` if it was never seen or is closed. The state
+/// of a record created by `Migrate` is unknown (`Null`) until the next instruction that sets it.
+pub fn get_token_record(store: &StoreGetString, address: &str) -> Option<TokenRecord> {
+    let state = get_field(store, address, STATE)
+        .and_then(|x| TokenState::from_str_name(&x))?;
+    let non_empty = |field: &str| get_field(store, address, field).filter(|x| !x.is_empty());
+
+    Some(TokenRecord {
+        address: address.to_string(),
+        mint: non_empty(MINT).unwrap_or_default(),
+        token: non_empty(TOKEN).unwrap_or_default(),
+        state: state.into(),
+        delegate: non_empty(DELEGATE),
+        delegate_role: non_empty(DELEGATE_ROLE)
+            .and_then(|x| DelegateRole::from_str_name(&x))
+            .map(|x| x.into()),
+        rule_set: non_empty(RULE_SET),
+    })
+}
+
+/// Fields of token records the event changes, as `(token_record, field, value)`.
+fn get_token_record_changes(event: &MplTokenMetadataEvent) -> Vec<(String, &'static str, String)> {
+    let mut changes = TokenRecordChanges::default();
+    match &event.event {
+        Some(Event::Mint(MintEvent { token_record: Some(token_record), mint, token, .. })) => {
+            changes.set_token(token_record, mint, token);
+            changes.reset(token_record);
+        },
+        // Migrating to a programmable asset creates the record, locked and delegated to a migration
+        // delegate if the token account was delegated, which the instruction doesn't tell.
+        Some(Event::Migrate(migrate)) => {
+            changes.set_token(&migrate.token_record, &migrate.mint, &migrate.token);
+            changes.set(&migrate.token_record, STATE, TokenState::Null.as_str_name());
+            changes.set(&migrate.token_record, DELEGATE, "");
+            changes.set(&migrate.token_record, DELEGATE_ROLE, "");
+        },
+        // Burning a programmable asset closes its record. Legacy `Utilize` can't burn one, since the
+        // token accounts of programmable assets stay frozen, so it never closes a record.
+        Some(Event::Burn(BurnEvent { token_record: Some(token_record), .. })) => {
+            changes.set(token_record, STATE, "");
+            changes.set(token_record, DELEGATE, "");
+            changes.set(token_record, DELEGATE_ROLE, "");
+        },
+        Some(Event::Transfer(transfer)) => {
+            if let Some(owner_token_record) = &transfer.owner_token_record {
+                changes.reset(owner_token_record);
+            }
+            if let Some(destination_token_record) = &transfer.destination_token_record {
+                changes.set_token(destination_token_record, &transfer.mint, &transfer.destination);
+                changes.reset(destination_token_record);
+                changes.set_rule_set(destination_token_record, transfer.rule_set.as_ref());
+            }
+        },
+        Some(Event::Delegate(delegate)) => {
+            if let (Some(token_record), Some(token_state)) = (&delegate.token_record, delegate.token_state) {
+                if let Some(token) = &delegate.token {
+                    changes.set_token(token_record, &delegate.mint, token);
+                }
+                changes.set_state(token_record, token_state);
+                changes.set(token_record, DELEGATE, &delegate.delegate);
+                changes.set(token_record, DELEGATE_ROLE, delegate.role().as_str_name());
+                changes.set_rule_set(token_record, delegate.rule_set.as_ref());
+            }
+        },
+        Some(Event::Revoke(revoke)) => {
+            if let (Some(token_record), Some(_)) = (&revoke.token_record, revoke.token_state) {
+                changes.reset(token_record);
+                changes.set_rule_set(token_record, revoke.rule_set.as_ref());
+            }
+        },
+        Some(Event::Lock(lock)) => {
+            if let (Some(token_record), Some(token_state)) = (&lock.token_record, lock.token_state) {
+                changes.set_token(token_record, &lock.mint, &lock.token);
+                changes.set_state(token_record, token_state);
+                changes.set_rule_set(token_record, lock.rule_set.as_ref());
+            }
+        },
+        Some(Event::Unlock(unlock)) => {
+            if let (Some(token_record), Some(token_state)) = (&unlock.token_record, unlock.token_state) {
+                changes.set_token(token_record, &unlock.mint, &unlock.token);
+                changes.set_state(token_record, token_state);
+                changes.set_rule_set(token_record, unlock.rule_set.as_ref());
+            }
+        },
+        _ => (),
+    }
+    changes.0
+}
+
+#[derive(Default)]
+struct TokenRecordChanges(Vec<(String, &'static str, String)>);
+
+impl TokenRecordChanges {
+    fn set(&mut self, token_record: &str, field: &'static str, value: &str) {
+        self.0.push((token_record.to_string(), field, value.to_string()));
+    }
+
+    fn set_token(&mut self, token_record: &str, mint: &str, token: &str) {
+        self.set(token_record, MINT, mint);
+        self.set(token_record, TOKEN, token);
+    }
+
+    fn set_state(&mut self, token_record: &str, state: i32) {
+        let state = TokenState::try_from(state).unwrap_or(TokenState::Null);
+        self.set(token_record, STATE, state.as_str_name());
+    }
+
+    fn set_rule_set(&mut self, token_record: &str, rule_set: Option<&String>) {
+        if let Some(rule_set) = rule_set {
+            self.set(token_record, RULE_SET, rule_set);
+        }
+    }
+
+    /// Clears the delegate and unlocks the record, as the program does when a token delegate is revoked or the token transferred.
+    fn reset(&mut self, token_record: &str) {
+        self.set(token_record, STATE, TokenState::Unlocked.as_str_name());
+        self.set(token_record, DELEGATE, "");
+        self.set(token_record, DELEGATE_ROLE, "");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_changes(event: Event) -> Vec<(String, &'static str, String)> {
+        get_token_record_changes(&MplTokenMetadataEvent { event: Some(event) })
+    }
+
+    fn change(field: &'static str, value: &str) -> (String, &'static str, String) {
+        ("token_record".to_string(), field, value.to_string())
+    }
+
+    #[test]
+    fn test_migrate_creates_record() {
+        let changes = get_changes(Event::Migrate(MigrateEvent {
+            token_record: "token_record".to_string(),
+            mint: "mint".to_string(),
+            token: "token".to_string(),
+            ..Default::default()
+        }));
+        assert_eq!(
+            changes,
+            vec![
+                change(MINT, "mint"),
+                change(TOKEN, "token"),
+                change(STATE, "TOKEN_STATE_NULL"),
+                change(DELEGATE, ""),
+                change(DELEGATE_ROLE, ""),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_burn_closes_record() {
+        let changes = get_changes(Event::Burn(BurnEvent {
+            token_record: Some("token_record".to_string()),
+            ..Default::default()
+        }));
+        assert_eq!(changes, vec![change(STATE, ""), change(DELEGATE, ""), change(DELEGATE_ROLE, "")]);
+
+        // Non-programmable assets have no token record.
+        assert!(get_changes(Event::Burn(BurnEvent::default())).is_empty());
+    }
+
+    #[test]
+    fn test_delegate_and_revoke() {
+        let changes = get_changes(Event::Delegate(DelegateEvent {
+            delegate: "delegate".to_string(),
+            token_record: Some("token_record".to_string()),
+            mint: "mint".to_string(),
+            role: DelegateRole::Sale.into(),
+            token_state: Some(TokenState::Listed.into()),
+            ..Default::default()
+        }));
+        assert_eq!(
+            changes,
+            vec![change(STATE, "LISTED"), change(DELEGATE, "delegate"), change(DELEGATE_ROLE, "SALE")]
+        );
+
+        let changes = get_changes(Event::Revoke(RevokeEvent {
+            token_record: Some("token_record".to_string()),
+            token_state: Some(TokenState::Unlocked.into()),
+            rule_set: Some("rule_set".to_string()),
+            ..Default::default()
+        }));
+        assert_eq!(
+            changes,
+            vec![change(STATE, "UNLOCKED"), change(DELEGATE, ""), change(DELEGATE_ROLE, ""), change(RULE_SET, "rule_set")]
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use anyhow::Context;
 use anyhow::{anyhow, Error};
 
@@ -5,7 +7,6 @@ use substreams_solana::pb::sf::solana::r#type::v1::Block;
 use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
 
 use substreams_solana_utils as utils;
-use substreams_solana_utils::spl_token::TOKEN_PROGRAM_ID;
 use utils::instruction::{
     get_structured_instructions, StructuredInstruction, StructuredInstructions,
 };
@@ -23,6 +24,7 @@ use pb::pumpfun::pumpfun_event::Event;
 use pb::pumpfun::*;
 
 use system_program_substream;
+use spl_token_substream::decimals::{self, to_ui_amount, SOL_DECIMALS};
 
 #[substreams::handlers::map]
 fn pumpfun_events(block: Block) -> Result<PumpfunBlockEvents, Error> {
@@ -53,6 +55,7 @@ pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<Pumpf
 
     let context = get_context(transaction).unwrap();
     let instructions = get_structured_instructions(transaction).unwrap();
+    let mint_decimals = decimals::get_mint_decimals(transaction);
 
     for instruction in instructions.flattened().iter() {
         if instruction.program_id() != PUMPFUN_PROGRAM_ID {
@@ -60,7 +63,10 @@ pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<Pumpf
         }
 
         match parse_instruction(&instruction, &context) {
-            Ok(Some(event)) => events.push(PumpfunEvent { event: Some(event) }),
+            Ok(Some(mut event)) => {
+                set_ui_amounts(&mut event, &mint_decimals);
+                events.push(PumpfunEvent { event: Some(event) })
+            },
             Ok(None) => (),
             Err(error) => {
                 return Err(anyhow!(
@@ -74,6 +80,14 @@ pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<Pumpf
     Ok(events)
 }
 
+fn set_ui_amounts(event: &mut Event, mint_decimals: &HashMap<String, u32>) {
+    if let Event::Swap(swap) = event {
+        swap.token_decimals = mint_decimals.get(&swap.mint).copied();
+        swap.ui_token_amount = swap.token_decimals.map(|x| to_ui_amount(swap.token_amount, x));
+        swap.ui_sol_amount = swap.sol_amount.map(|x| to_ui_amount(x, SOL_DECIMALS));
+    }
+}
+
 pub fn parse_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
@@ -197,7 +211,7 @@ fn _parse_buy_instruction<'a>(
     let token_transfer_instruction = instruction
         .inner_instructions()
         .iter()
-        .find(|x| x.program_id() == TOKEN_PROGRAM_ID)
+        .find(|x| spl_token_substream::is_token_program(x.program_id()))
         .unwrap()
         .clone();
     let token_transfer = spl_token_substream::parse_transfer_instruction(
@@ -206,6 +220,7 @@ fn _parse_buy_instruction<'a>(
     )
     .map_err(|e| anyhow!(e))?;
     let user_token_pre_balance = token_transfer.destination.unwrap().pre_balance;
+    let net_token_amount = token_transfer.net_amount;
 
     let trade = match parse_pumpfun_log(instruction) {
         Ok(PumpfunLog::Trade(trade)) => Some(trade),
@@ -251,6 +266,10 @@ fn _parse_buy_instruction<'a>(
         coin_creator_fee,
         timestamp,
         complete,
+        net_token_amount,
+        token_decimals: None,
+        ui_token_amount: None,
+        ui_sol_amount: None,
     })
 }
 
@@ -280,7 +299,7 @@ fn _parse_sell_instruction(
     let token_transfer_instruction = instruction
         .inner_instructions()
         .iter()
-        .find(|x| x.program_id() == TOKEN_PROGRAM_ID)
+        .find(|x| spl_token_substream::is_token_program(x.program_id()))
         .unwrap()
         .clone();
     let token_transfer = spl_token_substream::parse_transfer_instruction(
@@ -289,6 +308,7 @@ fn _parse_sell_instruction(
     )
     .map_err(|e| anyhow!(e))?;
     let user_token_pre_balance = token_transfer.source.unwrap().pre_balance;
+    let net_token_amount = token_transfer.net_amount;
 
     let is_buy = trade.as_ref().map(|x| x.is_buy).unwrap_or(false);
     let timestamp = trade.as_ref().map(|x| x.timestamp).unwrap_or(0);
@@ -323,6 +343,10 @@ fn _parse_sell_instruction(
         coin_creator_fee,
         timestamp,
         complete,
+        net_token_amount,
+        token_decimals: None,
+        ui_token_amount: None,
+        ui_sol_amount: None,
     })
 }
 
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use anyhow::{anyhow, Error};
 
 use pumpswap::instructions_cpi::BuyCpiInstruction;
@@ -19,6 +21,8 @@ use utils::instruction::{
 };
 use utils::transaction::{get_context, TransactionContext};
 
+use spl_token_substream::decimals::{self, to_ui_amount, SOL_DECIMALS};
+
 pub mod pb;
 use pb::pumpswap::pumpswap_event::Event;
 use pb::pumpswap::*;
@@ -53,6 +57,7 @@ pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<Pumps
 
     let mut context = get_context(transaction)?;
     let instructions = get_structured_instructions(transaction)?;
+    let mint_decimals = decimals::get_mint_decimals(transaction);
     for instruction in instructions.flattened().iter() {
         context.update_balance(&instruction.instruction);
 
@@ -63,7 +68,10 @@ pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<Pumps
         // substreams::log::println(format!("txn: {:?}", transaction.id()));
 
         match parse_instruction(&instruction, &context) {
-            Ok(Some(event)) => events.push(PumpswapEvent { event: Some(event) }),
+            Ok(Some(mut event)) => {
+                set_ui_amounts(&mut event, &mint_decimals);
+                events.push(PumpswapEvent { event: Some(event) })
+            },
             Ok(None) => (),
             Err(error) => substreams::log::println(format!(
                 "Failed to process instruction of transaction {}: {}",
@@ -74,6 +82,14 @@ pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<Pumps
     Ok(events)
 }
 
+fn set_ui_amounts(event: &mut Event, mint_decimals: &HashMap<String, u32>) {
+    if let Event::Swap(swap) = event {
+        swap.token_decimals = mint_decimals.get(&swap.mint).copied();
+        swap.ui_token_amount = swap.token_decimals.map(|x| to_ui_amount(swap.token_amount, x));
+        swap.ui_sol_amount = swap.sol_amount.map(|x| to_ui_amount(x, SOL_DECIMALS));
+    }
+}
+
 pub fn parse_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
@@ -156,7 +172,7 @@ fn _parse_create_pool_instruction(
 
 fn _parse_buy_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
-    _context: &TransactionContext,
+    context: &TransactionContext,
     _buy: pumpswap::instruction::BuyInstruction,
 ) -> Result<SwapEvent, Error> {
     let pool = instruction.accounts()[0].to_string();
@@ -186,6 +202,7 @@ fn _parse_buy_instruction<'a>(
     let coin_creator_fee: Option<u64> = Some(0);
     let timestamp: i64 = trade.timestamp;
     let user_token_pre_balance: Option<u64> = Some(0);
+    let net_token_amount = parse_base_token_transfer(instruction, context, &mint).and_then(|x| x.net_amount);
     let direction = "token".to_string();
     let is_buy = true;
     let complete = "".to_string();
@@ -208,12 +225,16 @@ fn _parse_buy_instruction<'a>(
         coin_creator_fee,
         timestamp,
         complete,
+        net_token_amount,
+        token_decimals: None,
+        ui_token_amount: None,
+        ui_sol_amount: None,
     })
 }
 
 fn _parse_sell_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
-    _context: &TransactionContext,
+    context: &TransactionContext,
     _sell: pumpswap::instruction::SellInstruction,
 ) -> Result<SwapEvent, Error> {
     let pool = instruction.accounts()[0].to_string();
@@ -243,6 +264,7 @@ fn _parse_sell_instruction<'a>(
     let coin_creator_fee: Option<u64> = Some(0);
     let timestamp: i64 = trade.timestamp;
     let user_token_pre_balance: Option<u64> = Some(0);
+    let net_token_amount = parse_base_token_transfer(instruction, context, &mint).and_then(|x| x.net_amount);
     let direction = "sol".to_string();
     let is_buy = false;
     let complete = "".to_string();
@@ -265,6 +287,10 @@ fn _parse_sell_instruction<'a>(
         coin_creator_fee,
         timestamp,
         complete,
+        net_token_amount,
+        token_decimals: None,
+        ui_token_amount: None,
+        ui_sol_amount: None,
     })
 }
 
@@ -330,6 +356,19 @@ fn _parse_withdraw_instruction(
     })
 }
 
+/// Finds the inner transfer of the pool's base token, to account for Token-2022 transfer fees.
+fn parse_base_token_transfer(
+    instruction: &StructuredInstruction,
+    context: &TransactionContext,
+    mint: &str,
+) -> Option<spl_token_substream::pb::spl_token::TransferEvent> {
+    instruction
+        .inner_instructions()
+        .iter()
+        .filter_map(|inner_ix| spl_token_substream::parse_transfer_instruction(inner_ix.as_ref(), context).ok())
+        .find(|transfer| transfer.source.as_ref().is_some_and(|x| x.mint == mint))
+}
+
 // fn parse_buy_cpi_instruction(
 //     instruction: &StructuredInstruction,
 // ) -> Result<BuyCpiInstruction, anyhow::Error> {
@@ -112,5 +112,15 @@ pub struct SwapEvent {
     pub timestamp: i64,
     #[prost(string, tag="17")]
     pub complete: ::prost::alloc::string::String,
+    /// Token amount net of any Token-2022 transfer fee.
+    #[prost(uint64, optional, tag="18")]
+    pub net_token_amount: ::core::option::Option<u64>,
+    /// Decimal-adjusted amounts, the token one only if the mint decimals are in the transaction's token balances.
+    #[prost(uint32, optional, tag="19")]
+    pub token_decimals: ::core::option::Option<u32>,
+    #[prost(string, optional, tag="20")]
+    pub ui_token_amount: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="21")]
+    pub ui_sol_amount: ::core::option::Option<::prost::alloc::string::String>,
 }
 // @@protoc_insertion_point(module)
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use regex;
 use anyhow::{anyhow, Error, Context};
 
@@ -16,11 +18,14 @@ use utils::pubkey::Pubkey;
 use utils::log::Log;
 
 use spl_token_substream;
+use spl_token_substream::decimals::{self, to_ui_amount};
 
 pub mod pb;
 use pb::raydium_amm::*;
 use pb::raydium_amm::raydium_amm_event::Event;
 
+pub mod pools;
+
 #[substreams::handlers::map]
 fn raydium_amm_events(block: Block) -> Result<RaydiumAmmBlockEvents, Error> {
     let transactions = parse_block(&block);
@@ -51,6 +56,7 @@ pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<Raydi
 
     let mut context = get_context(transaction)?;
     let instructions = get_structured_instructions(transaction)?;
+    let mint_decimals = decimals::get_mint_decimals(transaction);
     for instruction in instructions.flattened().iter() {
         context.update_balance(&instruction.instruction);
         if instruction.program_id() != RAYDIUM_AMM_PROGRAM_ID {
@@ -58,7 +64,8 @@ pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<Raydi
         }
 
         match parse_instruction(&instruction, &context) {
-            Ok(Some(event)) => {
+            Ok(Some(mut event)) => {
+                set_ui_amounts(&mut event, &mint_decimals);
                 events.push(RaydiumAmmEvent {
                     event: Some(event),
                 })
@@ -70,6 +77,15 @@ pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<Raydi
     Ok(events)
 }
 
+fn set_ui_amounts(event: &mut Event, mint_decimals: &HashMap<String, u32>) {
+    if let Event::Swap(swap) = event {
+        swap.decimals_in = mint_decimals.get(&swap.mint_in).copied();
+        swap.decimals_out = mint_decimals.get(&swap.mint_out).copied();
+        swap.ui_amount_in = swap.decimals_in.map(|x| to_ui_amount(swap.amount_in, x));
+        swap.ui_amount_out = swap.decimals_out.map(|x| to_ui_amount(swap.amount_out, x));
+    }
+}
+
 pub fn parse_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext
@@ -108,24 +124,50 @@ fn _parse_swap_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
 ) -> Result<SwapEvent, String> {
-    let amm = instruction.accounts()[1].to_string();
-    let user = instruction.accounts().last().unwrap().to_string();
+    let accounts = instruction.accounts();
+    if accounts.len() < 17 {
+        return Err("Invalid number of swap accounts.".into());
+    }
+    let amm = accounts[1].to_string();
+    let user_source = &accounts[accounts.len() - 3];
+    let user_destination = &accounts[accounts.len() - 2];
+
+    // The source owner is the trader even when a router signs for it through CPI.
+    let user = match context.get_token_account(user_source) {
+        Some(token_account) => token_account.owner.to_string(),
+        None => accounts.last().unwrap().to_string(),
+    };
+    let signer = context.accounts[0].to_string();
+    let router = match instruction.top_instruction() {
+        Some(top_instruction) if top_instruction.program_id() != RAYDIUM_AMM_PROGRAM_ID => Some(top_instruction.program_id().to_string()),
+        _ => None,
+    };
 
-    let instructions_len = instruction.inner_instructions().len();
-    let transfer_in = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 2], context)?;
-    let transfer_out = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 1], context)?;
+    let transfers: Vec<_> = instruction.inner_instructions().iter()
+        .filter_map(|inner_instruction| spl_token_substream::parse_transfer_instruction(inner_instruction, context).ok())
+        .collect();
+    let transfer_in = transfers.iter()
+        .find(|transfer| transfer.source.as_ref().is_some_and(|x| x.address == user_source.to_string()))
+        .ok_or("Failed to find the user input transfer.")?
+        .clone();
+    let transfer_out = transfers.iter()
+        .find(|transfer| transfer.destination.as_ref().is_some_and(|x| x.address == user_destination.to_string()))
+        .ok_or("Failed to find the user output transfer.")?
+        .clone();
 
     let amount_in = transfer_in.amount;
     let amount_out = transfer_out.amount;
+    let net_amount_in = transfer_in.net_amount;
+    let net_amount_out = transfer_out.net_amount;
     let mint_in = transfer_in.source.as_ref().unwrap().mint.clone();
     let mint_out = transfer_out.source.as_ref().unwrap().mint.clone();
 
     let user_pre_balance_in = transfer_in.source.unwrap().pre_balance;
     let user_pre_balance_out = transfer_out.destination.unwrap().pre_balance;
 
-    let delta = if instruction.accounts().len() == 17 { 0 } else { 1 };
-    let coin_mint = context.get_token_account(&instruction.accounts()[4 + delta]).unwrap().mint.to_string();
-    let pc_mint = context.get_token_account(&instruction.accounts()[5 + delta]).unwrap().mint.to_string();
+    let delta = if accounts.len() == 17 { 0 } else { 1 };
+    let coin_mint = context.get_token_account(&accounts[4 + delta]).ok_or("Failed to find the pool coin account.")?.mint.to_string();
+    let pc_mint = context.get_token_account(&accounts[5 + delta]).ok_or("Failed to find the pool pc account.")?.mint.to_string();
 
     let direction = (if mint_out == coin_mint { "coin" } else { "pc" }).to_string();
 
@@ -153,6 +195,14 @@ fn _parse_swap_instruction<'a>(
         pc_mint,
         user_pre_balance_in,
         user_pre_balance_out,
+        router,
+        signer,
+        net_amount_in,
+        net_amount_out,
+        decimals_in: None,
+        decimals_out: None,
+        ui_amount_in: None,
+        ui_amount_out: None,
     })
 }
 
@@ -179,9 +229,9 @@ fn _parse_initialize_instruction<'a>(
     let user_pc_pre_balance = pc_transfer.source.unwrap().pre_balance;
     let user_coin_pre_balance = coin_transfer.source.unwrap().pre_balance;
 
-    let market = match parse_raydium_log(instruction) {
-        Ok(RayLog::Init(init)) => Some(Pubkey(init.market).to_string()),
-        _ => None,
+    let (market, coin_decimals, pc_decimals) = match parse_raydium_log(instruction) {
+        Ok(RayLog::Init(init)) => (Some(Pubkey(init.market).to_string()), Some(init.coin_decimals as u32), Some(init.pc_decimals as u32)),
+        _ => (None, None, None),
     };
 
     Ok(InitializeEvent {
@@ -197,6 +247,8 @@ fn _parse_initialize_instruction<'a>(
         market,
         user_pc_pre_balance,
         user_coin_pre_balance,
+        coin_decimals,
+        pc_decimals,
     })
 }
 
@@ -340,7 +392,7 @@ fn parse_raydium_log(instruction: &StructuredInstruction) -> Result<RayLog, Erro
     });
     match log_message {
         Some(message) => match re.captures(message.as_str()) {
-            Some(captures) => Ok(decode_ray_log(&captures[1])),
+            Some(captures) => decode_ray_log(&captures[1]).map_err(|x| anyhow!(x)),
             None => return Err(anyhow!("Failed to capture log message")),
         },
         None => return Err(anyhow!("Log message not found")),
@@ -63,6 +63,10 @@ pub struct InitializeEvent {
     pub user_pc_pre_balance: ::core::option::Option<u64>,
     #[prost(uint64, optional, tag="12")]
     pub user_coin_pre_balance: ::core::option::Option<u64>,
+    #[prost(uint32, optional, tag="13")]
+    pub coin_decimals: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="14")]
+    pub pc_decimals: ::core::option::Option<u32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -169,5 +173,75 @@ pub struct SwapEvent {
     pub user_pre_balance_in: ::core::option::Option<u64>,
     #[prost(uint64, optional, tag="13")]
     pub user_pre_balance_out: ::core::option::Option<u64>,
+    #[prost(string, optional, tag="14")]
+    pub router: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="15")]
+    pub signer: ::prost::alloc::string::String,
+    /// Amounts net of Token-2022 transfer fees: what the pool received and what the user received. Unset
+    /// when the transfer doesn't state its fee.
+    #[prost(uint64, optional, tag="16")]
+    pub net_amount_in: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="17")]
+    pub net_amount_out: ::core::option::Option<u64>,
+    /// Mint decimals and decimal-adjusted amounts, unset if the decimals aren't in the transaction's token balances.
+    #[prost(uint32, optional, tag="18")]
+    pub decimals_in: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="19")]
+    pub decimals_out: ::core::option::Option<u32>,
+    #[prost(string, optional, tag="20")]
+    pub ui_amount_in: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="21")]
+    pub ui_amount_out: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Pool {
+    #[prost(string, tag="1")]
+    pub amm: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub coin_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub pc_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub lp_mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="5")]
+    pub market: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag="6")]
+    pub nonce: u32,
+    #[prost(uint32, optional, tag="7")]
+    pub coin_decimals: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="8")]
+    pub pc_decimals: ::core::option::Option<u32>,
+    #[prost(uint64, optional, tag="9")]
+    pub pool_coin_amount: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="10")]
+    pub pool_pc_amount: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="11")]
+    pub pool_lp_amount: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RaydiumAmmBlockPoolEvents {
+    #[prost(message, repeated, tag="1")]
+    pub transactions: ::prost::alloc::vec::Vec<RaydiumAmmTransactionPoolEvents>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RaydiumAmmTransactionPoolEvents {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="2")]
+    pub events: ::prost::alloc::vec::Vec<RaydiumAmmPoolEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RaydiumAmmPoolEvent {
+    #[prost(message, optional, tag="1")]
+    pub event: ::core::option::Option<RaydiumAmmEvent>,
+    #[prost(message, optional, tag="2")]
+    pub pool: ::core::option::Option<Pool>,
+    /// Decimal-adjusted price of the coin token in pc.
+    #[prost(double, optional, tag="3")]
+    pub price: ::core::option::Option<f64>,
 }
 // @@protoc_insertion_point(module)
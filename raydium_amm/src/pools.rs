@@ -0,0 +1,204 @@
+use substreams::errors::Error;
+use substreams::scalar::BigInt;
+use substreams::store::{StoreGet, StoreGetBigInt, StoreGetInt64, StoreGetProto, StoreNew, StoreSet, StoreSetBigInt, StoreSetProto};
+
+use spl_token_substream::decimals::get_stored_decimals;
+
+use crate::pb::raydium_amm::*;
+use crate::pb::raydium_amm::raydium_amm_event::Event;
+
+/// Pool reserves right after an event was processed.
+pub struct PoolReserves {
+    pub coin: Option<u64>,
+    pub pc: Option<u64>,
+    pub lp: Option<u64>,
+}
+
+#[substreams::handlers::store]
+fn store_raydium_pools(events: RaydiumAmmBlockEvents, store: StoreSetProto<Pool>) {
+    for transaction in events.transactions.iter() {
+        for event in transaction.events.iter() {
+            if let Some(Event::Initialize(initialize)) = &event.event {
+                let pool = Pool {
+                    amm: initialize.amm.clone(),
+                    coin_mint: initialize.coin_mint.clone(),
+                    pc_mint: initialize.pc_mint.clone(),
+                    lp_mint: initialize.lp_mint.clone(),
+                    market: initialize.market.clone(),
+                    nonce: initialize.nonce,
+                    coin_decimals: initialize.coin_decimals,
+                    pc_decimals: initialize.pc_decimals,
+                    pool_coin_amount: Some(initialize.coin_init_amount),
+                    pool_pc_amount: Some(initialize.pc_init_amount),
+                    pool_lp_amount: Some(initialize.lp_init_amount),
+                };
+                store.set(0, &initialize.amm, &pool);
+            }
+        }
+    }
+}
+
+#[substreams::handlers::store]
+fn store_raydium_pool_reserves(events: RaydiumAmmBlockEvents, store: StoreSetBigInt) {
+    for transaction in events.transactions.iter() {
+        for event in transaction.events.iter() {
+            let event = match &event.event {
+                Some(event) => event,
+                None => continue,
+            };
+            let amm = get_amm(event);
+            let reserves = get_pool_reserves(event);
+            if let Some(coin) = reserves.coin {
+                store.set(0, reserve_key(amm, "coin"), &BigInt::from(coin));
+            }
+            if let Some(pc) = reserves.pc {
+                store.set(0, reserve_key(amm, "pc"), &BigInt::from(pc));
+            }
+            if let Some(lp) = reserves.lp {
+                store.set(0, reserve_key(amm, "lp"), &BigInt::from(lp));
+            }
+        }
+    }
+}
+
+#[substreams::handlers::map]
+fn raydium_amm_pool_events(
+    events: RaydiumAmmBlockEvents,
+    pools: StoreGetProto<Pool>,
+    reserves: StoreGetBigInt,
+    mint_decimals: StoreGetInt64,
+) -> Result<RaydiumAmmBlockPoolEvents, Error> {
+    let mut transactions: Vec<RaydiumAmmTransactionPoolEvents> = Vec::new();
+    for transaction in events.transactions {
+        let mut pool_events: Vec<RaydiumAmmPoolEvent> = Vec::new();
+        for event in transaction.events {
+            let inner = match &event.event {
+                Some(inner) => inner,
+                None => continue,
+            };
+            let amm = get_amm(inner).to_string();
+            let mut pool = pools.get_last(&amm).unwrap_or_else(|| pool_from_event(inner));
+
+            // Reserves carried by the event are exact for this point in the block, the store only
+            // holds the state at the end of it.
+            let event_reserves = get_pool_reserves(inner);
+            let stored_reserve = |side: &str| reserves.get_last(reserve_key(&amm, side)).map(|x| x.to_u64());
+            pool.pool_coin_amount = event_reserves.coin.or_else(|| stored_reserve("coin")).or(pool.pool_coin_amount);
+            pool.pool_pc_amount = event_reserves.pc.or_else(|| stored_reserve("pc")).or(pool.pool_pc_amount);
+            pool.pool_lp_amount = event_reserves.lp.or_else(|| stored_reserve("lp")).or(pool.pool_lp_amount);
+
+            // Pools initialized before the start block have no decimals from `InitLog`.
+            let decimals = |mint: &str| get_event_decimals(inner, mint).or_else(|| get_stored_decimals(&mint_decimals, mint));
+            pool.coin_decimals = pool.coin_decimals.or_else(|| decimals(&pool.coin_mint));
+            pool.pc_decimals = pool.pc_decimals.or_else(|| decimals(&pool.pc_mint));
+
+            let price = get_price(&pool);
+            pool_events.push(RaydiumAmmPoolEvent {
+                event: Some(event),
+                pool: Some(pool),
+                price,
+            });
+        }
+        if !pool_events.is_empty() {
+            transactions.push(RaydiumAmmTransactionPoolEvents {
+                signature: transaction.signature,
+                events: pool_events,
+            });
+        }
+    }
+    Ok(RaydiumAmmBlockPoolEvents { transactions })
+}
+
+pub fn get_amm(event: &Event) -> &str {
+    match event {
+        Event::Initialize(initialize) => &initialize.amm,
+        Event::Deposit(deposit) => &deposit.amm,
+        Event::Withdraw(withdraw) => &withdraw.amm,
+        Event::WithdrawPnl(withdraw_pnl) => &withdraw_pnl.amm,
+        Event::Swap(swap) => &swap.amm,
+    }
+}
+
+/// Computes the reserves after the event from the pre-event pool amounts reported by `ray_log`.
+pub fn get_pool_reserves(event: &Event) -> PoolReserves {
+    match event {
+        Event::Initialize(initialize) => PoolReserves {
+            coin: Some(initialize.coin_init_amount),
+            pc: Some(initialize.pc_init_amount),
+            lp: Some(initialize.lp_init_amount),
+        },
+        Event::Deposit(deposit) => PoolReserves {
+            coin: deposit.pool_coin_amount.map(|x| x.saturating_add(deposit.coin_amount)),
+            pc: deposit.pool_pc_amount.map(|x| x.saturating_add(deposit.pc_amount)),
+            lp: deposit.pool_lp_amount.map(|x| x.saturating_add(deposit.lp_amount)),
+        },
+        Event::Withdraw(withdraw) => PoolReserves {
+            coin: withdraw.pool_coin_amount.map(|x| x.saturating_sub(withdraw.coin_amount)),
+            pc: withdraw.pool_pc_amount.map(|x| x.saturating_sub(withdraw.pc_amount)),
+            lp: withdraw.pool_lp_amount.map(|x| x.saturating_sub(withdraw.lp_amount)),
+        },
+        Event::Swap(swap) => {
+            let (coin, pc) = if swap.direction == "coin" {
+                (
+                    swap.pool_coin_amount.map(|x| x.saturating_sub(swap.amount_out)),
+                    swap.pool_pc_amount.map(|x| x.saturating_add(swap.amount_in)),
+                )
+            } else {
+                (
+                    swap.pool_coin_amount.map(|x| x.saturating_add(swap.amount_in)),
+                    swap.pool_pc_amount.map(|x| x.saturating_sub(swap.amount_out)),
+                )
+            };
+            PoolReserves { coin, pc, lp: None }
+        },
+        Event::WithdrawPnl(_) => PoolReserves { coin: None, pc: None, lp: None },
+    }
+}
+
+/// Decimal-adjusted price of the coin token denominated in the pc token.
+pub fn get_price(pool: &Pool) -> Option<f64> {
+    let coin = pool.pool_coin_amount? as f64 / 10f64.powi(pool.coin_decimals? as i32);
+    let pc = pool.pool_pc_amount? as f64 / 10f64.powi(pool.pc_decimals? as i32);
+    if coin == 0.0 {
+        return None;
+    }
+    Some(pc / coin)
+}
+
+/// Decimals of a mint from the transaction's token balances, as carried by swap events.
+fn get_event_decimals(event: &Event, mint: &str) -> Option<u32> {
+    match event {
+        Event::Swap(swap) if swap.mint_in == mint => swap.decimals_in,
+        Event::Swap(swap) if swap.mint_out == mint => swap.decimals_out,
+        _ => None,
+    }
+}
+
+fn pool_from_event(event: &Event) -> Pool {
+    let mut pool = Pool {
+        amm: get_amm(event).to_string(),
+        ..Default::default()
+    };
+    match event {
+        Event::Swap(swap) => {
+            pool.coin_mint = swap.coin_mint.clone();
+            pool.pc_mint = swap.pc_mint.clone();
+        },
+        Event::Deposit(deposit) => {
+            pool.coin_mint = deposit.coin_mint.clone();
+            pool.pc_mint = deposit.pc_mint.clone();
+            pool.lp_mint = deposit.lp_mint.clone();
+        },
+        Event::Withdraw(withdraw) => {
+            pool.coin_mint = withdraw.coin_mint.clone();
+            pool.pc_mint = withdraw.pc_mint.clone();
+            pool.lp_mint = withdraw.lp_mint.clone();
+        },
+        _ => (),
+    }
+    pool
+}
+
+fn reserve_key(amm: &str, side: &str) -> String {
+    format!("{}:{}", amm, side)
+}
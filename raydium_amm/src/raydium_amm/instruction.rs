@@ -500,7 +500,7 @@ impl AmmInstruction {
             }
             12 => {
                 let (param, rest) = Self::unpack_u8(rest)?;
-                match SimulateParams::from_u64(param as u64) {
+                match SimulateParams::from_u64(param as u64)? {
                     SimulateParams::PoolInfo | SimulateParams::RunCrankInfo => {
                         Self::SimulateInfo(SimulateInstruction {
                             param,
@@ -737,7 +737,7 @@ impl AmmInstruction {
             }) => {
                 buf.push(12);
                 buf.push(*param);
-                match SimulateParams::from_u64(*param as u64) {
+                match SimulateParams::from_u64(*param as u64)? {
                     SimulateParams::PoolInfo | SimulateParams::RunCrankInfo => {}
                     SimulateParams::SwapBaseInInfo => {
                         let swap_base_in = match swap_base_in_value {
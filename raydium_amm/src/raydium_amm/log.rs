@@ -24,14 +24,14 @@ pub enum RayLog {
 }
 
 impl LogType {
-    pub fn from_u8(log_type: u8) -> Self {
+    pub fn from_u8(log_type: u8) -> Result<Self, &'static str> {
         match log_type {
-            0 => LogType::Init,
-            1 => LogType::Deposit,
-            2 => LogType::Withdraw,
-            3 => LogType::SwapBaseIn,
-            4 => LogType::SwapBaseOut,
-            _ => unreachable!(),
+            0 => Ok(LogType::Init),
+            1 => Ok(LogType::Deposit),
+            2 => Ok(LogType::Withdraw),
+            3 => Ok(LogType::SwapBaseIn),
+            4 => Ok(LogType::SwapBaseOut),
+            _ => Err("Invalid log type"),
         }
     }
 
@@ -128,28 +128,15 @@ pub struct SwapBaseOutLog {
     pub deduct_in: u64,
 }
 
-pub fn decode_ray_log(log: &str) -> RayLog {
-    let bytes = base64::decode(log).unwrap();
-    match LogType::from_u8(bytes[0]) {
-        LogType::Init => {
-            let log: InitLog = bincode::deserialize(&bytes).unwrap();
-            return RayLog::Init(log);
-        }
-        LogType::Deposit => {
-            let log: DepositLog = bincode::deserialize(&bytes).unwrap();
-            return RayLog::Deposit(log);
-        }
-        LogType::Withdraw => {
-            let log: WithdrawLog = bincode::deserialize(&bytes).unwrap();
-            return RayLog::Withdraw(log);
-        }
-        LogType::SwapBaseIn => {
-            let log: SwapBaseInLog = bincode::deserialize(&bytes).unwrap();
-            return RayLog::SwapBaseIn(log);
-        }
-        LogType::SwapBaseOut => {
-            let log: SwapBaseOutLog = bincode::deserialize(&bytes).unwrap();
-            return RayLog::SwapBaseOut(log);
-        }
+pub fn decode_ray_log(log: &str) -> Result<RayLog, &'static str> {
+    let bytes = base64::decode(log).map_err(|_| "Invalid ray_log encoding")?;
+    let log_type = LogType::from_u8(*bytes.first().ok_or("Empty ray_log")?)?;
+    let invalid = |_| "Invalid ray_log data";
+    match log_type {
+        LogType::Init => bincode::deserialize(&bytes).map(RayLog::Init).map_err(invalid),
+        LogType::Deposit => bincode::deserialize(&bytes).map(RayLog::Deposit).map_err(invalid),
+        LogType::Withdraw => bincode::deserialize(&bytes).map(RayLog::Withdraw).map_err(invalid),
+        LogType::SwapBaseIn => bincode::deserialize(&bytes).map(RayLog::SwapBaseIn).map_err(invalid),
+        LogType::SwapBaseOut => bincode::deserialize(&bytes).map(RayLog::SwapBaseOut).map_err(invalid),
     }
 }
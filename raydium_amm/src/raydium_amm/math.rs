@@ -0,0 +1,347 @@
+use super::instruction::SimulateInstruction;
+use super::log::{SwapBaseInLog, SwapBaseOutLog};
+use super::state::{Fees, SimulateParams};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u64)]
+pub enum SwapDirection {
+    /// Input coin, output pc
+    Coin2PC = 1u64,
+    /// Input pc, output coin
+    PC2Coin = 2u64,
+}
+impl SwapDirection {
+    pub fn from_u64(direction: u64) -> Result<Self, &'static str> {
+        match direction {
+            1u64 => Ok(SwapDirection::Coin2PC),
+            2u64 => Ok(SwapDirection::PC2Coin),
+            _ => Err("Invalid swap direction"),
+        }
+    }
+
+    pub fn into_u64(&self) -> u64 {
+        match self {
+            SwapDirection::Coin2PC => 1u64,
+            SwapDirection::PC2Coin => 2u64,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SwapQuote {
+    /// amount the user pays, fee included
+    pub amount_in: u64,
+    /// amount the user receives
+    pub amount_out: u64,
+    /// portion of `amount_in` taken as swap fee
+    pub fee: u64,
+    /// relative distance between the spot price and the execution price, fees excluded
+    pub price_impact: f64,
+}
+
+/// Offline reproduction of the AMM v4 constant product swap math.
+///
+/// `pool_coin` and `pool_pc` are the pool amounts without the pending pnl, as reported in the
+/// `pool_coin`/`pool_pc` fields of the swap logs.
+pub struct Calculator {}
+
+impl Calculator {
+    /// Ceiling division with the rounding rules of the on-chain program, where a zero quotient is
+    /// rounded to one when the dividend is at least half the divisor.
+    pub fn checked_ceil_div(dividend: u128, divisor: u128) -> Option<u128> {
+        let quotient = dividend.checked_div(divisor)?;
+        if quotient == 0 {
+            if dividend.checked_mul(2)? >= divisor {
+                return Some(1);
+            } else {
+                return Some(0);
+            }
+        }
+        if dividend.checked_rem(divisor)? > 0 {
+            return quotient.checked_add(1);
+        }
+        Some(quotient)
+    }
+
+    pub fn swap_token_amount_base_in(
+        amount_in: u128,
+        pool_pc: u128,
+        pool_coin: u128,
+        direction: SwapDirection,
+    ) -> Option<u128> {
+        let (reserve_in, reserve_out) = match direction {
+            SwapDirection::Coin2PC => (pool_coin, pool_pc),
+            SwapDirection::PC2Coin => (pool_pc, pool_coin),
+        };
+        // (reserve_in + amount_in) * (reserve_out - amount_out) = reserve_in * reserve_out
+        // => amount_out = reserve_out * amount_in / (reserve_in + amount_in)
+        let denominator = reserve_in.checked_add(amount_in)?;
+        reserve_out.checked_mul(amount_in)?.checked_div(denominator)
+    }
+
+    pub fn swap_token_amount_base_out(
+        amount_out: u128,
+        pool_pc: u128,
+        pool_coin: u128,
+        direction: SwapDirection,
+    ) -> Option<u128> {
+        let (reserve_in, reserve_out) = match direction {
+            SwapDirection::Coin2PC => (pool_coin, pool_pc),
+            SwapDirection::PC2Coin => (pool_pc, pool_coin),
+        };
+        // (reserve_in + amount_in) * (reserve_out - amount_out) = reserve_in * reserve_out
+        // => amount_in = reserve_in * amount_out / (reserve_out - amount_out)
+        let denominator = reserve_out.checked_sub(amount_out)?;
+        if denominator == 0 {
+            return None;
+        }
+        Self::checked_ceil_div(reserve_in.checked_mul(amount_out)?, denominator)
+    }
+
+    /// Fraction of the spot price lost by trading `amount_in` (fees excluded) against `reserve_in`.
+    pub fn price_impact(amount_in: u64, pool_coin: u64, pool_pc: u64, direction: SwapDirection) -> f64 {
+        let reserve_in = match direction {
+            SwapDirection::Coin2PC => pool_coin,
+            SwapDirection::PC2Coin => pool_pc,
+        };
+        let total = reserve_in as f64 + amount_in as f64;
+        if total == 0.0 {
+            return 0.0;
+        }
+        amount_in as f64 / total
+    }
+
+    /// Quotes the output of an exact input swap, as `SwapBaseIn` would execute it.
+    pub fn swap_base_in(
+        amount_in: u64,
+        pool_coin: u64,
+        pool_pc: u64,
+        fees: &Fees,
+        direction: SwapDirection,
+    ) -> Result<SwapQuote, &'static str> {
+        let fee = Self::checked_ceil_div(
+            (amount_in as u128).checked_mul(fees.swap_fee_numerator as u128).ok_or("Overflow")?,
+            fees.swap_fee_denominator as u128,
+        ).ok_or("Invalid fee")?;
+        let amount_in_after_fee = (amount_in as u128).checked_sub(fee).ok_or("Fee exceeds amount in")?;
+        let amount_out = Self::swap_token_amount_base_in(amount_in_after_fee, pool_pc as u128, pool_coin as u128, direction)
+            .ok_or("Overflow")?;
+
+        Ok(SwapQuote {
+            amount_in,
+            amount_out: u64::try_from(amount_out).map_err(|_| "Overflow")?,
+            fee: fee as u64,
+            price_impact: Self::price_impact(amount_in_after_fee as u64, pool_coin, pool_pc, direction),
+        })
+    }
+
+    /// Quotes the input required by an exact output swap, as `SwapBaseOut` would execute it.
+    pub fn swap_base_out(
+        amount_out: u64,
+        pool_coin: u64,
+        pool_pc: u64,
+        fees: &Fees,
+        direction: SwapDirection,
+    ) -> Result<SwapQuote, &'static str> {
+        let amount_in_before_fee = Self::swap_token_amount_base_out(amount_out as u128, pool_pc as u128, pool_coin as u128, direction)
+            .ok_or("Insufficient liquidity")?;
+        let amount_in = Self::checked_ceil_div(
+            amount_in_before_fee.checked_mul(fees.swap_fee_denominator as u128).ok_or("Overflow")?,
+            (fees.swap_fee_denominator as u128).checked_sub(fees.swap_fee_numerator as u128).ok_or("Invalid fee")?,
+        ).ok_or("Invalid fee")?;
+        let amount_in = u64::try_from(amount_in).map_err(|_| "Overflow")?;
+        let amount_in_before_fee = u64::try_from(amount_in_before_fee).map_err(|_| "Overflow")?;
+
+        Ok(SwapQuote {
+            amount_in,
+            amount_out,
+            fee: amount_in - amount_in_before_fee,
+            price_impact: Self::price_impact(amount_in_before_fee, pool_coin, pool_pc, direction),
+        })
+    }
+
+    /// Reproduces the swap information returned by the `SimulateInfo` instruction.
+    pub fn simulate(
+        simulate: &SimulateInstruction,
+        pool_coin: u64,
+        pool_pc: u64,
+        fees: &Fees,
+        direction: SwapDirection,
+    ) -> Result<SwapQuote, &'static str> {
+        match SimulateParams::from_u64(simulate.param as u64)? {
+            SimulateParams::SwapBaseInInfo => {
+                let swap = simulate.swap_base_in_value.ok_or("Missing swap base in value")?;
+                Self::swap_base_in(swap.amount_in, pool_coin, pool_pc, fees, direction)
+            },
+            SimulateParams::SwapBaseOutInfo => {
+                let swap = simulate.swap_base_out_value.ok_or("Missing swap base out value")?;
+                Self::swap_base_out(swap.amount_out, pool_coin, pool_pc, fees, direction)
+            },
+            SimulateParams::PoolInfo | SimulateParams::RunCrankInfo => Err("Simulation does not quote a swap"),
+        }
+    }
+
+    /// Quotes the swap described by a `SwapBaseInLog` from the pool state it reports.
+    pub fn swap_base_in_from_log(log: &SwapBaseInLog, fees: &Fees) -> Result<SwapQuote, &'static str> {
+        Self::swap_base_in(log.amount_in, log.pool_coin, log.pool_pc, fees, SwapDirection::from_u64(log.direction)?)
+    }
+
+    /// Quotes the swap described by a `SwapBaseOutLog` from the pool state it reports.
+    pub fn swap_base_out_from_log(log: &SwapBaseOutLog, fees: &Fees) -> Result<SwapQuote, &'static str> {
+        Self::swap_base_out(log.amount_out, log.pool_coin, log.pool_pc, fees, SwapDirection::from_u64(log.direction)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::instruction::{SwapInstructionBaseIn, SwapInstructionBaseOut};
+    use super::super::log::{decode_ray_log, RayLog};
+
+    fn default_fees() -> Fees {
+        let mut fees = Fees::default();
+        fees.initialize().unwrap();
+        fees
+    }
+
+    #[test]
+    fn test_swap_base_in_matches_log() {
+        let fees = default_fees();
+        let log = SwapBaseInLog {
+            log_type: 3,
+            amount_in: 1_000_000_000,
+            minimum_out: 1_900_000_000,
+            direction: 2,
+            user_source: 4_000_000_000,
+            pool_coin: 1_000_000_000_000,
+            pool_pc: 500_000_000_000,
+            out_amount: 1_991_027_899,
+        };
+        let quote = Calculator::swap_base_in_from_log(&log, &fees).unwrap();
+        assert_eq!(quote.amount_out, log.out_amount);
+        assert_eq!(quote.fee, 2_500_000);
+        assert!((quote.price_impact - 0.001991027899).abs() < 1e-9);
+
+        let log = SwapBaseInLog {
+            log_type: 3,
+            amount_in: 12_345_678_901,
+            minimum_out: 0,
+            direction: 1,
+            user_source: 12_345_678_901,
+            pool_coin: 2_345_678_901_234,
+            pool_pc: 98_765_432_100,
+            out_amount: 515_810_532,
+        };
+        let quote = Calculator::swap_base_in_from_log(&log, &fees).unwrap();
+        assert_eq!(quote.amount_out, log.out_amount);
+        assert_eq!(quote.fee, 30_864_198);
+    }
+
+    #[test]
+    fn test_swap_base_out_matches_log() {
+        let fees = default_fees();
+        let log = SwapBaseOutLog {
+            log_type: 4,
+            max_in: 25_000_000_000,
+            amount_out: 1_000_000_000,
+            direction: 1,
+            user_source: 30_000_000_000,
+            pool_coin: 2_345_678_901_234,
+            pool_pc: 98_765_432_100,
+            deduct_in: 24_053_059_914,
+        };
+        let quote = Calculator::swap_base_out_from_log(&log, &fees).unwrap();
+        assert_eq!(quote.amount_in, log.deduct_in);
+        assert_eq!(quote.fee, 60_132_650);
+
+        let log = SwapBaseOutLog {
+            log_type: 4,
+            max_in: 3_000_000_000,
+            amount_out: 5_000_000_000,
+            direction: 2,
+            user_source: 10_000_000_000,
+            pool_coin: 1_000_000_000_000,
+            pool_pc: 500_000_000_000,
+            deduct_in: 2_518_859_965,
+        };
+        let quote = Calculator::swap_base_out_from_log(&log, &fees).unwrap();
+        assert_eq!(quote.amount_in, log.deduct_in);
+        assert_eq!(quote.fee, 6_297_150);
+    }
+
+    #[test]
+    fn test_swap_matches_encoded_ray_log() {
+        // `ray_log` payloads as the program prints them: a bincode-serialized log, base64 encoded.
+        let fees = default_fees();
+        let log = match decode_ray_log("AwDKmjsAAAAAALM/cQAAAAACAAAAAAAAAAAoa+4AAAAAABCl1OgAAAAAiFJqdAAAALusrHYAAAAA").unwrap() {
+            RayLog::SwapBaseIn(log) => log,
+            log => panic!("Unexpected log {:?}", log),
+        };
+        assert_eq!(log.amount_in, 1_000_000_000);
+        assert_eq!(log.pool_coin, 1_000_000_000_000);
+        assert_eq!(log.pool_pc, 500_000_000_000);
+        let quote = Calculator::swap_base_in_from_log(&log, &fees).unwrap();
+        assert_eq!(quote.amount_out, log.out_amount);
+
+        let log = match decode_ray_log("BABe0LIAAAAAAPIFKgEAAAACAAAAAAAAAADkC1QCAAAAABCl1OgAAAAAiFJqdAAAAL3AIpYAAAAA").unwrap() {
+            RayLog::SwapBaseOut(log) => log,
+            log => panic!("Unexpected log {:?}", log),
+        };
+        assert_eq!(log.amount_out, 5_000_000_000);
+        let quote = Calculator::swap_base_out_from_log(&log, &fees).unwrap();
+        assert_eq!(quote.amount_in, log.deduct_in);
+        assert!(quote.amount_in <= log.max_in);
+
+        assert!(decode_ray_log("AwDKmjsAAAAAALM/").is_err());
+        assert!(decode_ray_log("not base64!").is_err());
+    }
+
+    #[test]
+    fn test_simulate() {
+        let fees = default_fees();
+        let simulate = SimulateInstruction {
+            param: SimulateParams::SwapBaseInInfo.into_u64() as u8,
+            swap_base_in_value: Some(SwapInstructionBaseIn { amount_in: 1_000_000_000, minimum_amount_out: 0 }),
+            swap_base_out_value: None,
+        };
+        let quote = Calculator::simulate(&simulate, 1_000_000_000_000, 500_000_000_000, &fees, SwapDirection::PC2Coin).unwrap();
+        assert_eq!(quote.amount_out, 1_991_027_899);
+
+        let simulate = SimulateInstruction {
+            param: SimulateParams::SwapBaseOutInfo.into_u64() as u8,
+            swap_base_in_value: None,
+            swap_base_out_value: Some(SwapInstructionBaseOut { max_amount_in: u64::MAX, amount_out: 5_000_000_000 }),
+        };
+        let quote = Calculator::simulate(&simulate, 1_000_000_000_000, 500_000_000_000, &fees, SwapDirection::PC2Coin).unwrap();
+        assert_eq!(quote.amount_in, 2_518_859_965);
+
+        let simulate = SimulateInstruction {
+            param: SimulateParams::PoolInfo.into_u64() as u8,
+            swap_base_in_value: None,
+            swap_base_out_value: None,
+        };
+        assert!(Calculator::simulate(&simulate, 1, 1, &fees, SwapDirection::PC2Coin).is_err());
+    }
+
+    #[test]
+    fn test_invalid_log_direction() {
+        let fees = default_fees();
+        let log = SwapBaseInLog {
+            log_type: 3,
+            amount_in: 1_000,
+            minimum_out: 0,
+            direction: 0,
+            user_source: 1_000,
+            pool_coin: 1_000_000,
+            pool_pc: 1_000_000,
+            out_amount: 0,
+        };
+        assert!(Calculator::swap_base_in_from_log(&log, &fees).is_err());
+    }
+
+    #[test]
+    fn test_swap_base_out_exceeding_reserves() {
+        let fees = default_fees();
+        assert!(Calculator::swap_base_out(1_000, 1_000, 1_000, &fees, SwapDirection::Coin2PC).is_err());
+    }
+}
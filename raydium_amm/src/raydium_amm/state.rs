@@ -458,13 +458,13 @@ pub enum SimulateParams {
     RunCrankInfo = 3u64,
 }
 impl SimulateParams {
-    pub fn from_u64(flag: u64) -> Self {
+    pub fn from_u64(flag: u64) -> Result<Self, &'static str> {
         match flag {
-            0u64 => SimulateParams::PoolInfo,
-            1u64 => SimulateParams::SwapBaseInInfo,
-            2u64 => SimulateParams::SwapBaseOutInfo,
-            3u64 => SimulateParams::RunCrankInfo,
-            _ => unreachable!(),
+            0u64 => Ok(SimulateParams::PoolInfo),
+            1u64 => Ok(SimulateParams::SwapBaseInInfo),
+            2u64 => Ok(SimulateParams::SwapBaseOutInfo),
+            3u64 => Ok(SimulateParams::RunCrankInfo),
+            _ => Err("Invalid simulate param"),
         }
     }
 
@@ -0,0 +1,371 @@
+use anyhow::{anyhow, Context, Error};
+
+use substreams_solana::pb::sf::solana::r#type::v1::Block;
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+
+use substreams_solana_utils as utils;
+use utils::instruction::{get_structured_instructions, StructuredInstruction, StructuredInstructions};
+use utils::log::Log;
+use utils::transaction::{get_context, TransactionContext};
+
+pub mod raydium_clmm;
+use raydium_clmm::instruction::RaydiumClmmInstruction;
+use raydium_clmm::log::RaydiumClmmLog;
+use raydium_clmm::RAYDIUM_CLMM_PROGRAM_ID;
+
+pub mod pb;
+use pb::raydium_clmm::raydium_clmm_event::Event;
+use pb::raydium_clmm::*;
+
+#[substreams::handlers::map]
+fn raydium_clmm_events(block: Block) -> Result<RaydiumClmmBlockEvents, Error> {
+    let transactions = parse_block(&block);
+    Ok(RaydiumClmmBlockEvents { transactions })
+}
+
+pub fn parse_block(block: &Block) -> Vec<RaydiumClmmTransactionEvents> {
+    let mut block_events: Vec<RaydiumClmmTransactionEvents> = Vec::new();
+    for transaction in block.transactions.iter() {
+        if let Ok(events) = parse_transaction(transaction) {
+            if !events.is_empty() {
+                block_events.push(RaydiumClmmTransactionEvents {
+                    signature: utils::transaction::get_signature(&transaction),
+                    events,
+                });
+            }
+        }
+    }
+    block_events
+}
+
+pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<RaydiumClmmEvent>, Error> {
+    if let Some(_) = transaction.meta.as_ref().unwrap().err {
+        return Ok(Vec::new());
+    }
+
+    let mut events: Vec<RaydiumClmmEvent> = Vec::new();
+
+    let mut context = get_context(transaction)?;
+    let instructions = get_structured_instructions(transaction)?;
+    for instruction in instructions.flattened().iter() {
+        context.update_balance(&instruction.instruction);
+        if instruction.program_id() != RAYDIUM_CLMM_PROGRAM_ID {
+            continue;
+        }
+
+        match parse_instruction(&instruction, &context) {
+            Ok(Some(event)) => events.push(RaydiumClmmEvent { event: Some(event) }),
+            Ok(None) => (),
+            Err(error) => substreams::log::println(format!(
+                "Failed to process instruction of transaction {}: {}",
+                &context.signature, error
+            )),
+        }
+    }
+    Ok(events)
+}
+
+pub fn parse_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<Option<Event>, Error> {
+    if instruction.program_id() != RAYDIUM_CLMM_PROGRAM_ID {
+        return Err(anyhow!("Not a Raydium CLMM instruction."));
+    }
+    let unpacked = RaydiumClmmInstruction::unpack(instruction.data()).map_err(|x| anyhow!(x))?;
+    match unpacked {
+        RaydiumClmmInstruction::CreatePool(create_pool) => Ok(Some(Event::CreatePool(
+            _parse_create_pool_instruction(instruction, context, create_pool)?,
+        ))),
+        RaydiumClmmInstruction::OpenPosition(open_position) | RaydiumClmmInstruction::OpenPositionV2(open_position) => Ok(Some(Event::OpenPosition(
+            _parse_open_position_instruction(instruction, context, open_position, 5)?,
+        ))),
+        RaydiumClmmInstruction::OpenPositionWithToken22Nft(open_position) => Ok(Some(Event::OpenPosition(
+            _parse_open_position_instruction(instruction, context, open_position, 4)?,
+        ))),
+        RaydiumClmmInstruction::ClosePosition => Ok(Some(Event::ClosePosition(
+            _parse_close_position_instruction(instruction, context)?,
+        ))),
+        RaydiumClmmInstruction::IncreaseLiquidity(increase_liquidity) | RaydiumClmmInstruction::IncreaseLiquidityV2(increase_liquidity) => Ok(Some(Event::IncreaseLiquidity(
+            _parse_increase_liquidity_instruction(instruction, context, increase_liquidity)?,
+        ))),
+        RaydiumClmmInstruction::DecreaseLiquidity(decrease_liquidity) | RaydiumClmmInstruction::DecreaseLiquidityV2(decrease_liquidity) => Ok(Some(Event::DecreaseLiquidity(
+            _parse_decrease_liquidity_instruction(instruction, context, decrease_liquidity)?,
+        ))),
+        RaydiumClmmInstruction::Swap(swap) => Ok(Some(Event::Swap(
+            _parse_swap_instruction(instruction, context, swap, false)?,
+        ))),
+        RaydiumClmmInstruction::SwapV2(swap) => Ok(Some(Event::Swap(
+            _parse_swap_instruction(instruction, context, swap, true)?,
+        ))),
+        RaydiumClmmInstruction::CollectProtocolFee(collect_fee) => Ok(Some(Event::CollectFee(
+            _parse_collect_fee_instruction(instruction, context, collect_fee, CollectFeeType::Protocol)?,
+        ))),
+        RaydiumClmmInstruction::CollectFundFee(collect_fee) => Ok(Some(Event::CollectFee(
+            _parse_collect_fee_instruction(instruction, context, collect_fee, CollectFeeType::Fund)?,
+        ))),
+        RaydiumClmmInstruction::Unknown => Ok(None),
+    }
+}
+
+fn _parse_create_pool_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    create_pool: raydium_clmm::instruction::CreatePoolInstruction,
+) -> Result<CreatePoolEvent, Error> {
+    let accounts = instruction.accounts();
+    if accounts.len() < 13 {
+        return Err(anyhow!("Invalid number of create pool accounts."));
+    }
+    let creator = accounts[0].to_string();
+    let amm_config = accounts[1].to_string();
+    let pool = accounts[2].to_string();
+    let token0_mint = accounts[3].to_string();
+    let token1_mint = accounts[4].to_string();
+    let token0_vault = accounts[5].to_string();
+    let token1_vault = accounts[6].to_string();
+
+    let pool_created = parse_raydium_clmm_logs(instruction).unwrap_or_default().into_iter().find_map(|log| match log {
+        RaydiumClmmLog::PoolCreated(pool_created) => Some(pool_created),
+        _ => None,
+    });
+
+    Ok(CreatePoolEvent {
+        pool,
+        creator,
+        amm_config,
+        token0_mint,
+        token1_mint,
+        token0_vault,
+        token1_vault,
+        sqrt_price_x64: create_pool.sqrt_price_x64.to_string(),
+        open_time: create_pool.open_time,
+        tick: pool_created.as_ref().map(|x| x.tick),
+        tick_spacing: pool_created.as_ref().map(|x| x.tick_spacing as u32),
+    })
+}
+
+fn _parse_open_position_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    open_position: raydium_clmm::instruction::OpenPositionInstruction,
+    pool_index: usize,
+) -> Result<OpenPositionEvent, Error> {
+    let accounts = instruction.accounts();
+    if accounts.len() < 19 {
+        return Err(anyhow!("Invalid number of open position accounts."));
+    }
+    let owner = accounts[1].to_string();
+    let position_nft_mint = accounts[2].to_string();
+    let pool = accounts[pool_index].to_string();
+
+    // The program may round the requested liquidity, so prefer what it reports.
+    let position = parse_raydium_clmm_logs(instruction).unwrap_or_default().into_iter().find_map(|log| match log {
+        RaydiumClmmLog::CreatePersonalPosition(position) => Some(position),
+        _ => None,
+    });
+    let liquidity = position.as_ref().map_or(open_position.liquidity, |x| x.liquidity);
+
+    Ok(OpenPositionEvent {
+        pool,
+        owner,
+        position_nft_mint,
+        tick_lower_index: open_position.tick_lower_index,
+        tick_upper_index: open_position.tick_upper_index,
+        liquidity: liquidity.to_string(),
+        amount0: position.as_ref().map(|x| x.deposit_amount_0),
+        amount1: position.as_ref().map(|x| x.deposit_amount_1),
+        amount0_transfer_fee: position.as_ref().map(|x| x.deposit_amount_0_transfer_fee),
+        amount1_transfer_fee: position.as_ref().map(|x| x.deposit_amount_1_transfer_fee),
+    })
+}
+
+fn _parse_close_position_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<ClosePositionEvent, Error> {
+    let accounts = instruction.accounts();
+    if accounts.len() < 6 {
+        return Err(anyhow!("Invalid number of close position accounts."));
+    }
+    let owner = accounts[0].to_string();
+    let position_nft_mint = accounts[1].to_string();
+    let personal_position = accounts[3].to_string();
+
+    Ok(ClosePositionEvent {
+        owner,
+        position_nft_mint,
+        personal_position,
+    })
+}
+
+fn _parse_increase_liquidity_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    increase_liquidity: raydium_clmm::instruction::IncreaseLiquidityInstruction,
+) -> Result<IncreaseLiquidityEvent, Error> {
+    let accounts = instruction.accounts();
+    if accounts.len() < 12 {
+        return Err(anyhow!("Invalid number of increase liquidity accounts."));
+    }
+    let owner = accounts[0].to_string();
+    let pool = accounts[2].to_string();
+
+    let increase = parse_raydium_clmm_logs(instruction).unwrap_or_default().into_iter().find_map(|log| match log {
+        RaydiumClmmLog::IncreaseLiquidity(increase) => Some(increase),
+        _ => None,
+    });
+    let liquidity = increase.as_ref().map_or(increase_liquidity.liquidity, |x| x.liquidity);
+
+    Ok(IncreaseLiquidityEvent {
+        pool,
+        owner,
+        position_nft_mint: increase.as_ref().map(|x| x.position_nft_mint.to_string()),
+        liquidity: liquidity.to_string(),
+        amount0: increase.as_ref().map(|x| x.amount_0),
+        amount1: increase.as_ref().map(|x| x.amount_1),
+        amount0_transfer_fee: increase.as_ref().map(|x| x.amount_0_transfer_fee),
+        amount1_transfer_fee: increase.as_ref().map(|x| x.amount_1_transfer_fee),
+    })
+}
+
+fn _parse_decrease_liquidity_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    decrease_liquidity: raydium_clmm::instruction::DecreaseLiquidityInstruction,
+) -> Result<DecreaseLiquidityEvent, Error> {
+    let accounts = instruction.accounts();
+    if accounts.len() < 12 {
+        return Err(anyhow!("Invalid number of decrease liquidity accounts."));
+    }
+    let owner = accounts[0].to_string();
+    let pool = accounts[3].to_string();
+
+    let decrease = parse_raydium_clmm_logs(instruction).unwrap_or_default().into_iter().find_map(|log| match log {
+        RaydiumClmmLog::DecreaseLiquidity(decrease) => Some(decrease),
+        _ => None,
+    });
+
+    Ok(DecreaseLiquidityEvent {
+        pool,
+        owner,
+        position_nft_mint: decrease.as_ref().map(|x| x.position_nft_mint.to_string()),
+        liquidity: decrease_liquidity.liquidity.to_string(),
+        amount0: decrease.as_ref().map(|x| x.decrease_amount_0),
+        amount1: decrease.as_ref().map(|x| x.decrease_amount_1),
+        fee_amount0: decrease.as_ref().map(|x| x.fee_amount_0),
+        fee_amount1: decrease.as_ref().map(|x| x.fee_amount_1),
+        amount0_transfer_fee: decrease.as_ref().map(|x| x.transfer_fee_0),
+        amount1_transfer_fee: decrease.as_ref().map(|x| x.transfer_fee_1),
+    })
+}
+
+fn _parse_swap_instruction(
+    instruction: &StructuredInstruction,
+    context: &TransactionContext,
+    swap: raydium_clmm::instruction::SwapInstruction,
+    v2: bool,
+) -> Result<SwapEvent, Error> {
+    let accounts = instruction.accounts();
+    if accounts.len() < if v2 { 13 } else { 10 } {
+        return Err(anyhow!("Invalid number of swap accounts."));
+    }
+    let amm_config = accounts[1].to_string();
+    let pool = accounts[2].to_string();
+    let user = match context.get_token_account(&accounts[3]) {
+        Some(token_account) => token_account.owner.to_string(),
+        None => accounts[0].to_string(),
+    };
+    // Only swap_v2 passes the vault mints; for swap they are resolved from the vault token accounts.
+    let (input_mint, output_mint) = if v2 {
+        (accounts[11].to_string(), accounts[12].to_string())
+    } else {
+        let input_vault = context.get_token_account(&accounts[5]).ok_or(anyhow!("Failed to find the input vault."))?;
+        let output_vault = context.get_token_account(&accounts[6]).ok_or(anyhow!("Failed to find the output vault."))?;
+        (input_vault.mint.to_string(), output_vault.mint.to_string())
+    };
+
+    let swap_log = parse_raydium_clmm_logs(instruction)?
+        .into_iter()
+        .find_map(|log| match log {
+            RaydiumClmmLog::Swap(swap_log) => Some(swap_log),
+            _ => None,
+        })
+        .ok_or(anyhow!("Couldn't find SwapEvent log."))?;
+
+    let (amount_in, amount_out, input_transfer_fee, output_transfer_fee) = if swap_log.zero_for_one {
+        (swap_log.amount_0, swap_log.amount_1, swap_log.transfer_fee_0, swap_log.transfer_fee_1)
+    } else {
+        (swap_log.amount_1, swap_log.amount_0, swap_log.transfer_fee_1, swap_log.transfer_fee_0)
+    };
+
+    Ok(SwapEvent {
+        pool,
+        user,
+        amm_config,
+        input_mint,
+        output_mint,
+        amount_in,
+        amount_out,
+        base_input: swap.is_base_input,
+        zero_for_one: swap_log.zero_for_one,
+        input_transfer_fee: Some(input_transfer_fee),
+        output_transfer_fee: Some(output_transfer_fee),
+        sqrt_price_x64: swap_log.sqrt_price_x64.to_string(),
+        liquidity: swap_log.liquidity.to_string(),
+        tick: swap_log.tick,
+    })
+}
+
+fn _parse_collect_fee_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    collect_fee: raydium_clmm::instruction::CollectFeeInstruction,
+    fee_type: CollectFeeType,
+) -> Result<CollectFeeEvent, Error> {
+    let accounts = instruction.accounts();
+    if accounts.len() < 11 {
+        return Err(anyhow!("Invalid number of collect fee accounts."));
+    }
+    let owner = accounts[0].to_string();
+    let pool = accounts[1].to_string();
+    let token0_mint = accounts[5].to_string();
+    let token1_mint = accounts[6].to_string();
+    let recipient_token0_account = accounts[7].to_string();
+    let recipient_token1_account = accounts[8].to_string();
+
+    let collected = parse_raydium_clmm_logs(instruction).unwrap_or_default().into_iter().find_map(|log| match log {
+        RaydiumClmmLog::CollectProtocolFee(collected) => Some(collected),
+        _ => None,
+    });
+
+    Ok(CollectFeeEvent {
+        pool,
+        owner,
+        fee_type: fee_type.into(),
+        token0_mint,
+        token1_mint,
+        amount0_requested: collect_fee.amount_0_requested,
+        amount1_requested: collect_fee.amount_1_requested,
+        recipient_token0_account,
+        recipient_token1_account,
+        amount0: collected.as_ref().map(|x| x.amount_0),
+        amount1: collected.as_ref().map(|x| x.amount_1),
+    })
+}
+
+/// Decodes every Raydium CLMM event emitted by the instruction, skipping the ones we don't track.
+fn parse_raydium_clmm_logs(instruction: &StructuredInstruction) -> Result<Vec<RaydiumClmmLog>, Error> {
+    let logs = instruction
+        .logs()
+        .as_ref()
+        .context("Failed to parse logs due to truncation")?
+        .iter()
+        .filter_map(|log| match log {
+            Log::Data(data_log) => data_log.data().ok(),
+            _ => None,
+        })
+        .filter_map(|data| RaydiumClmmLog::unpack(data.as_slice()).ok())
+        .collect();
+    Ok(logs)
+}
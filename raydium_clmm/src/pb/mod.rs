@@ -0,0 +1,6 @@
+// @generated
+// @@protoc_insertion_point(attribute:raydium_clmm)
+pub mod raydium_clmm {
+    include!("raydium_clmm.rs");
+    // @@protoc_insertion_point(raydium_clmm)
+}
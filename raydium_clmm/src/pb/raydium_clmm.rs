@@ -0,0 +1,231 @@
+// @generated
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RaydiumClmmBlockEvents {
+    #[prost(message, repeated, tag="1")]
+    pub transactions: ::prost::alloc::vec::Vec<RaydiumClmmTransactionEvents>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RaydiumClmmTransactionEvents {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="2")]
+    pub events: ::prost::alloc::vec::Vec<RaydiumClmmEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RaydiumClmmEvent {
+    #[prost(oneof="raydium_clmm_event::Event", tags="1, 2, 3, 4, 5, 6, 7")]
+    pub event: ::core::option::Option<raydium_clmm_event::Event>,
+}
+/// Nested message and enum types in `RaydiumClmmEvent`.
+pub mod raydium_clmm_event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag="1")]
+        CreatePool(super::CreatePoolEvent),
+        #[prost(message, tag="2")]
+        OpenPosition(super::OpenPositionEvent),
+        #[prost(message, tag="3")]
+        ClosePosition(super::ClosePositionEvent),
+        #[prost(message, tag="4")]
+        IncreaseLiquidity(super::IncreaseLiquidityEvent),
+        #[prost(message, tag="5")]
+        DecreaseLiquidity(super::DecreaseLiquidityEvent),
+        #[prost(message, tag="6")]
+        Swap(super::SwapEvent),
+        #[prost(message, tag="7")]
+        CollectFee(super::CollectFeeEvent),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreatePoolEvent {
+    #[prost(string, tag="1")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub creator: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub amm_config: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub token0_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub token1_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub token0_vault: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub token1_vault: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub sqrt_price_x64: ::prost::alloc::string::String,
+    #[prost(uint64, tag="9")]
+    pub open_time: u64,
+    #[prost(int32, optional, tag="10")]
+    pub tick: ::core::option::Option<i32>,
+    #[prost(uint32, optional, tag="11")]
+    pub tick_spacing: ::core::option::Option<u32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OpenPositionEvent {
+    #[prost(string, tag="1")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub position_nft_mint: ::prost::alloc::string::String,
+    #[prost(int32, tag="4")]
+    pub tick_lower_index: i32,
+    #[prost(int32, tag="5")]
+    pub tick_upper_index: i32,
+    #[prost(string, tag="6")]
+    pub liquidity: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag="7")]
+    pub amount0: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="8")]
+    pub amount1: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="9")]
+    pub amount0_transfer_fee: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="10")]
+    pub amount1_transfer_fee: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClosePositionEvent {
+    #[prost(string, tag="1")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub position_nft_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub personal_position: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IncreaseLiquidityEvent {
+    #[prost(string, tag="1")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub position_nft_mint: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="4")]
+    pub liquidity: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag="5")]
+    pub amount0: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="6")]
+    pub amount1: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="7")]
+    pub amount0_transfer_fee: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="8")]
+    pub amount1_transfer_fee: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DecreaseLiquidityEvent {
+    #[prost(string, tag="1")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub position_nft_mint: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="4")]
+    pub liquidity: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag="5")]
+    pub amount0: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="6")]
+    pub amount1: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="7")]
+    pub fee_amount0: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="8")]
+    pub fee_amount1: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="9")]
+    pub amount0_transfer_fee: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="10")]
+    pub amount1_transfer_fee: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapEvent {
+    #[prost(string, tag="1")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub user: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub amm_config: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub input_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub output_mint: ::prost::alloc::string::String,
+    #[prost(uint64, tag="6")]
+    pub amount_in: u64,
+    #[prost(uint64, tag="7")]
+    pub amount_out: u64,
+    #[prost(bool, tag="8")]
+    pub base_input: bool,
+    #[prost(bool, tag="9")]
+    pub zero_for_one: bool,
+    #[prost(uint64, optional, tag="10")]
+    pub input_transfer_fee: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="11")]
+    pub output_transfer_fee: ::core::option::Option<u64>,
+    #[prost(string, tag="12")]
+    pub sqrt_price_x64: ::prost::alloc::string::String,
+    #[prost(string, tag="13")]
+    pub liquidity: ::prost::alloc::string::String,
+    #[prost(int32, tag="14")]
+    pub tick: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollectFeeEvent {
+    #[prost(string, tag="1")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(enumeration="CollectFeeType", tag="3")]
+    pub fee_type: i32,
+    #[prost(string, tag="4")]
+    pub token0_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub token1_mint: ::prost::alloc::string::String,
+    #[prost(uint64, tag="6")]
+    pub amount0_requested: u64,
+    #[prost(uint64, tag="7")]
+    pub amount1_requested: u64,
+    #[prost(string, tag="8")]
+    pub recipient_token0_account: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub recipient_token1_account: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag="10")]
+    pub amount0: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="11")]
+    pub amount1: ::core::option::Option<u64>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CollectFeeType {
+    Protocol = 0,
+    Fund = 1,
+}
+impl CollectFeeType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CollectFeeType::Protocol => "Protocol",
+            CollectFeeType::Fund => "Fund",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Protocol" => Some(Self::Protocol),
+            "Fund" => Some(Self::Fund),
+            _ => None,
+        }
+    }
+}
+// @@protoc_insertion_point(module)
@@ -0,0 +1,270 @@
+use borsh::BorshDeserialize;
+
+#[derive(Debug, BorshDeserialize)]
+pub enum RaydiumClmmInstruction {
+    CreatePool(CreatePoolInstruction),
+    OpenPosition(OpenPositionInstruction),
+    OpenPositionV2(OpenPositionInstruction),
+    OpenPositionWithToken22Nft(OpenPositionInstruction),
+    ClosePosition,
+    IncreaseLiquidity(IncreaseLiquidityInstruction),
+    IncreaseLiquidityV2(IncreaseLiquidityInstruction),
+    DecreaseLiquidity(DecreaseLiquidityInstruction),
+    DecreaseLiquidityV2(DecreaseLiquidityInstruction),
+    Swap(SwapInstruction),
+    SwapV2(SwapInstruction),
+    CollectProtocolFee(CollectFeeInstruction),
+    CollectFundFee(CollectFeeInstruction),
+    Unknown,
+}
+
+impl RaydiumClmmInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        if data.len() < 8 {
+            return Err("Instruction data is too short.");
+        }
+        let (tag, data) = data.split_at(8);
+        match tag {
+            [233, 146, 209, 142, 207, 104, 64, 188] => Ok(Self::CreatePool(CreatePoolInstruction::unpack(data)?)),
+            [135, 128, 47, 77, 15, 152, 240, 49] => Ok(Self::OpenPosition(OpenPositionInstruction::unpack(data)?)),
+            [77, 184, 74, 214, 112, 86, 241, 199] => Ok(Self::OpenPositionV2(OpenPositionInstruction::unpack(data)?)),
+            [77, 255, 174, 82, 125, 29, 201, 46] => Ok(Self::OpenPositionWithToken22Nft(OpenPositionInstruction::unpack(data)?)),
+            [123, 134, 81, 0, 49, 68, 98, 98] => Ok(Self::ClosePosition),
+            [46, 156, 243, 118, 13, 205, 251, 178] => Ok(Self::IncreaseLiquidity(IncreaseLiquidityInstruction::unpack(data)?)),
+            [133, 29, 89, 223, 69, 238, 176, 10] => Ok(Self::IncreaseLiquidityV2(IncreaseLiquidityInstruction::unpack(data)?)),
+            [160, 38, 208, 111, 104, 91, 44, 1] => Ok(Self::DecreaseLiquidity(DecreaseLiquidityInstruction::unpack(data)?)),
+            [58, 127, 188, 62, 79, 82, 196, 96] => Ok(Self::DecreaseLiquidityV2(DecreaseLiquidityInstruction::unpack(data)?)),
+            [248, 198, 158, 145, 225, 117, 135, 200] => Ok(Self::Swap(SwapInstruction::unpack(data)?)),
+            [43, 4, 237, 11, 26, 201, 30, 98] => Ok(Self::SwapV2(SwapInstruction::unpack(data)?)),
+            [136, 136, 252, 221, 194, 66, 126, 89] => Ok(Self::CollectProtocolFee(CollectFeeInstruction::unpack(data)?)),
+            [167, 138, 78, 149, 223, 194, 6, 126] => Ok(Self::CollectFundFee(CollectFeeInstruction::unpack(data)?)),
+            _ => Ok(Self::Unknown),
+        }
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct CreatePoolInstruction {
+    pub sqrt_price_x64: u128,
+    pub open_time: u64,
+}
+
+impl CreatePoolInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize CreatePoolInstruction.")
+    }
+}
+
+/// Shared by `open_position`, `open_position_v2` and `open_position_with_token22_nft`; the trailing
+/// v2 arguments (`with_metadata`, `base_flag`) are not decoded.
+#[derive(Debug, BorshDeserialize)]
+pub struct OpenPositionInstruction {
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub tick_array_lower_start_index: i32,
+    pub tick_array_upper_start_index: i32,
+    pub liquidity: u128,
+    pub amount_0_max: u64,
+    pub amount_1_max: u64,
+}
+
+impl OpenPositionInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize OpenPositionInstruction.")
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct IncreaseLiquidityInstruction {
+    pub liquidity: u128,
+    pub amount_0_max: u64,
+    pub amount_1_max: u64,
+}
+
+impl IncreaseLiquidityInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize IncreaseLiquidityInstruction.")
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct DecreaseLiquidityInstruction {
+    pub liquidity: u128,
+    pub amount_0_min: u64,
+    pub amount_1_min: u64,
+}
+
+impl DecreaseLiquidityInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize DecreaseLiquidityInstruction.")
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct SwapInstruction {
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub sqrt_price_limit_x64: u128,
+    pub is_base_input: bool,
+}
+
+impl SwapInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize SwapInstruction.")
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct CollectFeeInstruction {
+    pub amount_0_requested: u64,
+    pub amount_1_requested: u64,
+}
+
+impl CollectFeeInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize CollectFeeInstruction.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unpack_create_pool() {
+        let sqrt_price_x64: u128 = 1 << 64;
+        let data = [&[233, 146, 209, 142, 207, 104, 64, 188][..], &sqrt_price_x64.to_le_bytes(), &1_720_000_000u64.to_le_bytes()].concat();
+        match RaydiumClmmInstruction::unpack(&data).unwrap() {
+            RaydiumClmmInstruction::CreatePool(create_pool) => {
+                assert_eq!(create_pool.sqrt_price_x64, sqrt_price_x64);
+                assert_eq!(create_pool.open_time, 1_720_000_000);
+            },
+            instruction => panic!("Unexpected instruction {:?}", instruction),
+        }
+    }
+
+    #[test]
+    fn test_unpack_open_positions() {
+        let args = [
+            &(-120i32).to_le_bytes()[..],
+            &120i32.to_le_bytes(),
+            &(-3600i32).to_le_bytes(),
+            &0i32.to_le_bytes(),
+            &5_000_000u128.to_le_bytes(),
+            &1_000u64.to_le_bytes(),
+            &2_000u64.to_le_bytes(),
+        ]
+        .concat();
+        // The v2 variants carry `with_metadata` and `base_flag` after the shared arguments.
+        let v2_args = [&args[..], &[1], &[0]].concat();
+        for (discriminator, args) in [
+            ([135, 128, 47, 77, 15, 152, 240, 49], &args),
+            ([77, 184, 74, 214, 112, 86, 241, 199], &v2_args),
+            ([77, 255, 174, 82, 125, 29, 201, 46], &v2_args),
+        ] {
+            let data = [&discriminator[..], args].concat();
+            let open_position = match RaydiumClmmInstruction::unpack(&data).unwrap() {
+                RaydiumClmmInstruction::OpenPosition(open_position)
+                | RaydiumClmmInstruction::OpenPositionV2(open_position)
+                | RaydiumClmmInstruction::OpenPositionWithToken22Nft(open_position) => open_position,
+                instruction => panic!("Unexpected instruction {:?}", instruction),
+            };
+            assert_eq!(open_position.tick_lower_index, -120);
+            assert_eq!(open_position.tick_upper_index, 120);
+            assert_eq!(open_position.tick_array_lower_start_index, -3600);
+            assert_eq!(open_position.tick_array_upper_start_index, 0);
+            assert_eq!(open_position.liquidity, 5_000_000);
+            assert_eq!(open_position.amount_0_max, 1_000);
+            assert_eq!(open_position.amount_1_max, 2_000);
+        }
+        assert!(matches!(
+            RaydiumClmmInstruction::unpack(&[77, 255, 174, 82, 125, 29, 201, 46]).unwrap_err(),
+            "Failed to deserialize OpenPositionInstruction."
+        ));
+    }
+
+    #[test]
+    fn test_unpack_close_position() {
+        assert!(matches!(
+            RaydiumClmmInstruction::unpack(&[123, 134, 81, 0, 49, 68, 98, 98]).unwrap(),
+            RaydiumClmmInstruction::ClosePosition
+        ));
+    }
+
+    #[test]
+    fn test_unpack_liquidity_changes() {
+        let args = [&7_500u128.to_le_bytes()[..], &10u64.to_le_bytes(), &20u64.to_le_bytes()].concat();
+
+        let data = [&[46, 156, 243, 118, 13, 205, 251, 178][..], &args].concat();
+        match RaydiumClmmInstruction::unpack(&data).unwrap() {
+            RaydiumClmmInstruction::IncreaseLiquidity(increase) => {
+                assert_eq!(increase.liquidity, 7_500);
+                assert_eq!(increase.amount_0_max, 10);
+                assert_eq!(increase.amount_1_max, 20);
+            },
+            instruction => panic!("Unexpected instruction {:?}", instruction),
+        }
+        // increase_liquidity_v2 also passes an optional `base_flag`.
+        let data = [&[133, 29, 89, 223, 69, 238, 176, 10][..], &args, &[0]].concat();
+        assert!(matches!(RaydiumClmmInstruction::unpack(&data).unwrap(), RaydiumClmmInstruction::IncreaseLiquidityV2(_)));
+
+        let data = [&[160, 38, 208, 111, 104, 91, 44, 1][..], &args].concat();
+        match RaydiumClmmInstruction::unpack(&data).unwrap() {
+            RaydiumClmmInstruction::DecreaseLiquidity(decrease) => {
+                assert_eq!(decrease.liquidity, 7_500);
+                assert_eq!(decrease.amount_0_min, 10);
+                assert_eq!(decrease.amount_1_min, 20);
+            },
+            instruction => panic!("Unexpected instruction {:?}", instruction),
+        }
+        let data = [&[58, 127, 188, 62, 79, 82, 196, 96][..], &args].concat();
+        assert!(matches!(RaydiumClmmInstruction::unpack(&data).unwrap(), RaydiumClmmInstruction::DecreaseLiquidityV2(_)));
+    }
+
+    #[test]
+    fn test_unpack_swaps() {
+        let args = [&1_000_000u64.to_le_bytes()[..], &990_000u64.to_le_bytes(), &0u128.to_le_bytes(), &[1]].concat();
+
+        let data = [&[248, 198, 158, 145, 225, 117, 135, 200][..], &args].concat();
+        match RaydiumClmmInstruction::unpack(&data).unwrap() {
+            RaydiumClmmInstruction::Swap(swap) => {
+                assert_eq!(swap.amount, 1_000_000);
+                assert_eq!(swap.other_amount_threshold, 990_000);
+                assert_eq!(swap.sqrt_price_limit_x64, 0);
+                assert!(swap.is_base_input);
+            },
+            instruction => panic!("Unexpected instruction {:?}", instruction),
+        }
+
+        let data = [&[43, 4, 237, 11, 26, 201, 30, 98][..], &args].concat();
+        match RaydiumClmmInstruction::unpack(&data).unwrap() {
+            RaydiumClmmInstruction::SwapV2(swap) => {
+                assert_eq!(swap.amount, 1_000_000);
+                assert!(swap.is_base_input);
+            },
+            instruction => panic!("Unexpected instruction {:?}", instruction),
+        }
+    }
+
+    #[test]
+    fn test_unpack_collect_fees() {
+        let args = [&u64::MAX.to_le_bytes()[..], &5u64.to_le_bytes()].concat();
+        let data = [&[136, 136, 252, 221, 194, 66, 126, 89][..], &args].concat();
+        match RaydiumClmmInstruction::unpack(&data).unwrap() {
+            RaydiumClmmInstruction::CollectProtocolFee(collect_fee) => {
+                assert_eq!(collect_fee.amount_0_requested, u64::MAX);
+                assert_eq!(collect_fee.amount_1_requested, 5);
+            },
+            instruction => panic!("Unexpected instruction {:?}", instruction),
+        }
+        let data = [&[167, 138, 78, 149, 223, 194, 6, 126][..], &args].concat();
+        assert!(matches!(RaydiumClmmInstruction::unpack(&data).unwrap(), RaydiumClmmInstruction::CollectFundFee(_)));
+    }
+
+    #[test]
+    fn test_unpack_invalid_data() {
+        assert!(RaydiumClmmInstruction::unpack(&[248, 198, 158]).is_err());
+        assert!(RaydiumClmmInstruction::unpack(&[248, 198, 158, 145, 225, 117, 135, 200, 1]).is_err());
+        assert!(matches!(RaydiumClmmInstruction::unpack(&[0; 8]).unwrap(), RaydiumClmmInstruction::Unknown));
+    }
+}
@@ -0,0 +1,310 @@
+use borsh::BorshDeserialize;
+use substreams_solana_utils::pubkey::Pubkey;
+
+#[derive(Debug)]
+pub enum RaydiumClmmLog {
+    PoolCreated(PoolCreatedLog),
+    CreatePersonalPosition(CreatePersonalPositionLog),
+    IncreaseLiquidity(IncreaseLiquidityLog),
+    DecreaseLiquidity(DecreaseLiquidityLog),
+    CollectProtocolFee(CollectProtocolFeeLog),
+    Swap(SwapLog),
+}
+
+impl RaydiumClmmLog {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        if data.len() < 8 {
+            return Err("Event data is too short.");
+        }
+        let (discriminator, data) = data.split_at(8);
+        match discriminator {
+            [25, 94, 75, 47, 112, 99, 53, 63] => PoolCreatedLog::deserialize(&mut &data[..])
+                .map(Self::PoolCreated)
+                .map_err(|_| "Failed to unpack PoolCreatedEvent."),
+            [100, 30, 87, 249, 196, 223, 154, 206] => CreatePersonalPositionLog::deserialize(&mut &data[..])
+                .map(Self::CreatePersonalPosition)
+                .map_err(|_| "Failed to unpack CreatePersonalPositionEvent."),
+            [49, 79, 105, 212, 32, 34, 30, 84] => IncreaseLiquidityLog::deserialize(&mut &data[..])
+                .map(Self::IncreaseLiquidity)
+                .map_err(|_| "Failed to unpack IncreaseLiquidityEvent."),
+            [58, 222, 86, 58, 68, 50, 85, 56] => DecreaseLiquidityLog::deserialize(&mut &data[..])
+                .map(Self::DecreaseLiquidity)
+                .map_err(|_| "Failed to unpack DecreaseLiquidityEvent."),
+            [206, 87, 17, 79, 45, 41, 213, 61] => CollectProtocolFeeLog::deserialize(&mut &data[..])
+                .map(Self::CollectProtocolFee)
+                .map_err(|_| "Failed to unpack CollectProtocolFeeEvent."),
+            [64, 198, 205, 232, 38, 8, 113, 226] => SwapLog::deserialize(&mut &data[..])
+                .map(Self::Swap)
+                .map_err(|_| "Failed to unpack SwapEvent."),
+            _ => Err("Unknown Raydium CLMM event."),
+        }
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct PoolCreatedLog {
+    pub token_mint_0: Pubkey,
+    pub token_mint_1: Pubkey,
+    pub tick_spacing: u16,
+    pub pool_state: Pubkey,
+    pub sqrt_price_x64: u128,
+    pub tick: i32,
+    pub token_vault_0: Pubkey,
+    pub token_vault_1: Pubkey,
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct CreatePersonalPositionLog {
+    pub pool_state: Pubkey,
+    pub minter: Pubkey,
+    pub nft_owner: Pubkey,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    pub deposit_amount_0: u64,
+    pub deposit_amount_1: u64,
+    pub deposit_amount_0_transfer_fee: u64,
+    pub deposit_amount_1_transfer_fee: u64,
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct IncreaseLiquidityLog {
+    pub position_nft_mint: Pubkey,
+    pub liquidity: u128,
+    pub amount_0: u64,
+    pub amount_1: u64,
+    pub amount_0_transfer_fee: u64,
+    pub amount_1_transfer_fee: u64,
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct DecreaseLiquidityLog {
+    pub position_nft_mint: Pubkey,
+    pub liquidity: u128,
+    pub decrease_amount_0: u64,
+    pub decrease_amount_1: u64,
+    pub fee_amount_0: u64,
+    pub fee_amount_1: u64,
+    pub reward_amounts: [u64; 3],
+    pub transfer_fee_0: u64,
+    pub transfer_fee_1: u64,
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct CollectProtocolFeeLog {
+    pub pool_state: Pubkey,
+    pub recipient_token_account_0: Pubkey,
+    pub recipient_token_account_1: Pubkey,
+    pub amount_0: u64,
+    pub amount_1: u64,
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct SwapLog {
+    pub pool_state: Pubkey,
+    pub sender: Pubkey,
+    pub token_account_0: Pubkey,
+    pub token_account_1: Pubkey,
+    pub amount_0: u64,
+    pub transfer_fee_0: u64,
+    pub amount_1: u64,
+    pub transfer_fee_1: u64,
+    pub zero_for_one: bool,
+    pub sqrt_price_x64: u128,
+    pub liquidity: u128,
+    pub tick: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pubkey(byte: u8) -> [u8; 32] {
+        [byte; 32]
+    }
+
+    #[test]
+    fn test_unpack_pool_created_event() {
+        let sqrt_price_x64: u128 = 18_446_744_073_709_551_616;
+        let data = [
+            &[25, 94, 75, 47, 112, 99, 53, 63][..],
+            &pubkey(1),
+            &pubkey(2),
+            &60u16.to_le_bytes(),
+            &pubkey(3),
+            &sqrt_price_x64.to_le_bytes(),
+            &(-7i32).to_le_bytes(),
+            &pubkey(4),
+            &pubkey(5),
+        ]
+        .concat();
+        match RaydiumClmmLog::unpack(&data).unwrap() {
+            RaydiumClmmLog::PoolCreated(pool_created) => {
+                assert_eq!(pool_created.token_mint_0, Pubkey(pubkey(1)));
+                assert_eq!(pool_created.token_mint_1, Pubkey(pubkey(2)));
+                assert_eq!(pool_created.tick_spacing, 60);
+                assert_eq!(pool_created.pool_state, Pubkey(pubkey(3)));
+                assert_eq!(pool_created.sqrt_price_x64, sqrt_price_x64);
+                assert_eq!(pool_created.tick, -7);
+                assert_eq!(pool_created.token_vault_0, Pubkey(pubkey(4)));
+                assert_eq!(pool_created.token_vault_1, Pubkey(pubkey(5)));
+            },
+            log => panic!("Unexpected log {:?}", log),
+        }
+    }
+
+    #[test]
+    fn test_unpack_create_personal_position_event() {
+        let data = [
+            &[100, 30, 87, 249, 196, 223, 154, 206][..],
+            &pubkey(1),
+            &pubkey(2),
+            &pubkey(3),
+            &(-60i32).to_le_bytes(),
+            &60i32.to_le_bytes(),
+            &1_000u128.to_le_bytes(),
+            &10u64.to_le_bytes(),
+            &20u64.to_le_bytes(),
+            &1u64.to_le_bytes(),
+            &0u64.to_le_bytes(),
+        ]
+        .concat();
+        match RaydiumClmmLog::unpack(&data).unwrap() {
+            RaydiumClmmLog::CreatePersonalPosition(position) => {
+                assert_eq!(position.pool_state, Pubkey(pubkey(1)));
+                assert_eq!(position.minter, Pubkey(pubkey(2)));
+                assert_eq!(position.nft_owner, Pubkey(pubkey(3)));
+                assert_eq!(position.tick_lower_index, -60);
+                assert_eq!(position.tick_upper_index, 60);
+                assert_eq!(position.liquidity, 1_000);
+                assert_eq!(position.deposit_amount_0, 10);
+                assert_eq!(position.deposit_amount_1, 20);
+                assert_eq!(position.deposit_amount_0_transfer_fee, 1);
+                assert_eq!(position.deposit_amount_1_transfer_fee, 0);
+            },
+            log => panic!("Unexpected log {:?}", log),
+        }
+    }
+
+    #[test]
+    fn test_unpack_liquidity_events() {
+        let data = [
+            &[49, 79, 105, 212, 32, 34, 30, 84][..],
+            &pubkey(9),
+            &500u128.to_le_bytes(),
+            &30u64.to_le_bytes(),
+            &40u64.to_le_bytes(),
+            &3u64.to_le_bytes(),
+            &4u64.to_le_bytes(),
+        ]
+        .concat();
+        match RaydiumClmmLog::unpack(&data).unwrap() {
+            RaydiumClmmLog::IncreaseLiquidity(increase) => {
+                assert_eq!(increase.position_nft_mint, Pubkey(pubkey(9)));
+                assert_eq!(increase.liquidity, 500);
+                assert_eq!(increase.amount_0, 30);
+                assert_eq!(increase.amount_1, 40);
+                assert_eq!(increase.amount_0_transfer_fee, 3);
+                assert_eq!(increase.amount_1_transfer_fee, 4);
+            },
+            log => panic!("Unexpected log {:?}", log),
+        }
+
+        let data = [
+            &[58, 222, 86, 58, 68, 50, 85, 56][..],
+            &pubkey(9),
+            &500u128.to_le_bytes(),
+            &30u64.to_le_bytes(),
+            &40u64.to_le_bytes(),
+            &5u64.to_le_bytes(),
+            &6u64.to_le_bytes(),
+            &7u64.to_le_bytes(),
+            &8u64.to_le_bytes(),
+            &9u64.to_le_bytes(),
+            &1u64.to_le_bytes(),
+            &2u64.to_le_bytes(),
+        ]
+        .concat();
+        match RaydiumClmmLog::unpack(&data).unwrap() {
+            RaydiumClmmLog::DecreaseLiquidity(decrease) => {
+                assert_eq!(decrease.position_nft_mint, Pubkey(pubkey(9)));
+                assert_eq!(decrease.liquidity, 500);
+                assert_eq!(decrease.decrease_amount_0, 30);
+                assert_eq!(decrease.decrease_amount_1, 40);
+                assert_eq!(decrease.fee_amount_0, 5);
+                assert_eq!(decrease.fee_amount_1, 6);
+                assert_eq!(decrease.reward_amounts, [7, 8, 9]);
+                assert_eq!(decrease.transfer_fee_0, 1);
+                assert_eq!(decrease.transfer_fee_1, 2);
+            },
+            log => panic!("Unexpected log {:?}", log),
+        }
+    }
+
+    #[test]
+    fn test_unpack_collect_protocol_fee_event() {
+        let data = [
+            &[206, 87, 17, 79, 45, 41, 213, 61][..],
+            &pubkey(1),
+            &pubkey(2),
+            &pubkey(3),
+            &100u64.to_le_bytes(),
+            &200u64.to_le_bytes(),
+        ]
+        .concat();
+        match RaydiumClmmLog::unpack(&data).unwrap() {
+            RaydiumClmmLog::CollectProtocolFee(collected) => {
+                assert_eq!(collected.pool_state, Pubkey(pubkey(1)));
+                assert_eq!(collected.recipient_token_account_0, Pubkey(pubkey(2)));
+                assert_eq!(collected.recipient_token_account_1, Pubkey(pubkey(3)));
+                assert_eq!(collected.amount_0, 100);
+                assert_eq!(collected.amount_1, 200);
+            },
+            log => panic!("Unexpected log {:?}", log),
+        }
+    }
+
+    #[test]
+    fn test_unpack_swap_event() {
+        let data = [
+            &[64, 198, 205, 232, 38, 8, 113, 226][..],
+            &pubkey(1),
+            &pubkey(2),
+            &pubkey(3),
+            &pubkey(4),
+            &1_000u64.to_le_bytes(),
+            &0u64.to_le_bytes(),
+            &1_995u64.to_le_bytes(),
+            &5u64.to_le_bytes(),
+            &[0],
+            &(3u128 << 64).to_le_bytes(),
+            &9_000_000u128.to_le_bytes(),
+            &21_972i32.to_le_bytes(),
+        ]
+        .concat();
+        match RaydiumClmmLog::unpack(&data).unwrap() {
+            RaydiumClmmLog::Swap(swap) => {
+                assert_eq!(swap.pool_state, Pubkey(pubkey(1)));
+                assert_eq!(swap.sender, Pubkey(pubkey(2)));
+                assert_eq!(swap.token_account_0, Pubkey(pubkey(3)));
+                assert_eq!(swap.token_account_1, Pubkey(pubkey(4)));
+                assert_eq!(swap.amount_0, 1_000);
+                assert_eq!(swap.transfer_fee_0, 0);
+                assert_eq!(swap.amount_1, 1_995);
+                assert_eq!(swap.transfer_fee_1, 5);
+                assert!(!swap.zero_for_one);
+                assert_eq!(swap.sqrt_price_x64, 3u128 << 64);
+                assert_eq!(swap.liquidity, 9_000_000);
+                assert_eq!(swap.tick, 21_972);
+            },
+            log => panic!("Unexpected log {:?}", log),
+        }
+    }
+
+    #[test]
+    fn test_unpack_invalid_event() {
+        assert!(RaydiumClmmLog::unpack(&[64, 198, 205]).is_err());
+        assert!(RaydiumClmmLog::unpack(&[0; 40]).is_err());
+        assert!(RaydiumClmmLog::unpack(&[&[64, 198, 205, 232, 38, 8, 113, 226][..], &pubkey(1)].concat()).is_err());
+    }
+}
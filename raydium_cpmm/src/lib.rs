@@ -0,0 +1,303 @@
+use anyhow::{anyhow, Context, Error};
+
+use substreams_solana::pb::sf::solana::r#type::v1::Block;
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+
+use substreams_solana_utils as utils;
+use utils::instruction::{get_structured_instructions, StructuredInstruction, StructuredInstructions};
+use utils::log::Log;
+use utils::transaction::{get_context, TransactionContext};
+
+pub mod raydium_cpmm;
+use raydium_cpmm::instruction::RaydiumCpmmInstruction;
+use raydium_cpmm::log::RaydiumCpmmLog;
+use raydium_cpmm::RAYDIUM_CPMM_PROGRAM_ID;
+
+pub mod pb;
+use pb::raydium_cpmm::raydium_cpmm_event::Event;
+use pb::raydium_cpmm::*;
+
+#[substreams::handlers::map]
+fn raydium_cpmm_events(block: Block) -> Result<RaydiumCpmmBlockEvents, Error> {
+    let transactions = parse_block(&block);
+    Ok(RaydiumCpmmBlockEvents { transactions })
+}
+
+pub fn parse_block(block: &Block) -> Vec<RaydiumCpmmTransactionEvents> {
+    let mut block_events: Vec<RaydiumCpmmTransactionEvents> = Vec::new();
+    for transaction in block.transactions.iter() {
+        if let Ok(events) = parse_transaction(transaction) {
+            if !events.is_empty() {
+                block_events.push(RaydiumCpmmTransactionEvents {
+                    signature: utils::transaction::get_signature(&transaction),
+                    events,
+                });
+            }
+        }
+    }
+    block_events
+}
+
+pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<RaydiumCpmmEvent>, Error> {
+    if let Some(_) = transaction.meta.as_ref().unwrap().err {
+        return Ok(Vec::new());
+    }
+
+    let mut events: Vec<RaydiumCpmmEvent> = Vec::new();
+
+    let mut context = get_context(transaction)?;
+    let instructions = get_structured_instructions(transaction)?;
+    for instruction in instructions.flattened().iter() {
+        context.update_balance(&instruction.instruction);
+        if instruction.program_id() != RAYDIUM_CPMM_PROGRAM_ID {
+            continue;
+        }
+
+        match parse_instruction(&instruction, &context) {
+            Ok(Some(event)) => events.push(RaydiumCpmmEvent { event: Some(event) }),
+            Ok(None) => (),
+            Err(error) => substreams::log::println(format!(
+                "Failed to process instruction of transaction {}: {}",
+                &context.signature, error
+            )),
+        }
+    }
+    Ok(events)
+}
+
+pub fn parse_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<Option<Event>, Error> {
+    if instruction.program_id() != RAYDIUM_CPMM_PROGRAM_ID {
+        return Err(anyhow!("Not a Raydium CPMM instruction."));
+    }
+    let unpacked = RaydiumCpmmInstruction::unpack(instruction.data()).map_err(|x| anyhow!(x))?;
+    match unpacked {
+        RaydiumCpmmInstruction::Initialize(initialize) => Ok(Some(Event::Initialize(
+            _parse_initialize_instruction(instruction, context, initialize)?,
+        ))),
+        RaydiumCpmmInstruction::SwapBaseInput(_) => Ok(Some(Event::Swap(
+            _parse_swap_instruction(instruction, context, true)?,
+        ))),
+        RaydiumCpmmInstruction::SwapBaseOutput(_) => Ok(Some(Event::Swap(
+            _parse_swap_instruction(instruction, context, false)?,
+        ))),
+        RaydiumCpmmInstruction::Deposit(deposit) => Ok(Some(Event::Deposit(
+            _parse_deposit_instruction(instruction, context, deposit)?,
+        ))),
+        RaydiumCpmmInstruction::Withdraw(withdraw) => Ok(Some(Event::Withdraw(
+            _parse_withdraw_instruction(instruction, context, withdraw)?,
+        ))),
+        RaydiumCpmmInstruction::CollectProtocolFee(collect_fee) => Ok(Some(Event::CollectFee(
+            _parse_collect_fee_instruction(instruction, context, collect_fee, CollectFeeType::Protocol)?,
+        ))),
+        RaydiumCpmmInstruction::CollectFundFee(collect_fee) => Ok(Some(Event::CollectFee(
+            _parse_collect_fee_instruction(instruction, context, collect_fee, CollectFeeType::Fund)?,
+        ))),
+        _ => Ok(None),
+    }
+}
+
+fn _parse_initialize_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    initialize: raydium_cpmm::instruction::InitializeInstruction,
+) -> Result<InitializeEvent, Error> {
+    let accounts = instruction.accounts();
+    if accounts.len() < 20 {
+        return Err(anyhow!("Invalid number of initialize accounts."));
+    }
+    let creator = accounts[0].to_string();
+    let amm_config = accounts[1].to_string();
+    let pool = accounts[3].to_string();
+    let token0_mint = accounts[4].to_string();
+    let token1_mint = accounts[5].to_string();
+    let lp_mint = accounts[6].to_string();
+    let token0_vault = accounts[10].to_string();
+    let token1_vault = accounts[11].to_string();
+
+    Ok(InitializeEvent {
+        pool,
+        creator,
+        amm_config,
+        token0_mint,
+        token1_mint,
+        lp_mint,
+        token0_vault,
+        token1_vault,
+        init_amount0: initialize.init_amount_0,
+        init_amount1: initialize.init_amount_1,
+        open_time: initialize.open_time,
+    })
+}
+
+fn _parse_swap_instruction(
+    instruction: &StructuredInstruction,
+    context: &TransactionContext,
+    base_input: bool,
+) -> Result<SwapEvent, Error> {
+    let accounts = instruction.accounts();
+    if accounts.len() < 13 {
+        return Err(anyhow!("Invalid number of swap accounts."));
+    }
+    let amm_config = accounts[2].to_string();
+    let pool = accounts[3].to_string();
+    let input_mint = accounts[10].to_string();
+    let output_mint = accounts[11].to_string();
+
+    let input_token_account = context.get_token_account(&accounts[4]);
+    let output_token_account = context.get_token_account(&accounts[5]);
+    let user = match input_token_account {
+        Some(token_account) => token_account.owner.to_string(),
+        None => accounts[0].to_string(),
+    };
+    let user_pre_balance_in = input_token_account.and_then(|x| x.pre_balance);
+    let user_pre_balance_out = output_token_account.and_then(|x| x.pre_balance);
+
+    let swap = match parse_raydium_cpmm_log(instruction)? {
+        RaydiumCpmmLog::Swap(swap) => swap,
+        _ => return Err(anyhow!("Couldn't find SwapEvent log.")),
+    };
+
+    Ok(SwapEvent {
+        pool,
+        user,
+        amm_config,
+        input_mint,
+        output_mint,
+        amount_in: swap.input_amount,
+        amount_out: swap.output_amount,
+        base_input,
+        input_vault_before: Some(swap.input_vault_before),
+        output_vault_before: Some(swap.output_vault_before),
+        input_transfer_fee: Some(swap.input_transfer_fee),
+        output_transfer_fee: Some(swap.output_transfer_fee),
+        user_pre_balance_in,
+        user_pre_balance_out,
+    })
+}
+
+fn _parse_deposit_instruction(
+    instruction: &StructuredInstruction,
+    context: &TransactionContext,
+    deposit: raydium_cpmm::instruction::DepositInstruction,
+) -> Result<DepositEvent, Error> {
+    let accounts = instruction.accounts();
+    if accounts.len() < 13 {
+        return Err(anyhow!("Invalid number of deposit accounts."));
+    }
+    let pool = accounts[2].to_string();
+    let user = match context.get_token_account(&accounts[3]) {
+        Some(token_account) => token_account.owner.to_string(),
+        None => accounts[0].to_string(),
+    };
+    let token0_mint = accounts[10].to_string();
+    let token1_mint = accounts[11].to_string();
+    let lp_mint = accounts[12].to_string();
+
+    let lp_change = match parse_raydium_cpmm_log(instruction) {
+        Ok(RaydiumCpmmLog::LpChange(lp_change)) => Some(lp_change),
+        _ => None,
+    };
+
+    Ok(DepositEvent {
+        pool,
+        user,
+        lp_mint,
+        token0_mint,
+        token1_mint,
+        lp_amount: deposit.lp_token_amount,
+        token0_amount: lp_change.as_ref().map(|x| x.token_0_amount),
+        token1_amount: lp_change.as_ref().map(|x| x.token_1_amount),
+        lp_amount_before: lp_change.as_ref().map(|x| x.lp_amount_before),
+        token0_vault_before: lp_change.as_ref().map(|x| x.token_0_vault_before),
+        token1_vault_before: lp_change.as_ref().map(|x| x.token_1_vault_before),
+        token0_transfer_fee: lp_change.as_ref().map(|x| x.token_0_transfer_fee),
+        token1_transfer_fee: lp_change.as_ref().map(|x| x.token_1_transfer_fee),
+    })
+}
+
+fn _parse_withdraw_instruction(
+    instruction: &StructuredInstruction,
+    context: &TransactionContext,
+    withdraw: raydium_cpmm::instruction::WithdrawInstruction,
+) -> Result<WithdrawEvent, Error> {
+    let accounts = instruction.accounts();
+    if accounts.len() < 14 {
+        return Err(anyhow!("Invalid number of withdraw accounts."));
+    }
+    let pool = accounts[2].to_string();
+    let user = match context.get_token_account(&accounts[3]) {
+        Some(token_account) => token_account.owner.to_string(),
+        None => accounts[0].to_string(),
+    };
+    let token0_mint = accounts[10].to_string();
+    let token1_mint = accounts[11].to_string();
+    let lp_mint = accounts[12].to_string();
+
+    let lp_change = match parse_raydium_cpmm_log(instruction) {
+        Ok(RaydiumCpmmLog::LpChange(lp_change)) => Some(lp_change),
+        _ => None,
+    };
+
+    Ok(WithdrawEvent {
+        pool,
+        user,
+        lp_mint,
+        token0_mint,
+        token1_mint,
+        lp_amount: withdraw.lp_token_amount,
+        token0_amount: lp_change.as_ref().map(|x| x.token_0_amount),
+        token1_amount: lp_change.as_ref().map(|x| x.token_1_amount),
+        lp_amount_before: lp_change.as_ref().map(|x| x.lp_amount_before),
+        token0_vault_before: lp_change.as_ref().map(|x| x.token_0_vault_before),
+        token1_vault_before: lp_change.as_ref().map(|x| x.token_1_vault_before),
+        token0_transfer_fee: lp_change.as_ref().map(|x| x.token_0_transfer_fee),
+        token1_transfer_fee: lp_change.as_ref().map(|x| x.token_1_transfer_fee),
+    })
+}
+
+fn _parse_collect_fee_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    collect_fee: raydium_cpmm::instruction::CollectFeeInstruction,
+    fee_type: CollectFeeType,
+) -> Result<CollectFeeEvent, Error> {
+    let accounts = instruction.accounts();
+    if accounts.len() < 12 {
+        return Err(anyhow!("Invalid number of collect fee accounts."));
+    }
+    let owner = accounts[0].to_string();
+    let pool = accounts[2].to_string();
+    let token0_mint = accounts[6].to_string();
+    let token1_mint = accounts[7].to_string();
+    let recipient_token0_account = accounts[8].to_string();
+    let recipient_token1_account = accounts[9].to_string();
+
+    Ok(CollectFeeEvent {
+        pool,
+        owner,
+        fee_type: fee_type.into(),
+        token0_mint,
+        token1_mint,
+        amount0_requested: collect_fee.amount_0_requested,
+        amount1_requested: collect_fee.amount_1_requested,
+        recipient_token0_account,
+        recipient_token1_account,
+    })
+}
+
+fn parse_raydium_cpmm_log(instruction: &StructuredInstruction) -> Result<RaydiumCpmmLog, Error> {
+    let data = instruction
+        .logs()
+        .as_ref()
+        .context("Failed to parse logs due to truncation")?
+        .iter()
+        .find_map(|log| match log {
+            Log::Data(data_log) => data_log.data().ok(),
+            _ => None,
+        })
+        .ok_or(anyhow!("Couldn't find data log."))?;
+    RaydiumCpmmLog::unpack(data.as_slice()).map_err(|x| anyhow!(x))
+}
@@ -0,0 +1,6 @@
+// @generated
+// @@protoc_insertion_point(attribute:raydium_cpmm)
+pub mod raydium_cpmm {
+    include!("raydium_cpmm.rs");
+    // @@protoc_insertion_point(raydium_cpmm)
+}
@@ -0,0 +1,205 @@
+// @generated
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RaydiumCpmmBlockEvents {
+    #[prost(message, repeated, tag="1")]
+    pub transactions: ::prost::alloc::vec::Vec<RaydiumCpmmTransactionEvents>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RaydiumCpmmTransactionEvents {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="2")]
+    pub events: ::prost::alloc::vec::Vec<RaydiumCpmmEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RaydiumCpmmEvent {
+    #[prost(oneof="raydium_cpmm_event::Event", tags="1, 2, 3, 4, 5")]
+    pub event: ::core::option::Option<raydium_cpmm_event::Event>,
+}
+/// Nested message and enum types in `RaydiumCpmmEvent`.
+pub mod raydium_cpmm_event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag="1")]
+        Initialize(super::InitializeEvent),
+        #[prost(message, tag="2")]
+        Swap(super::SwapEvent),
+        #[prost(message, tag="3")]
+        Deposit(super::DepositEvent),
+        #[prost(message, tag="4")]
+        Withdraw(super::WithdrawEvent),
+        #[prost(message, tag="5")]
+        CollectFee(super::CollectFeeEvent),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeEvent {
+    #[prost(string, tag="1")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub creator: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub amm_config: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub token0_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub token1_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub lp_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub token0_vault: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub token1_vault: ::prost::alloc::string::String,
+    #[prost(uint64, tag="9")]
+    pub init_amount0: u64,
+    #[prost(uint64, tag="10")]
+    pub init_amount1: u64,
+    #[prost(uint64, tag="11")]
+    pub open_time: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapEvent {
+    #[prost(string, tag="1")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub user: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub amm_config: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub input_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub output_mint: ::prost::alloc::string::String,
+    #[prost(uint64, tag="6")]
+    pub amount_in: u64,
+    #[prost(uint64, tag="7")]
+    pub amount_out: u64,
+    #[prost(bool, tag="8")]
+    pub base_input: bool,
+    #[prost(uint64, optional, tag="9")]
+    pub input_vault_before: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="10")]
+    pub output_vault_before: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="11")]
+    pub input_transfer_fee: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="12")]
+    pub output_transfer_fee: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="13")]
+    pub user_pre_balance_in: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="14")]
+    pub user_pre_balance_out: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DepositEvent {
+    #[prost(string, tag="1")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub user: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub lp_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub token0_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub token1_mint: ::prost::alloc::string::String,
+    #[prost(uint64, tag="6")]
+    pub lp_amount: u64,
+    #[prost(uint64, optional, tag="7")]
+    pub token0_amount: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="8")]
+    pub token1_amount: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="9")]
+    pub lp_amount_before: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="10")]
+    pub token0_vault_before: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="11")]
+    pub token1_vault_before: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="12")]
+    pub token0_transfer_fee: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="13")]
+    pub token1_transfer_fee: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithdrawEvent {
+    #[prost(string, tag="1")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub user: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub lp_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub token0_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub token1_mint: ::prost::alloc::string::String,
+    #[prost(uint64, tag="6")]
+    pub lp_amount: u64,
+    #[prost(uint64, optional, tag="7")]
+    pub token0_amount: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="8")]
+    pub token1_amount: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="9")]
+    pub lp_amount_before: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="10")]
+    pub token0_vault_before: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="11")]
+    pub token1_vault_before: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="12")]
+    pub token0_transfer_fee: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="13")]
+    pub token1_transfer_fee: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollectFeeEvent {
+    #[prost(string, tag="1")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(enumeration="CollectFeeType", tag="3")]
+    pub fee_type: i32,
+    #[prost(string, tag="4")]
+    pub token0_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub token1_mint: ::prost::alloc::string::String,
+    #[prost(uint64, tag="6")]
+    pub amount0_requested: u64,
+    #[prost(uint64, tag="7")]
+    pub amount1_requested: u64,
+    #[prost(string, tag="8")]
+    pub recipient_token0_account: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub recipient_token1_account: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CollectFeeType {
+    Protocol = 0,
+    Fund = 1,
+}
+impl CollectFeeType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CollectFeeType::Protocol => "Protocol",
+            CollectFeeType::Fund => "Fund",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Protocol" => Some(Self::Protocol),
+            "Fund" => Some(Self::Fund),
+            _ => None,
+        }
+    }
+}
+// @@protoc_insertion_point(module)
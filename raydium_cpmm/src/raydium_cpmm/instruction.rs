@@ -0,0 +1,209 @@
+use borsh::BorshDeserialize;
+
+#[derive(Debug, BorshDeserialize)]
+pub enum RaydiumCpmmInstruction {
+    Initialize(InitializeInstruction),
+    Deposit(DepositInstruction),
+    Withdraw(WithdrawInstruction),
+    SwapBaseInput(SwapBaseInputInstruction),
+    SwapBaseOutput(SwapBaseOutputInstruction),
+    CollectProtocolFee(CollectFeeInstruction),
+    CollectFundFee(CollectFeeInstruction),
+    Unknown,
+}
+
+impl RaydiumCpmmInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        if data.len() < 8 {
+            return Err("Instruction data is too short.");
+        }
+        let (tag, data) = data.split_at(8);
+        match tag {
+            [175, 175, 109, 31, 13, 152, 155, 237] => Ok(Self::Initialize(InitializeInstruction::unpack(data)?)),
+            [242, 35, 198, 137, 82, 225, 242, 182] => Ok(Self::Deposit(DepositInstruction::unpack(data)?)),
+            [183, 18, 70, 156, 148, 109, 161, 34] => Ok(Self::Withdraw(WithdrawInstruction::unpack(data)?)),
+            [143, 190, 90, 218, 196, 30, 51, 222] => Ok(Self::SwapBaseInput(SwapBaseInputInstruction::unpack(data)?)),
+            [55, 217, 98, 86, 163, 74, 180, 173] => Ok(Self::SwapBaseOutput(SwapBaseOutputInstruction::unpack(data)?)),
+            [136, 136, 252, 221, 194, 66, 126, 89] => Ok(Self::CollectProtocolFee(CollectFeeInstruction::unpack(data)?)),
+            [167, 138, 78, 149, 223, 194, 6, 126] => Ok(Self::CollectFundFee(CollectFeeInstruction::unpack(data)?)),
+            _ => Ok(Self::Unknown),
+        }
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct InitializeInstruction {
+    pub init_amount_0: u64,
+    pub init_amount_1: u64,
+    pub open_time: u64,
+}
+
+impl InitializeInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize InitializeInstruction.")
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct DepositInstruction {
+    pub lp_token_amount: u64,
+    pub maximum_token_0_amount: u64,
+    pub maximum_token_1_amount: u64,
+}
+
+impl DepositInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize DepositInstruction.")
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct WithdrawInstruction {
+    pub lp_token_amount: u64,
+    pub minimum_token_0_amount: u64,
+    pub minimum_token_1_amount: u64,
+}
+
+impl WithdrawInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize WithdrawInstruction.")
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct SwapBaseInputInstruction {
+    pub amount_in: u64,
+    pub minimum_amount_out: u64,
+}
+
+impl SwapBaseInputInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize SwapBaseInputInstruction.")
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct SwapBaseOutputInstruction {
+    pub max_amount_in: u64,
+    pub amount_out: u64,
+}
+
+impl SwapBaseOutputInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize SwapBaseOutputInstruction.")
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct CollectFeeInstruction {
+    pub amount_0_requested: u64,
+    pub amount_1_requested: u64,
+}
+
+impl CollectFeeInstruction {
+    fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        Self::deserialize(&mut &data[..]).map_err(|_| "Failed to deserialize CollectFeeInstruction.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instruction_data(discriminator: [u8; 8], args: &[u64]) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        for arg in args {
+            data.extend_from_slice(&arg.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn test_unpack_initialize() {
+        let data = instruction_data([175, 175, 109, 31, 13, 152, 155, 237], &[1_000_000, 2_500_000, 1_720_000_000]);
+        match RaydiumCpmmInstruction::unpack(&data).unwrap() {
+            RaydiumCpmmInstruction::Initialize(initialize) => {
+                assert_eq!(initialize.init_amount_0, 1_000_000);
+                assert_eq!(initialize.init_amount_1, 2_500_000);
+                assert_eq!(initialize.open_time, 1_720_000_000);
+            },
+            instruction => panic!("Unexpected instruction {:?}", instruction),
+        }
+    }
+
+    #[test]
+    fn test_unpack_deposit_and_withdraw() {
+        let data = instruction_data([242, 35, 198, 137, 82, 225, 242, 182], &[500, 1_000, 2_000]);
+        match RaydiumCpmmInstruction::unpack(&data).unwrap() {
+            RaydiumCpmmInstruction::Deposit(deposit) => {
+                assert_eq!(deposit.lp_token_amount, 500);
+                assert_eq!(deposit.maximum_token_0_amount, 1_000);
+                assert_eq!(deposit.maximum_token_1_amount, 2_000);
+            },
+            instruction => panic!("Unexpected instruction {:?}", instruction),
+        }
+
+        let data = instruction_data([183, 18, 70, 156, 148, 109, 161, 34], &[500, 900, 1_800]);
+        match RaydiumCpmmInstruction::unpack(&data).unwrap() {
+            RaydiumCpmmInstruction::Withdraw(withdraw) => {
+                assert_eq!(withdraw.lp_token_amount, 500);
+                assert_eq!(withdraw.minimum_token_0_amount, 900);
+                assert_eq!(withdraw.minimum_token_1_amount, 1_800);
+            },
+            instruction => panic!("Unexpected instruction {:?}", instruction),
+        }
+    }
+
+    #[test]
+    fn test_unpack_swaps() {
+        let data = instruction_data([143, 190, 90, 218, 196, 30, 51, 222], &[1_000_000, 990_000]);
+        match RaydiumCpmmInstruction::unpack(&data).unwrap() {
+            RaydiumCpmmInstruction::SwapBaseInput(swap) => {
+                assert_eq!(swap.amount_in, 1_000_000);
+                assert_eq!(swap.minimum_amount_out, 990_000);
+            },
+            instruction => panic!("Unexpected instruction {:?}", instruction),
+        }
+
+        let data = instruction_data([55, 217, 98, 86, 163, 74, 180, 173], &[1_010_000, 1_000_000]);
+        match RaydiumCpmmInstruction::unpack(&data).unwrap() {
+            RaydiumCpmmInstruction::SwapBaseOutput(swap) => {
+                assert_eq!(swap.max_amount_in, 1_010_000);
+                assert_eq!(swap.amount_out, 1_000_000);
+            },
+            instruction => panic!("Unexpected instruction {:?}", instruction),
+        }
+    }
+
+    #[test]
+    fn test_unpack_collect_fees() {
+        let data = instruction_data([136, 136, 252, 221, 194, 66, 126, 89], &[u64::MAX, 42]);
+        match RaydiumCpmmInstruction::unpack(&data).unwrap() {
+            RaydiumCpmmInstruction::CollectProtocolFee(collect_fee) => {
+                assert_eq!(collect_fee.amount_0_requested, u64::MAX);
+                assert_eq!(collect_fee.amount_1_requested, 42);
+            },
+            instruction => panic!("Unexpected instruction {:?}", instruction),
+        }
+
+        let data = instruction_data([167, 138, 78, 149, 223, 194, 6, 126], &[7, u64::MAX]);
+        match RaydiumCpmmInstruction::unpack(&data).unwrap() {
+            RaydiumCpmmInstruction::CollectFundFee(collect_fee) => {
+                assert_eq!(collect_fee.amount_0_requested, 7);
+                assert_eq!(collect_fee.amount_1_requested, u64::MAX);
+            },
+            instruction => panic!("Unexpected instruction {:?}", instruction),
+        }
+    }
+
+    #[test]
+    fn test_unpack_invalid_data() {
+        assert!(RaydiumCpmmInstruction::unpack(&[143, 190, 90]).is_err());
+        // Swaps without their arguments fail instead of decoding garbage.
+        assert!(RaydiumCpmmInstruction::unpack(&instruction_data([143, 190, 90, 218, 196, 30, 51, 222], &[1])).is_err());
+        assert!(matches!(
+            RaydiumCpmmInstruction::unpack(&instruction_data([0; 8], &[])).unwrap(),
+            RaydiumCpmmInstruction::Unknown
+        ));
+    }
+}
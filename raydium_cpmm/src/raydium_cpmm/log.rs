@@ -0,0 +1,114 @@
+use borsh::BorshDeserialize;
+use substreams_solana_utils::pubkey::Pubkey;
+
+#[derive(Debug)]
+pub enum RaydiumCpmmLog {
+    Swap(SwapLog),
+    LpChange(LpChangeLog),
+}
+
+impl RaydiumCpmmLog {
+    pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        if data.len() < 8 {
+            return Err("Event data is too short.");
+        }
+        let (discriminator, data) = data.split_at(8);
+        match discriminator {
+            [64, 198, 205, 232, 38, 8, 113, 226] => SwapLog::deserialize(&mut &data[..])
+                .map(Self::Swap)
+                .map_err(|_| "Failed to unpack SwapEvent."),
+            [121, 163, 205, 201, 57, 218, 117, 60] => LpChangeLog::deserialize(&mut &data[..])
+                .map(Self::LpChange)
+                .map_err(|_| "Failed to unpack LpChangeEvent."),
+            _ => Err("Unknown Raydium CPMM event."),
+        }
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct SwapLog {
+    pub pool_id: Pubkey,
+    pub input_vault_before: u64,
+    pub output_vault_before: u64,
+    pub input_amount: u64,
+    pub output_amount: u64,
+    pub input_transfer_fee: u64,
+    pub output_transfer_fee: u64,
+    pub base_input: bool,
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct LpChangeLog {
+    pub pool_id: Pubkey,
+    pub lp_amount_before: u64,
+    pub token_0_vault_before: u64,
+    pub token_1_vault_before: u64,
+    pub token_0_amount: u64,
+    pub token_1_amount: u64,
+    pub token_0_transfer_fee: u64,
+    pub token_1_transfer_fee: u64,
+    /// 0: deposit, 1: withdraw
+    pub change_type: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POOL_ID: [u8; 32] = [7; 32];
+
+    fn event_data(discriminator: [u8; 8], values: &[u64], trailer: u8) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        data.extend_from_slice(&POOL_ID);
+        for value in values {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.push(trailer);
+        data
+    }
+
+    #[test]
+    fn test_unpack_swap_event() {
+        let data = event_data([64, 198, 205, 232, 38, 8, 113, 226], &[5_000_000, 9_000_000, 1_000, 1_795, 3, 0], 1);
+        match RaydiumCpmmLog::unpack(&data).unwrap() {
+            RaydiumCpmmLog::Swap(swap) => {
+                assert_eq!(swap.pool_id, Pubkey(POOL_ID));
+                assert_eq!(swap.input_vault_before, 5_000_000);
+                assert_eq!(swap.output_vault_before, 9_000_000);
+                assert_eq!(swap.input_amount, 1_000);
+                assert_eq!(swap.output_amount, 1_795);
+                assert_eq!(swap.input_transfer_fee, 3);
+                assert_eq!(swap.output_transfer_fee, 0);
+                assert!(swap.base_input);
+            },
+            log => panic!("Unexpected log {:?}", log),
+        }
+    }
+
+    #[test]
+    fn test_unpack_lp_change_event() {
+        let data = event_data([121, 163, 205, 201, 57, 218, 117, 60], &[10_000, 20_000, 40_000, 500, 1_000, 0, 2], 1);
+        match RaydiumCpmmLog::unpack(&data).unwrap() {
+            RaydiumCpmmLog::LpChange(lp_change) => {
+                assert_eq!(lp_change.pool_id, Pubkey(POOL_ID));
+                assert_eq!(lp_change.lp_amount_before, 10_000);
+                assert_eq!(lp_change.token_0_vault_before, 20_000);
+                assert_eq!(lp_change.token_1_vault_before, 40_000);
+                assert_eq!(lp_change.token_0_amount, 500);
+                assert_eq!(lp_change.token_1_amount, 1_000);
+                assert_eq!(lp_change.token_0_transfer_fee, 0);
+                assert_eq!(lp_change.token_1_transfer_fee, 2);
+                assert_eq!(lp_change.change_type, 1);
+            },
+            log => panic!("Unexpected log {:?}", log),
+        }
+    }
+
+    #[test]
+    fn test_unpack_invalid_event() {
+        assert!(RaydiumCpmmLog::unpack(&[64, 198, 205]).is_err());
+        assert!(RaydiumCpmmLog::unpack(&event_data([0; 8], &[], 0)).is_err());
+        // A swap event cut short doesn't decode.
+        assert!(RaydiumCpmmLog::unpack(&event_data([64, 198, 205, 232, 38, 8, 113, 226], &[1, 2], 0)).is_err());
+    }
+}
@@ -0,0 +1,4 @@
+pub mod instruction;
+pub mod constants;
+pub use constants::RAYDIUM_CPMM_PROGRAM_ID;
+pub mod log;
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use anyhow::Error;
+use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction, TokenBalance};
+
+use substreams_solana_utils as utils;
+
+use crate::pb::spl_token::*;
+use crate::pb::spl_token::spl_token_event::Event;
+
+#[substreams::handlers::map]
+fn spl_token_balance_changes(block: Block) -> Result<SplTokenBlockBalanceChanges, Error> {
+    let mut transactions: Vec<SplTokenTransactionBalanceChanges> = Vec::new();
+    for transaction in block.transactions() {
+        let changes = parse_balance_changes(transaction)?;
+        if !changes.is_empty() {
+            transactions.push(SplTokenTransactionBalanceChanges {
+                signature: utils::transaction::get_signature(&transaction),
+                changes,
+            });
+        }
+    }
+    Ok(SplTokenBlockBalanceChanges { transactions })
+}
+
+/// Token balance changes of a transaction, built from `pre_token_balances` and `post_token_balances`.
+///
+/// Accounts whose balance didn't change are skipped. Each change is checked against the delta implied
+/// by the decoded transfers, mints and burns of the transaction.
+pub fn parse_balance_changes(transaction: &ConfirmedTransaction) -> Result<Vec<TokenBalanceChange>, Error> {
+    let meta = transaction.meta.as_ref().unwrap();
+    if meta.err.is_some() {
+        return Ok(Vec::new());
+    }
+
+    let accounts: Vec<String> = transaction
+        .resolved_accounts()
+        .iter()
+        .map(|x| bs58::encode(x).into_string())
+        .collect();
+
+    // Keyed by account index, keeping the order in which accounts appear.
+    let mut balances: Vec<(u32, Option<&TokenBalance>, Option<&TokenBalance>)> = Vec::new();
+    for pre in meta.pre_token_balances.iter() {
+        balances.push((pre.account_index, Some(pre), None));
+    }
+    for post in meta.post_token_balances.iter() {
+        match balances.iter_mut().find(|(index, _, _)| *index == post.account_index) {
+            Some(balance) => balance.2 = Some(post),
+            None => balances.push((post.account_index, None, Some(post))),
+        }
+    }
+
+    let instruction_deltas = match crate::parse_transaction(transaction) {
+        Ok(events) => Some(get_instruction_deltas(&events)),
+        Err(_) => None,
+    };
+
+    let mut changes: Vec<TokenBalanceChange> = Vec::new();
+    for (index, pre, post) in balances {
+        let pre_amount = pre.map_or(0, get_amount);
+        let post_amount = post.map_or(0, get_amount);
+        if pre_amount == post_amount {
+            continue;
+        }
+        // Either one is set, and both describe the same account.
+        let balance = post.or(pre).unwrap();
+        let account = accounts.get(index as usize).cloned().unwrap_or_default();
+        let delta = (post_amount as i128 - pre_amount as i128) as i64;
+        let instruction_delta = instruction_deltas.as_ref().map(|x| x.get(&account).copied().unwrap_or(0) as i64);
+
+        changes.push(TokenBalanceChange {
+            account,
+            owner: balance.owner.clone(),
+            mint: balance.mint.clone(),
+            program_id: balance.program_id.clone(),
+            decimals: balance.ui_token_amount.as_ref().map_or(0, |x| x.decimals),
+            pre_amount,
+            post_amount,
+            delta,
+            instruction_delta,
+            reconciled: instruction_delta == Some(delta),
+        });
+    }
+    Ok(changes)
+}
+
+fn get_amount(balance: &TokenBalance) -> u64 {
+    balance.ui_token_amount.as_ref().and_then(|x| x.amount.parse().ok()).unwrap_or(0)
+}
+
+/// Net balance change of every token account according to the decoded instructions.
+fn get_instruction_deltas(events: &[SplTokenEvent]) -> HashMap<String, i128> {
+    let mut deltas: HashMap<String, i128> = HashMap::new();
+    for event in events.iter() {
+        match &event.event {
+            Some(Event::Transfer(transfer)) => {
+                if let Some(source) = &transfer.source {
+                    *deltas.entry(source.address.clone()).or_default() -= transfer.amount as i128;
+                }
+                if let Some(destination) = &transfer.destination {
+                    *deltas.entry(destination.address.clone()).or_default() += transfer.net_amount.unwrap_or(transfer.amount) as i128;
+                }
+            },
+            Some(Event::MintTo(mint_to)) => {
+                if let Some(destination) = &mint_to.destination {
+                    *deltas.entry(destination.address.clone()).or_default() += mint_to.amount as i128;
+                }
+            },
+            Some(Event::Burn(burn)) => {
+                if let Some(source) = &burn.source {
+                    *deltas.entry(source.address.clone()).or_default() -= burn.amount as i128;
+                }
+            },
+            _ => (),
+        }
+    }
+    deltas
+}
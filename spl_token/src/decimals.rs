@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use substreams::store::{StoreGet, StoreGetInt64, StoreNew, StoreSet, StoreSetInt64};
+use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction};
+
+use crate::pb::spl_token::spl_token_event::Event;
+
+/// Decimals of native SOL amounts in lamports.
+pub const SOL_DECIMALS: u32 = 9;
+
+/// Decimals of every mint seen in the token balances of the block.
+#[substreams::handlers::store]
+fn store_mint_decimals(block: Block, store: StoreSetInt64) {
+    for transaction in block.transactions() {
+        for (mint, decimals) in get_mint_decimals(transaction) {
+            store.set(0, mint, &(decimals as i64));
+        }
+    }
+}
+
+/// Decimals of every mint in the transaction's `pre_token_balances` and `post_token_balances`.
+pub fn get_mint_decimals(transaction: &ConfirmedTransaction) -> HashMap<String, u32> {
+    let mut mint_decimals: HashMap<String, u32> = HashMap::new();
+    if let Some(meta) = transaction.meta.as_ref() {
+        for balance in meta.pre_token_balances.iter().chain(meta.post_token_balances.iter()) {
+            if let Some(ui_token_amount) = &balance.ui_token_amount {
+                mint_decimals.insert(balance.mint.clone(), ui_token_amount.decimals);
+            }
+        }
+    }
+    mint_decimals
+}
+
+/// Reads the decimals of a mint from `store_mint_decimals`.
+pub fn get_stored_decimals(store: &StoreGetInt64, mint: &str) -> Option<u32> {
+    store.get_last(mint).map(|x| x as u32)
+}
+
+/// Formats a raw amount as a decimal string, e.g. `1500000` with 6 decimals is `1.5`.
+pub fn to_ui_amount(amount: u64, decimals: u32) -> String {
+    let decimals = decimals as usize;
+    let digits = format!("{:0>width$}", amount, width = decimals + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{}.{}", integer, fraction)
+    }
+}
+
+/// Sets the decimals and UI amount of transfer, mint and burn events whose decimals aren't known yet.
+pub fn set_ui_amounts<F: Fn(&str) -> Option<u32>>(event: &mut Event, get_decimals: F) {
+    match event {
+        Event::Transfer(transfer) => {
+            let mint = transfer.source.as_ref().map(|x| x.mint.as_str()).unwrap_or_default();
+            transfer.decimals = transfer.decimals.or_else(|| get_decimals(mint));
+            transfer.ui_amount = transfer.decimals.map(|x| to_ui_amount(transfer.amount, x));
+        },
+        Event::MintTo(mint_to) => {
+            mint_to.decimals = mint_to.decimals.or_else(|| get_decimals(&mint_to.mint));
+            mint_to.ui_amount = mint_to.decimals.map(|x| to_ui_amount(mint_to.amount, x));
+        },
+        Event::Burn(burn) => {
+            let mint = burn.source.as_ref().map(|x| x.mint.as_str()).unwrap_or_default();
+            burn.decimals = burn.decimals.or_else(|| get_decimals(mint));
+            burn.ui_amount = burn.decimals.map(|x| to_ui_amount(burn.amount, x));
+        },
+        _ => (),
+    }
+}
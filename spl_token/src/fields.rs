@@ -0,0 +1,20 @@
+//! Records kept one field per key.
+//!
+//! A store handler can't read its own values back, so a record that changes one field at a time
+//! (e.g. a token account whose delegate is revoked) can't be rewritten as a whole. Such stores keep
+//! every field under its own `{address}:{field}` key instead, and their readers put the record back
+//! together from the individual fields.
+
+use substreams::store::{StoreGet, StoreGetString, StoreSet, StoreSetString};
+
+pub fn field_key(address: &str, field: &str) -> String {
+    format!("{}:{}", address, field)
+}
+
+pub fn set_field(store: &StoreSetString, address: &str, field: &str, value: &str) {
+    store.set(0, field_key(address, field), &value.to_string());
+}
+
+pub fn get_field(store: &StoreGetString, address: &str, field: &str) -> Option<String> {
+    store.get_last(field_key(address, field))
+}
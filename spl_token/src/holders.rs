@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use substreams::errors::Error;
+use substreams::scalar::BigInt;
+use substreams::store::{
+    DeltaBigInt, DeltaInt64, Deltas, StoreAdd, StoreAddBigInt, StoreAddInt64, StoreGet, StoreGetBigInt, StoreNew,
+};
+
+use crate::fields::field_key;
+use crate::pb::spl_token::*;
+
+const SUM_OF_SQUARES: &str = "sum_of_squares";
+const TOTAL: &str = "total";
+
+/// Number of holders reported by `spl_token_block_top_movers` for every mint.
+pub const TOP_MOVERS: usize = 10;
+
+/// Balance of every holder, keyed by `{mint}:{owner}` and summed over all of the owner's token accounts.
+///
+/// Balances follow the token balance changes of transactions rather than decoded instructions, so closed
+/// accounts, native SOL accounts and withheld Token-2022 fees are accounted for. Holders funded before
+/// the start block can have negative balances, which count as empty below.
+#[substreams::handlers::store]
+fn store_holder_balances(balance_changes: SplTokenBlockBalanceChanges, store: StoreAddBigInt) {
+    for transaction in balance_changes.transactions.iter() {
+        for change in transaction.changes.iter() {
+            store.add(0, holder_key(&change.mint, &change.owner), BigInt::from(change.delta));
+        }
+    }
+}
+
+/// Number of holders with a positive balance of every mint.
+#[substreams::handlers::store]
+fn store_holder_counts(balances: Deltas<DeltaBigInt>, store: StoreAddInt64) {
+    for delta in balances.deltas.iter() {
+        let was_holder = delta.old_value > BigInt::zero();
+        let is_holder = delta.new_value > BigInt::zero();
+        if was_holder != is_holder {
+            store.add(0, get_mint(&delta.key), if is_holder { 1 } else { -1 });
+        }
+    }
+}
+
+/// Sum of the squared holder balances and sum of the balances of every mint, keyed by
+/// `{mint}:sum_of_squares` and `{mint}:total`, from which [`get_holder_concentration`] is computed.
+///
+/// Unlike the supply share of `spl_token_block_top_movers`, the Herfindahl index covers every holder.
+#[substreams::handlers::store]
+fn store_holder_concentration(balances: Deltas<DeltaBigInt>, store: StoreAddBigInt) {
+    for delta in balances.deltas.iter() {
+        let old_value = non_negative(&delta.old_value);
+        let new_value = non_negative(&delta.new_value);
+        let mint = get_mint(&delta.key);
+        store.add(0, field_key(mint, SUM_OF_SQUARES), new_value.clone() * new_value.clone() - old_value.clone() * old_value.clone());
+        store.add(0, field_key(mint, TOTAL), new_value - old_value);
+    }
+}
+
+/// Largest holders of every mint among those whose balance changed in the block, by their balance at the
+/// end of it, with the share of the mint supply they hold.
+///
+/// This isn't the mint's top holders ranking: a store can't read its own values back, so a ranking of all
+/// holders can't be kept up to date, and holders that didn't move in the block are left out. The supply
+/// share is therefore only a lower bound of the share held by the mint's largest holders.
+#[substreams::handlers::map]
+fn spl_token_block_top_movers(balances: Deltas<DeltaBigInt>, supply: StoreGetBigInt) -> Result<SplTokenBlockTopMovers, Error> {
+    // Balances at the end of the block, keyed by mint, and the mints in order of appearance.
+    let mut holders: HashMap<String, Vec<(String, BigInt)>> = HashMap::new();
+    let mut mints: Vec<String> = Vec::new();
+    for delta in balances.deltas.iter() {
+        let (mint, owner) = match delta.key.split_once(':') {
+            Some(key) => key,
+            None => continue,
+        };
+        if !holders.contains_key(mint) {
+            mints.push(mint.to_string());
+        }
+        let mint_holders = holders.entry(mint.to_string()).or_default();
+        match mint_holders.iter_mut().find(|(x, _)| x == owner) {
+            Some(holder) => holder.1 = delta.new_value.clone(),
+            None => mint_holders.push((owner.to_string(), delta.new_value.clone())),
+        }
+    }
+
+    let mints = mints
+        .into_iter()
+        .filter_map(|mint| {
+            let mut mint_holders = holders.remove(&mint)?;
+            mint_holders.retain(|(_, balance)| *balance > BigInt::zero());
+            if mint_holders.is_empty() {
+                return None;
+            }
+            mint_holders.sort_by(|a, b| b.1.cmp(&a.1));
+            mint_holders.truncate(TOP_MOVERS);
+
+            let top_total = mint_holders.iter().fold(BigInt::zero(), |total, (_, balance)| total + balance.clone());
+            let mint_supply = supply.get_last(&mint).map(|x| to_f64(&x)).filter(|x| *x > 0.0);
+            Some(MintTopMovers {
+                supply_share: mint_supply.map(|mint_supply| to_f64(&top_total) / mint_supply),
+                holders: mint_holders
+                    .into_iter()
+                    .map(|(owner, balance)| HolderBalance { owner, balance: balance.to_string() })
+                    .collect(),
+                mint,
+            })
+        })
+        .collect();
+
+    Ok(SplTokenBlockTopMovers { mints })
+}
+
+/// Holder count changes in the block of every mint whose number of holders changed.
+#[substreams::handlers::map]
+fn spl_token_holder_changes(
+    holder_counts: Deltas<DeltaInt64>,
+    concentration: StoreGetBigInt,
+) -> Result<SplTokenBlockHolderChanges, Error> {
+    let mut mints: Vec<HolderCountChange> = Vec::new();
+    for delta in holder_counts.deltas.iter() {
+        match mints.iter_mut().find(|x| x.mint == delta.key) {
+            Some(change) => change.holder_count = delta.new_value,
+            None => mints.push(HolderCountChange {
+                mint: delta.key.clone(),
+                previous_holder_count: delta.old_value,
+                holder_count: delta.new_value,
+                concentration: None,
+            }),
+        }
+    }
+    for change in mints.iter_mut() {
+        change.concentration = get_holder_concentration(&concentration, &change.mint);
+    }
+    mints.retain(|x| x.holder_count != x.previous_holder_count);
+
+    Ok(SplTokenBlockHolderChanges { mints })
+}
+
+/// Herfindahl index of the holder balances of a mint: 1 if a single holder owns the whole supply, and
+/// close to 0 if it's spread over many holders.
+pub fn get_holder_concentration(store: &StoreGetBigInt, mint: &str) -> Option<f64> {
+    let sum_of_squares = to_f64(&store.get_last(field_key(mint, SUM_OF_SQUARES))?);
+    let total = to_f64(&store.get_last(field_key(mint, TOTAL))?);
+    if total <= 0.0 {
+        return None;
+    }
+    Some(sum_of_squares / (total * total))
+}
+
+fn non_negative(value: &BigInt) -> BigInt {
+    if *value > BigInt::zero() { value.clone() } else { BigInt::zero() }
+}
+
+fn to_f64(value: &BigInt) -> f64 {
+    value.to_string().parse().unwrap_or(0.0)
+}
+
+fn get_mint(key: &str) -> &str {
+    key.split(':').next().unwrap_or(key)
+}
+
+fn holder_key(mint: &str, owner: &str) -> String {
+    format!("{}:{}", mint, owner)
+}
@@ -1,5 +1,7 @@
 use anyhow::{anyhow, Context, Error};
 
+use substreams::store::StoreGetInt64;
+
 use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
 use substreams_solana::pb::sf::solana::r#type::v1::Block;
 
@@ -9,13 +11,31 @@ use utils::transaction::{get_context, TransactionContext};
 use utils::spl_token::{TokenInstruction, TOKEN_PROGRAM_ID};
 use utils::pubkey::Pubkey;
 
+pub mod token_2022;
+use token_2022::instruction::Token2022Instruction;
+use token_2022::TOKEN_2022_PROGRAM_ID;
+
 pub mod pb;
 use pb::spl_token::*;
 use pb::spl_token::spl_token_event::Event;
 
+pub mod balance_changes;
+pub mod token_accounts;
+pub mod mint_supply;
+pub mod holders;
+pub mod native;
+pub mod decimals;
+pub mod fields;
+use native::PendingLamports;
+
 #[substreams::handlers::map]
-fn spl_token_events(block: Block) -> Result<SplTokenBlockEvents, Error> {
-    Ok(SplTokenBlockEvents { transactions: parse_block(&block)? })
+fn spl_token_events(block: Block, mint_decimals: StoreGetInt64) -> Result<SplTokenBlockEvents, Error> {
+    let mut transactions = parse_block(&block)?;
+    // Decimals of mints missing from a transaction's token balances are taken from earlier blocks.
+    for event in transactions.iter_mut().flat_map(|x| x.events.iter_mut()).filter_map(|x| x.event.as_mut()) {
+        decimals::set_ui_amounts(event, |mint| decimals::get_stored_decimals(&mint_decimals, mint));
+    }
+    Ok(SplTokenBlockEvents { transactions })
 }
 
 pub fn parse_block(block: &Block) -> Result<Vec<SplTokenTransactionEvents>, Error> {
@@ -42,11 +62,45 @@ pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<SplTo
     let mut context = get_context(transaction)?;
     let instructions = get_structured_instructions(transaction)?;
 
-    for instruction in instructions.flattened().iter() {
+    let mut pending_lamports = PendingLamports::new(transaction);
+    let mint_decimals = decimals::get_mint_decimals(transaction);
+    let signers = get_transaction_signers(transaction);
+
+    let instructions = instructions.flattened();
+    let token_instruction_accounts = instructions
+        .iter()
+        .filter(|x| is_token_program(x.program_id()))
+        .flat_map(|x| x.accounts().iter().map(|x| x.to_string()).collect::<Vec<_>>());
+    let single_instruction_deltas = token_2022::transfer_fee::get_single_instruction_deltas(transaction, token_instruction_accounts);
+
+    for instruction in instructions.iter() {
         context.update_balance(&instruction.instruction);
-        if instruction.program_id() == TOKEN_PROGRAM_ID {
-            let event = parse_instruction(instruction, &context)?;
+        pending_lamports.record(instruction);
+        if is_token_program(instruction.program_id()) {
+            let mut event = match parse_instruction(instruction, &context) {
+                Ok(event) => event,
+                // Token-2022 keeps adding extensions, so instructions it can't decode are skipped rather than failing the block.
+                Err(_) if instruction.program_id() == TOKEN_2022_PROGRAM_ID => continue,
+                Err(error) => return Err(error),
+            };
+            if let Some(event) = event.as_mut() {
+                decimals::set_ui_amounts(event, |mint| mint_decimals.get(mint).copied());
+                retain_multisig_signers(event, &signers);
+                if let Event::Transfer(transfer) = event {
+                    token_2022::transfer_fee::set_derived_fee(transfer, &single_instruction_deltas);
+                }
+            }
+            // Wraps and unwraps are emitted right after the instruction event they derive from.
+            let native_event = match &event {
+                Some(Event::SyncNative(sync_native)) => native::parse_wrap_sol(sync_native, &mut pending_lamports).map(Event::WrapSol),
+                Some(Event::InitializeAccount(initialize_account)) => native::parse_initialize_wrap_sol(initialize_account, &mut pending_lamports).map(Event::WrapSol),
+                Some(Event::CloseAccount(close_account)) => native::parse_unwrap_sol(close_account, &pending_lamports).map(Event::UnwrapSol),
+                _ => None,
+            };
             events.push(SplTokenEvent { event });
+            if let Some(native_event) = native_event {
+                events.push(SplTokenEvent { event: Some(native_event) });
+            }
         }
     }
 
@@ -57,9 +111,12 @@ pub fn parse_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
 ) -> Result<Option<Event>, Error> {
-    if instruction.program_id() != TOKEN_PROGRAM_ID {
+    if !is_token_program(instruction.program_id()) {
         return Err(anyhow!("Not a Token program instruction"));
     }
+    if instruction.program_id() == TOKEN_2022_PROGRAM_ID && Token2022Instruction::is_extension(instruction.data()) {
+        return parse_token_2022_instruction(instruction, context);
+    }
 
     let unpacked = TokenInstruction::unpack(&instruction.data())
         .map_err(|x| anyhow!(x).context("Failed to unpack Token instruction"))?;
@@ -90,11 +147,11 @@ pub fn parse_instruction<'a>(
         },
 
         TokenInstruction::Transfer { amount } => {
-            let event = _parse_transfer_instruction(instruction, context, amount, None);
+            let event = _parse_transfer_instruction(instruction, context, amount, None, None);
             event.map(|x| Some(Event::Transfer(x))).map_err(|x| anyhow!(x))
         },
         TokenInstruction::TransferChecked { amount, decimals } => {
-            let event = _parse_transfer_instruction(instruction, context, amount, Some(decimals));
+            let event = _parse_transfer_instruction(instruction, context, amount, Some(decimals), None);
             event.map(|x| Some(Event::Transfer(x))).map_err(|x| anyhow!(x))
         },
 
@@ -118,20 +175,20 @@ pub fn parse_instruction<'a>(
         },
 
         TokenInstruction::MintTo { amount } => {
-            let event = _parse_mint_to_instruction(instruction, context, amount);
+            let event = _parse_mint_to_instruction(instruction, context, amount, None);
             event.map(|x| Some(Event::MintTo(x))).map_err(|x| anyhow!(x))
         },
-        TokenInstruction::MintToChecked { amount, decimals: _ } => {
-            let event = _parse_mint_to_instruction(instruction, context, amount);
+        TokenInstruction::MintToChecked { amount, decimals } => {
+            let event = _parse_mint_to_instruction(instruction, context, amount, Some(decimals));
             event.map(|x| Some(Event::MintTo(x))).map_err(|x| anyhow!(x))
         },
 
         TokenInstruction::Burn { amount } => {
-            let event = _parse_burn_instruction(instruction, context, amount);
+            let event = _parse_burn_instruction(instruction, context, amount, None);
             event.map(|x| Some(Event::Burn(x))).map_err(|x| anyhow!(x))
         },
-        TokenInstruction::BurnChecked { amount, decimals: _ } => {
-            let event = _parse_burn_instruction(instruction, context, amount);
+        TokenInstruction::BurnChecked { amount, decimals } => {
+            let event = _parse_burn_instruction(instruction, context, amount, Some(decimals));
             event.map(|x| Some(Event::Burn(x))).map_err(|x| anyhow!(x))
         },
 
@@ -172,6 +229,7 @@ fn _parse_initialize_mint_instruction(
     mint_authority: Pubkey,
     freeze_authority: Option<Pubkey>,
 ) -> Result<InitializeMintEvent, &'static str> {
+    check_accounts(instruction, 1)?;
     let mint = instruction.accounts()[0].to_string();
     let mint_authority = mint_authority.to_string();
     let freeze_authority = freeze_authority.map(|x| x.to_string());
@@ -187,13 +245,26 @@ fn _parse_initialize_mint_instruction(
 fn _parse_initialize_account_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
-    _owner: Option<Pubkey>,
+    owner: Option<Pubkey>,
 ) -> Result<InitializeAccountEvent, &'static str> {
+    check_accounts(instruction, if owner.is_some() { 2 } else { 3 })?;
     let address = &instruction.accounts()[0];
-    let token_account = context.get_token_account(address).unwrap();
+    let account = match context.get_token_account(address) {
+        Some(token_account) => token_account.into(),
+        None => TokenAccount {
+            address: address.to_string(),
+            mint: instruction.accounts()[1].to_string(),
+            owner: match owner {
+                Some(owner) => owner.to_string(),
+                None => instruction.accounts()[2].to_string(),
+            },
+            pre_balance: None,
+            post_balance: None,
+        },
+    };
 
     Ok(InitializeAccountEvent {
-        account: Some(token_account.into())
+        account: Some(account)
     })
 }
 
@@ -203,9 +274,10 @@ fn _parse_initialize_multisig_instruction(
     m: u8,
     rent_sysvar_account: bool,
 ) -> Result<InitializeMultisigEvent, &'static str> {
+    let delta = if rent_sysvar_account { 2 } else { 1 };
+    check_accounts(instruction, delta)?;
     let multisig = instruction.accounts()[0].to_string();
     let mut signers: Vec<String> = Vec::new();
-    let delta = if rent_sysvar_account { 2 } else { 1 };
     for account in instruction.accounts()[delta..].iter() {
         signers.push(account.to_string());
     }
@@ -222,17 +294,35 @@ fn _parse_transfer_instruction(
     context: &TransactionContext,
     amount: u64,
     expected_decimals: Option<u8>,
+    fee: Option<u64>,
 ) -> Result<TransferEvent, &'static str> {
     let delta: usize = if expected_decimals.is_none() { 0 } else { 1 };
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
-    let destination = context.get_token_account(&instruction.accounts()[1 + delta]).unwrap();
+    check_accounts(instruction, 3 + delta)?;
+    let source = context.get_token_account(&instruction.accounts()[0]).ok_or("Token account not found in the transaction.")?;
+    let destination = context.get_token_account(&instruction.accounts()[1 + delta]).ok_or("Token account not found in the transaction.")?;
     let authority = instruction.accounts()[2 + delta].to_string();
+    let multisig_signers = get_multisig_signers(instruction, 2 + delta);
+
+    // Token-2022 only states the withheld fee in TransferCheckedWithFee. Other transfers of a mint with a
+    // transfer fee still withhold it, so their fee is left unset here and derived from the token balances
+    // in `parse_transaction` when possible.
+    let fee_withheld = match fee {
+        Some(fee) => Some(fee),
+        None if instruction.program_id() == TOKEN_2022_PROGRAM_ID => None,
+        None => Some(0),
+    };
+    let net_amount = fee_withheld.map(|fee_withheld| amount.saturating_sub(fee_withheld));
 
     Ok(TransferEvent {
         source: Some(source.into()),
         destination: Some(destination.into()),
         amount,
         authority,
+        fee_withheld,
+        net_amount,
+        decimals: expected_decimals.map(|x| x as u32),
+        ui_amount: None,
+        multisig_signers,
     })
 }
 
@@ -243,13 +333,18 @@ fn _parse_approve_instruction(
     expected_decimals: Option<u8>,
 ) -> Result<ApproveEvent, &'static str> {
     let delta: usize = if expected_decimals.is_none() { 0 } else { 1 };
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
+    check_accounts(instruction, 3 + delta)?;
+    let source = context.get_token_account(&instruction.accounts()[0]).ok_or("Token account not found in the transaction.")?;
     let delegate = instruction.accounts()[1 + delta].to_string();
+    let owner = instruction.accounts()[2 + delta].to_string();
+    let multisig_signers = get_multisig_signers(instruction, 2 + delta);
 
     Ok(ApproveEvent {
         source: Some(source.into()),
         delegate,
         amount,
+        owner,
+        multisig_signers,
     })
 }
 
@@ -257,7 +352,8 @@ fn _parse_revoke_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
 ) -> Result<RevokeEvent, &'static str> {
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
+    check_accounts(instruction, 1)?;
+    let source = context.get_token_account(&instruction.accounts()[0]).ok_or("Token account not found in the transaction.")?;
 
     Ok(RevokeEvent {
         source: Some(source.into()),
@@ -270,6 +366,7 @@ fn _parse_set_authority_instruction(
     authority_type: utils::spl_token::AuthorityType,
     new_authority: Option<Pubkey>,
 ) -> Result<SetAuthorityEvent, &'static str> {
+    check_accounts(instruction, 2)?;
     let mint = instruction.accounts()[0].to_string();
     let authority = instruction.accounts()[1].to_string();
     let authority_type: i32 = match authority_type {
@@ -292,16 +389,22 @@ fn _parse_mint_to_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
     amount: u64,
+    decimals: Option<u8>,
 ) -> Result<MintToEvent, &'static str> {
+    check_accounts(instruction, 3)?;
     let mint = instruction.accounts()[0].to_string();
-    let destination = context.get_token_account(&instruction.accounts()[1]).unwrap();
+    let destination = context.get_token_account(&instruction.accounts()[1]).ok_or("Token account not found in the transaction.")?;
     let mint_authority = instruction.accounts()[2].to_string();
+    let multisig_signers = get_multisig_signers(instruction, 2);
 
     Ok(MintToEvent {
         mint,
         destination: Some(destination.into()),
         mint_authority,
         amount,
+        decimals: decimals.map(|x| x as u32),
+        ui_amount: None,
+        multisig_signers,
     })
 }
 
@@ -309,15 +412,21 @@ fn _parse_burn_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
     amount: u64,
+    decimals: Option<u8>,
 ) -> Result<BurnEvent, &'static str> {
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
+    check_accounts(instruction, 3)?;
+    let source = context.get_token_account(&instruction.accounts()[0]).ok_or("Token account not found in the transaction.")?;
     let _mint = instruction.accounts()[1].to_string();
     let authority = instruction.accounts()[2].to_string();
+    let multisig_signers = get_multisig_signers(instruction, 2);
 
     Ok(BurnEvent {
         source: Some(source.into()),
         authority,
         amount,
+        decimals: decimals.map(|x| x as u32),
+        ui_amount: None,
+        multisig_signers,
     })
 }
 
@@ -325,7 +434,8 @@ fn _parse_close_account_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
 ) -> Result<CloseAccountEvent, &'static str> {
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
+    check_accounts(instruction, 2)?;
+    let source = context.get_token_account(&instruction.accounts()[0]).ok_or("Token account not found in the transaction.")?;
     let destination = instruction.accounts()[1].to_string();
 
     Ok(CloseAccountEvent {
@@ -338,7 +448,8 @@ fn _parse_freeze_account_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
 ) -> Result<FreezeAccountEvent, &'static str> {
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
+    check_accounts(instruction, 2)?;
+    let source = context.get_token_account(&instruction.accounts()[0]).ok_or("Token account not found in the transaction.")?;
     let freeze_authority = instruction.accounts()[1].to_string();
 
     Ok(FreezeAccountEvent {
@@ -351,7 +462,8 @@ fn _parse_thaw_account_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
 ) -> Result<ThawAccountEvent, &'static str> {
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
+    check_accounts(instruction, 2)?;
+    let source = context.get_token_account(&instruction.accounts()[0]).ok_or("Token account not found in the transaction.")?;
     let freeze_authority = instruction.accounts()[1].to_string();
 
     Ok(ThawAccountEvent {
@@ -364,7 +476,8 @@ fn _parse_initialize_immutable_owner_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
 ) -> Result<InitializeImmutableOwnerEvent, &'static str> {
-    let account = context.get_token_account(&instruction.accounts()[0]).unwrap();
+    check_accounts(instruction, 1)?;
+    let account = context.get_token_account(&instruction.accounts()[0]).ok_or("Token account not found in the transaction.")?;
 
     Ok(InitializeImmutableOwnerEvent {
         account: Some(account.into()),
@@ -375,13 +488,395 @@ fn _parse_sync_native_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
 ) -> Result<SyncNativeEvent, &'static str> {
-    let account = context.get_token_account(&instruction.accounts()[0]).unwrap();
+    check_accounts(instruction, 1)?;
+    let account = context.get_token_account(&instruction.accounts()[0]).ok_or("Token account not found in the transaction.")?;
 
     Ok(SyncNativeEvent {
         account: Some(account.into())
     })
 }
 
+fn parse_token_2022_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<Option<Event>, Error> {
+    let unpacked = Token2022Instruction::unpack(&instruction.data())
+        .map_err(|x| anyhow!(x).context("Failed to unpack Token-2022 instruction"))?;
+    match unpacked {
+        Token2022Instruction::TransferCheckedWithFee { amount, decimals, fee } => {
+            let event = _parse_transfer_instruction(instruction, context, amount, Some(decimals), Some(fee));
+            event.map(|x| Some(Event::Transfer(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::InitializeTransferFeeConfig { transfer_fee_config_authority, withdraw_withheld_authority, transfer_fee_basis_points, maximum_fee } => {
+            let event = _parse_initialize_transfer_fee_config_instruction(instruction, context, transfer_fee_config_authority, withdraw_withheld_authority, transfer_fee_basis_points, maximum_fee);
+            event.map(|x| Some(Event::InitializeTransferFeeConfig(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::SetTransferFee { transfer_fee_basis_points, maximum_fee } => {
+            let event = _parse_set_transfer_fee_instruction(instruction, context, transfer_fee_basis_points, maximum_fee);
+            event.map(|x| Some(Event::SetTransferFee(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::WithdrawWithheldTokensFromMint => {
+            let event = _parse_withdraw_withheld_tokens_instruction(instruction, context, 0);
+            event.map(|x| Some(Event::WithdrawWithheldTokens(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::WithdrawWithheldTokensFromAccounts { num_token_accounts } => {
+            let event = _parse_withdraw_withheld_tokens_instruction(instruction, context, num_token_accounts as usize);
+            event.map(|x| Some(Event::WithdrawWithheldTokens(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::HarvestWithheldTokensToMint => {
+            let event = _parse_harvest_withheld_tokens_instruction(instruction, context);
+            event.map(|x| Some(Event::HarvestWithheldTokens(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::InitializeInterestBearingMint { rate_authority, rate } => {
+            let event = _parse_interest_bearing_mint_instruction(instruction, context, Some(rate_authority), rate);
+            event.map(|x| Some(Event::InterestBearingMint(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::UpdateInterestRate { rate } => {
+            let event = _parse_interest_bearing_mint_instruction(instruction, context, None, rate);
+            event.map(|x| Some(Event::InterestBearingMint(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::InitializeMetadataPointer { authority, metadata_address } => {
+            let event = _parse_metadata_pointer_instruction(instruction, context, Some(authority), metadata_address);
+            event.map(|x| Some(Event::MetadataPointer(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::UpdateMetadataPointer { metadata_address } => {
+            let event = _parse_metadata_pointer_instruction(instruction, context, None, metadata_address);
+            event.map(|x| Some(Event::MetadataPointer(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::InitializeTokenMetadata { name, symbol, uri } => {
+            let event = _parse_initialize_token_metadata_instruction(instruction, context, name, symbol, uri);
+            event.map(|x| Some(Event::InitializeTokenMetadata(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::UpdateTokenMetadataField { field, value } => {
+            let event = _parse_update_token_metadata_field_instruction(instruction, context, field.name(), value);
+            event.map(|x| Some(Event::UpdateTokenMetadataField(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::RemoveTokenMetadataKey { idempotent, key } => {
+            let event = _parse_remove_token_metadata_key_instruction(instruction, context, idempotent, key);
+            event.map(|x| Some(Event::RemoveTokenMetadataKey(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::UpdateTokenMetadataAuthority { new_authority } => {
+            let event = _parse_update_token_metadata_authority_instruction(instruction, context, new_authority);
+            event.map(|x| Some(Event::UpdateTokenMetadataAuthority(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::InitializeTransferHook { authority, program_id } => {
+            let event = _parse_transfer_hook_instruction(instruction, context, Some(authority), program_id);
+            event.map(|x| Some(Event::TransferHook(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::UpdateTransferHook { program_id } => {
+            let event = _parse_transfer_hook_instruction(instruction, context, None, program_id);
+            event.map(|x| Some(Event::TransferHook(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::Confidential { extension, instruction: confidential_instruction } => {
+            let event = _parse_confidential_transfer_instruction(instruction, context, extension, confidential_instruction);
+            event.map(|x| Some(Event::ConfidentialTransfer(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::InitializeDefaultAccountState { state } => {
+            let event = _parse_default_account_state_instruction(instruction, context, state, true);
+            event.map(|x| Some(Event::DefaultAccountState(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::UpdateDefaultAccountState { state } => {
+            let event = _parse_default_account_state_instruction(instruction, context, state, false);
+            event.map(|x| Some(Event::DefaultAccountState(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::EnableCpiGuard => {
+            let event = _parse_cpi_guard_instruction(instruction, context, true);
+            event.map(|x| Some(Event::CpiGuard(x))).map_err(|x| anyhow!(x))
+        },
+        Token2022Instruction::DisableCpiGuard => {
+            let event = _parse_cpi_guard_instruction(instruction, context, false);
+            event.map(|x| Some(Event::CpiGuard(x))).map_err(|x| anyhow!(x))
+        },
+
+        Token2022Instruction::Unknown => Ok(None),
+    }.context("Failed to parse Token-2022 instruction")
+}
+
+fn _parse_initialize_transfer_fee_config_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    transfer_fee_config_authority: Option<Pubkey>,
+    withdraw_withheld_authority: Option<Pubkey>,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> Result<InitializeTransferFeeConfigEvent, &'static str> {
+    check_accounts(instruction, 1)?;
+    let mint = instruction.accounts()[0].to_string();
+
+    Ok(InitializeTransferFeeConfigEvent {
+        mint,
+        transfer_fee_config_authority: transfer_fee_config_authority.map(|x| x.to_string()),
+        withdraw_withheld_authority: withdraw_withheld_authority.map(|x| x.to_string()),
+        transfer_fee_basis_points: transfer_fee_basis_points.into(),
+        maximum_fee,
+    })
+}
+
+fn _parse_set_transfer_fee_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> Result<SetTransferFeeEvent, &'static str> {
+    check_accounts(instruction, 2)?;
+    let mint = instruction.accounts()[0].to_string();
+    let authority = instruction.accounts()[1].to_string();
+
+    Ok(SetTransferFeeEvent {
+        mint,
+        authority,
+        transfer_fee_basis_points: transfer_fee_basis_points.into(),
+        maximum_fee,
+    })
+}
+
+fn _parse_withdraw_withheld_tokens_instruction(
+    instruction: &StructuredInstruction,
+    context: &TransactionContext,
+    num_token_accounts: usize,
+) -> Result<WithdrawWithheldTokensEvent, &'static str> {
+    check_accounts(instruction, 3)?;
+    let accounts = instruction.accounts();
+    let mint = accounts[0].to_string();
+    let destination = context.get_token_account(&accounts[1]);
+    let authority = accounts[2].to_string();
+    // Multisig signers sit between the authority and the source accounts.
+    let sources_start = accounts.len().checked_sub(num_token_accounts).ok_or("Invalid number of source accounts.")?;
+    let sources = accounts[sources_start.max(3)..].iter().map(|x| x.to_string()).collect();
+
+    Ok(WithdrawWithheldTokensEvent {
+        mint,
+        destination: destination.map(|x| x.into()),
+        authority,
+        sources,
+    })
+}
+
+fn _parse_harvest_withheld_tokens_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<HarvestWithheldTokensEvent, &'static str> {
+    check_accounts(instruction, 1)?;
+    let mint = instruction.accounts()[0].to_string();
+    let sources = instruction.accounts()[1..].iter().map(|x| x.to_string()).collect();
+
+    Ok(HarvestWithheldTokensEvent {
+        mint,
+        sources,
+    })
+}
+
+fn _parse_interest_bearing_mint_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    rate_authority: Option<Option<Pubkey>>,
+    rate: i16,
+) -> Result<InterestBearingMintEvent, &'static str> {
+    check_accounts(instruction, if rate_authority.is_some() { 1 } else { 2 })?;
+    let mint = instruction.accounts()[0].to_string();
+    let initialize = rate_authority.is_some();
+    let rate_authority = match rate_authority {
+        Some(rate_authority) => rate_authority.map(|x| x.to_string()),
+        None => Some(instruction.accounts()[1].to_string()),
+    };
+
+    Ok(InterestBearingMintEvent {
+        mint,
+        rate_authority,
+        rate: rate.into(),
+        initialize,
+    })
+}
+
+fn _parse_metadata_pointer_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    authority: Option<Option<Pubkey>>,
+    metadata_address: Option<Pubkey>,
+) -> Result<MetadataPointerEvent, &'static str> {
+    check_accounts(instruction, if authority.is_some() { 1 } else { 2 })?;
+    let mint = instruction.accounts()[0].to_string();
+    let initialize = authority.is_some();
+    let authority = match authority {
+        Some(authority) => authority.map(|x| x.to_string()),
+        None => Some(instruction.accounts()[1].to_string()),
+    };
+
+    Ok(MetadataPointerEvent {
+        mint,
+        authority,
+        metadata_address: metadata_address.map(|x| x.to_string()),
+        initialize,
+    })
+}
+
+fn _parse_initialize_token_metadata_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<InitializeTokenMetadataEvent, &'static str> {
+    check_accounts(instruction, 4)?;
+    let metadata = instruction.accounts()[0].to_string();
+    let update_authority = instruction.accounts()[1].to_string();
+    let mint = instruction.accounts()[2].to_string();
+    let mint_authority = instruction.accounts()[3].to_string();
+
+    Ok(InitializeTokenMetadataEvent {
+        metadata,
+        update_authority,
+        mint,
+        mint_authority,
+        name,
+        symbol,
+        uri,
+    })
+}
+
+fn _parse_update_token_metadata_field_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    field: String,
+    value: String,
+) -> Result<UpdateTokenMetadataFieldEvent, &'static str> {
+    check_accounts(instruction, 2)?;
+    let metadata = instruction.accounts()[0].to_string();
+    let update_authority = instruction.accounts()[1].to_string();
+
+    Ok(UpdateTokenMetadataFieldEvent {
+        metadata,
+        update_authority,
+        field,
+        value,
+    })
+}
+
+fn _parse_remove_token_metadata_key_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    idempotent: bool,
+    key: String,
+) -> Result<RemoveTokenMetadataKeyEvent, &'static str> {
+    check_accounts(instruction, 2)?;
+    let metadata = instruction.accounts()[0].to_string();
+    let update_authority = instruction.accounts()[1].to_string();
+
+    Ok(RemoveTokenMetadataKeyEvent {
+        metadata,
+        update_authority,
+        key,
+        idempotent,
+    })
+}
+
+fn _parse_update_token_metadata_authority_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    new_authority: Option<Pubkey>,
+) -> Result<UpdateTokenMetadataAuthorityEvent, &'static str> {
+    check_accounts(instruction, 2)?;
+    let metadata = instruction.accounts()[0].to_string();
+    let update_authority = instruction.accounts()[1].to_string();
+
+    Ok(UpdateTokenMetadataAuthorityEvent {
+        metadata,
+        update_authority,
+        new_authority: new_authority.map(|x| x.to_string()),
+    })
+}
+
+fn _parse_transfer_hook_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    authority: Option<Option<Pubkey>>,
+    program_id: Option<Pubkey>,
+) -> Result<TransferHookEvent, &'static str> {
+    check_accounts(instruction, if authority.is_some() { 1 } else { 2 })?;
+    let mint = instruction.accounts()[0].to_string();
+    let initialize = authority.is_some();
+    let authority = match authority {
+        Some(authority) => authority.map(|x| x.to_string()),
+        None => Some(instruction.accounts()[1].to_string()),
+    };
+
+    Ok(TransferHookEvent {
+        mint,
+        authority,
+        program_id: program_id.map(|x| x.to_string()),
+        initialize,
+    })
+}
+
+fn _parse_confidential_transfer_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    extension: token_2022::instruction::ConfidentialExtension,
+    confidential_instruction: u8,
+) -> Result<ConfidentialTransferEvent, &'static str> {
+    check_accounts(instruction, 1)?;
+    let account = instruction.accounts()[0].to_string();
+    let extension: i32 = match extension {
+        token_2022::instruction::ConfidentialExtension::ConfidentialTransfer => ConfidentialExtension::ConfidentialTransfer.into(),
+        token_2022::instruction::ConfidentialExtension::ConfidentialTransferFee => ConfidentialExtension::ConfidentialTransferFee.into(),
+        token_2022::instruction::ConfidentialExtension::ConfidentialMintBurn => ConfidentialExtension::ConfidentialMintBurn.into(),
+    };
+
+    Ok(ConfidentialTransferEvent {
+        extension,
+        instruction: confidential_instruction.into(),
+        account,
+    })
+}
+
+fn _parse_default_account_state_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    state: u8,
+    initialize: bool,
+) -> Result<DefaultAccountStateEvent, &'static str> {
+    check_accounts(instruction, if initialize { 1 } else { 2 })?;
+    let mint = instruction.accounts()[0].to_string();
+    let freeze_authority = if initialize { None } else { Some(instruction.accounts()[1].to_string()) };
+    let state: i32 = match state {
+        0 => AccountState::Uninitialized.into(),
+        1 => AccountState::Initialized.into(),
+        2 => AccountState::Frozen.into(),
+        _ => return Err("Invalid account state."),
+    };
+
+    Ok(DefaultAccountStateEvent {
+        mint,
+        state,
+        freeze_authority,
+        initialize,
+    })
+}
+
+fn _parse_cpi_guard_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    enabled: bool,
+) -> Result<CpiGuardEvent, &'static str> {
+    check_accounts(instruction, 2)?;
+    let account = instruction.accounts()[0].to_string();
+    let owner = instruction.accounts()[1].to_string();
+
+    Ok(CpiGuardEvent {
+        account,
+        owner,
+        enabled,
+    })
+}
+
 pub fn parse_initialize_mint_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
@@ -514,6 +1009,60 @@ pub fn parse_initialize_immutable_owner_instruction<'a>(
     }
 }
 
+/// Fails on instructions with fewer accounts than the parser reads, rather than panicking on them.
+fn check_accounts(instruction: &StructuredInstruction, count: usize) -> Result<(), &'static str> {
+    if instruction.accounts().len() < count {
+        return Err("Missing instruction accounts.");
+    }
+    Ok(())
+}
+
+/// Signer accounts following the authority, present when the authority is a multisig.
+fn get_multisig_signers(instruction: &StructuredInstruction, authority_index: usize) -> Vec<String> {
+    instruction.accounts().iter().skip(authority_index + 1).map(|x| x.to_string()).collect()
+}
+
+/// Keeps the multisig signers that are flagged as signers, i.e. that signed the transaction, up to the first
+/// one that isn't: Token-2022 appends the transfer hook accounts after the signers. An authority that signed
+/// the transaction itself isn't a multisig, so every trailing account belongs to the hook.
+///
+/// Inner instructions don't carry signer flags, so co-signers signing through CPI are left out.
+fn retain_multisig_signers(event: &mut Event, signers: &[String]) {
+    let (authority, multisig_signers) = match event {
+        Event::Transfer(transfer) => (&transfer.authority, &mut transfer.multisig_signers),
+        Event::Approve(approve) => (&approve.owner, &mut approve.multisig_signers),
+        Event::MintTo(mint_to) => (&mint_to.mint_authority, &mut mint_to.multisig_signers),
+        Event::Burn(burn) => (&burn.authority, &mut burn.multisig_signers),
+        _ => return,
+    };
+    if signers.contains(authority) {
+        multisig_signers.clear();
+        return;
+    }
+    let count = multisig_signers.iter().take_while(|x| signers.contains(x)).count();
+    multisig_signers.truncate(count);
+}
+
+fn get_transaction_signers(transaction: &ConfirmedTransaction) -> Vec<String> {
+    let num_required_signatures = transaction
+        .transaction
+        .as_ref()
+        .and_then(|x| x.message.as_ref())
+        .and_then(|x| x.header.as_ref())
+        .map_or(0, |x| x.num_required_signatures as usize);
+    transaction
+        .resolved_accounts()
+        .iter()
+        .take(num_required_signatures)
+        .map(|x| bs58::encode(x).into_string())
+        .collect()
+}
+
+/// Whether the program is either the Token program or Token-2022, which share their base instruction set.
+pub fn is_token_program<P: PartialEq<Pubkey>>(program_id: P) -> bool {
+    program_id == TOKEN_PROGRAM_ID || program_id == TOKEN_2022_PROGRAM_ID
+}
+
 impl<'a> From<&'a utils::spl_token::TokenAccount<'a>> for TokenAccount {
     fn from(value: &'a utils::spl_token::TokenAccount<'a>) -> Self {
         Self {
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use substreams::errors::Error;
+use substreams::scalar::BigInt;
+use substreams::store::{StoreAdd, StoreAddBigInt, StoreGet, StoreGetBigInt, StoreGetString, StoreNew, StoreSetString};
+
+use crate::fields::{get_field, set_field};
+use crate::pb::spl_token::*;
+use crate::pb::spl_token::spl_token_event::Event;
+
+const DECIMALS: &str = "decimals";
+const MINT_AUTHORITY: &str = "mint_authority";
+const FREEZE_AUTHORITY: &str = "freeze_authority";
+
+/// Net supply of every mint. Only exact for mints initialized after the start block.
+#[substreams::handlers::store]
+fn store_mint_supply(events: SplTokenBlockEvents, store: StoreAddBigInt) {
+    for transaction in events.transactions.iter() {
+        for event in transaction.events.iter() {
+            match &event.event {
+                Some(Event::MintTo(mint_to)) => {
+                    store.add(0, &mint_to.mint, BigInt::from(mint_to.amount));
+                },
+                Some(Event::Burn(burn)) => {
+                    if let Some(source) = &burn.source {
+                        store.add(0, &source.mint, BigInt::zero() - BigInt::from(burn.amount));
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Decimals and authorities of every mint, kept one field per key (see [`crate::fields`]). Unset authorities
+/// are stored as empty strings, so a missing key means the mint wasn't seen yet.
+#[substreams::handlers::store]
+fn store_mint_authorities(events: SplTokenBlockEvents, store: StoreSetString) {
+    for transaction in events.transactions.iter() {
+        for event in transaction.events.iter() {
+            match &event.event {
+                Some(Event::InitializeMint(initialize_mint)) => {
+                    let mint = &initialize_mint.mint;
+                    set_field(&store, mint, DECIMALS, &initialize_mint.decimals.to_string());
+                    set_field(&store, mint, MINT_AUTHORITY, &initialize_mint.mint_authority);
+                    set_field(&store, mint, FREEZE_AUTHORITY, &initialize_mint.freeze_authority.clone().unwrap_or_default());
+                },
+                Some(Event::SetAuthority(set_authority)) => {
+                    let field = match set_authority.authority_type() {
+                        AuthorityType::MintTokens => MINT_AUTHORITY,
+                        AuthorityType::FreezeAccount => FREEZE_AUTHORITY,
+                        _ => continue,
+                    };
+                    let new_authority = set_authority.new_authority.clone().unwrap_or_default();
+                    set_field(&store, &set_authority.mint, field, &new_authority);
+                },
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Supply and authorities at the end of the block of every mint touched by an `InitializeMint`,
+/// `MintTo`, `Burn` or mint `SetAuthority` event.
+#[substreams::handlers::map]
+fn spl_token_mint_supply(
+    events: SplTokenBlockEvents,
+    supply: StoreGetBigInt,
+    authorities: StoreGetString,
+) -> Result<SplTokenBlockMintSupply, Error> {
+    // Minted and burned amounts in the block, keyed by mint, and the mints in order of appearance.
+    let mut amounts: HashMap<String, (u64, u64)> = HashMap::new();
+    let mut mints: Vec<String> = Vec::new();
+    for transaction in events.transactions.iter() {
+        for event in transaction.events.iter() {
+            let (mint, minted, burned) = match &event.event {
+                Some(Event::InitializeMint(initialize_mint)) => (&initialize_mint.mint, 0, 0),
+                Some(Event::MintTo(mint_to)) => (&mint_to.mint, mint_to.amount, 0),
+                Some(Event::Burn(BurnEvent { source: Some(source), amount, .. })) => (&source.mint, 0, *amount),
+                Some(Event::SetAuthority(set_authority)) => match set_authority.authority_type() {
+                    AuthorityType::MintTokens | AuthorityType::FreezeAccount => (&set_authority.mint, 0, 0),
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            if !amounts.contains_key(mint) {
+                mints.push(mint.clone());
+            }
+            let entry = amounts.entry(mint.clone()).or_default();
+            entry.0 = entry.0.saturating_add(minted);
+            entry.1 = entry.1.saturating_add(burned);
+        }
+    }
+
+    let mints = mints
+        .into_iter()
+        .map(|mint| {
+            let (minted, burned) = amounts[&mint];
+            MintSupply {
+                state: Some(get_mint_state(&supply, &authorities, &mint)),
+                minted,
+                burned,
+            }
+        })
+        .collect();
+    Ok(SplTokenBlockMintSupply { mints })
+}
+
+/// Reads a mint from `store_mint_supply` and `store_mint_authorities`.
+pub fn get_mint_state(supply: &StoreGetBigInt, authorities: &StoreGetString, mint: &str) -> MintState {
+    let decimals = get_field(authorities, mint, DECIMALS);
+    let mint_authority = get_field(authorities, mint, MINT_AUTHORITY);
+    let freeze_authority = get_field(authorities, mint, FREEZE_AUTHORITY);
+
+    MintState {
+        mint: mint.to_string(),
+        decimals: decimals.as_ref().and_then(|x| x.parse().ok()),
+        // Burns of supply minted before the start block can make the net supply negative.
+        supply: supply.get_last(mint).and_then(|x| x.to_string().parse().ok()).unwrap_or(0),
+        initialized: decimals.is_some(),
+        mint_authority_revoked: mint_authority.as_ref().is_some_and(|x| x.is_empty()),
+        freeze_authority_revoked: freeze_authority.as_ref().is_some_and(|x| x.is_empty()),
+        mint_authority: mint_authority.filter(|x| !x.is_empty()),
+        freeze_authority: freeze_authority.filter(|x| !x.is_empty()),
+    }
+}
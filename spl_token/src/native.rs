@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+
+use substreams_solana_utils as utils;
+use utils::instruction::StructuredInstruction;
+use utils::system_program::{SystemInstruction, SYSTEM_PROGRAM_ID};
+
+use crate::pb::spl_token::*;
+
+/// Wrapped SOL mint of the Token program.
+pub const NATIVE_MINT: &str = "So11111111111111111111111111111111111111112";
+/// Wrapped SOL mint of Token-2022.
+pub const NATIVE_MINT_2022: &str = "9pan9bMn5HatX4EJdBwg9VgCa7Uz5HL8N1m5D3NdXejP";
+
+/// Rent parameters of the cluster, from which the rent-exempt reserve of an account is derived.
+const ACCOUNT_STORAGE_OVERHEAD: u64 = 128;
+const LAMPORTS_PER_BYTE_YEAR: u64 = 3480;
+const EXEMPTION_THRESHOLD_YEARS: u64 = 2;
+
+pub fn is_native_mint(mint: &str) -> bool {
+    mint == NATIVE_MINT || mint == NATIVE_MINT_2022
+}
+
+/// Minimum balance for an account of `space` bytes to be rent exempt.
+pub fn get_rent_exempt_reserve(space: u64) -> u64 {
+    (ACCOUNT_STORAGE_OVERHEAD + space) * LAMPORTS_PER_BYTE_YEAR * EXEMPTION_THRESHOLD_YEARS
+}
+
+/// Lamports sent to accounts by System transfers and account creations, which `SyncNative` or the
+/// initialization of a native mint account turn into wrapped SOL, along with the rent-exempt reserve of
+/// native mint accounts, which closing them returns on top of the wrapped amount.
+#[derive(Default)]
+pub struct PendingLamports {
+    transfers: HashMap<String, (u64, String)>,
+    rent_exempt_reserves: HashMap<String, u64>,
+}
+
+impl PendingLamports {
+    /// Reserves of the native mint accounts that exist before the transaction, i.e. their lamports beyond
+    /// the wrapped amount.
+    pub fn new(transaction: &ConfirmedTransaction) -> Self {
+        let mut pending = Self::default();
+        let meta = match transaction.meta.as_ref() {
+            Some(meta) => meta,
+            None => return pending,
+        };
+        let accounts = transaction.resolved_accounts();
+        for balance in meta.pre_token_balances.iter().filter(|x| is_native_mint(&x.mint)) {
+            let index = balance.account_index as usize;
+            let amount: Option<u64> = balance.ui_token_amount.as_ref().and_then(|x| x.amount.parse().ok());
+            if let (Some(account), Some(lamports), Some(amount)) = (accounts.get(index), meta.pre_balances.get(index), amount) {
+                let reserve = lamports.saturating_sub(amount);
+                pending.rent_exempt_reserves.insert(bs58::encode(account).into_string(), reserve);
+            }
+        }
+        pending
+    }
+
+    /// Records the lamports sent by a System transfer or account creation instruction.
+    pub fn record(&mut self, instruction: &StructuredInstruction) {
+        if instruction.program_id() != SYSTEM_PROGRAM_ID || instruction.accounts().len() < 2 {
+            return;
+        }
+        let lamports = match SystemInstruction::unpack(&instruction.data()) {
+            Ok(SystemInstruction::Transfer(transfer)) => transfer.lamports,
+            Ok(SystemInstruction::CreateAccount(create_account)) => {
+                self.rent_exempt_reserves.insert(instruction.accounts()[1].to_string(), get_rent_exempt_reserve(create_account.space));
+                create_account.lamports
+            },
+            Ok(SystemInstruction::CreateAccountWithSeed(create_account)) => {
+                self.rent_exempt_reserves.insert(instruction.accounts()[1].to_string(), get_rent_exempt_reserve(create_account.space));
+                create_account.lamports
+            },
+            _ => return,
+        };
+        let source = instruction.accounts()[0].to_string();
+        let entry = self.transfers.entry(instruction.accounts()[1].to_string()).or_insert((0, source.clone()));
+        entry.0 = entry.0.saturating_add(lamports);
+        entry.1 = source;
+    }
+
+    /// Takes the lamports sent to the account since it was last synced, and the last sender.
+    pub fn take(&mut self, account: &str) -> Option<(u64, String)> {
+        self.transfers.remove(account)
+    }
+
+    /// Rent-exempt reserve of a native mint account, if it was created in the transaction or existed before it.
+    pub fn get_rent_exempt_reserve(&self, account: &str) -> Option<u64> {
+        self.rent_exempt_reserves.get(account).copied()
+    }
+}
+
+/// Wrap of native SOL into a native mint account, from the `SyncNative` that follows the System
+/// transfers into it. The amount falls back to the account balance change if no transfer was seen.
+pub fn parse_wrap_sol(sync_native: &SyncNativeEvent, pending: &mut PendingLamports) -> Option<WrapSolEvent> {
+    let account = sync_native.account.as_ref()?;
+    if !is_native_mint(&account.mint) {
+        return None;
+    }
+    let (amount, source) = match pending.take(&account.address) {
+        Some((lamports, source)) => (lamports, Some(source)),
+        None => {
+            let amount = account.post_balance?.checked_sub(account.pre_balance.unwrap_or(0))?;
+            (amount, None)
+        },
+    };
+    if amount == 0 {
+        return None;
+    }
+
+    Some(WrapSolEvent {
+        account: Some(account.clone()),
+        source,
+        amount,
+    })
+}
+
+/// Wrap of native SOL by initializing a native mint account, which wraps the lamports it holds beyond
+/// its rent-exempt reserve without a `SyncNative`.
+pub fn parse_initialize_wrap_sol(initialize_account: &InitializeAccountEvent, pending: &mut PendingLamports) -> Option<WrapSolEvent> {
+    let account = initialize_account.account.as_ref()?;
+    if !is_native_mint(&account.mint) {
+        return None;
+    }
+    let rent_exempt_reserve = pending.get_rent_exempt_reserve(&account.address)?;
+    let (lamports, source) = pending.take(&account.address)?;
+    let amount = lamports.checked_sub(rent_exempt_reserve)?;
+    if amount == 0 {
+        return None;
+    }
+
+    Some(WrapSolEvent {
+        account: Some(account.clone()),
+        source: Some(source),
+        amount,
+    })
+}
+
+/// Unwrap of a native mint account by `CloseAccount`, which returns its balance as native SOL.
+pub fn parse_unwrap_sol(close_account: &CloseAccountEvent, pending: &PendingLamports) -> Option<UnwrapSolEvent> {
+    let account = close_account.source.as_ref()?;
+    if !is_native_mint(&account.mint) {
+        return None;
+    }
+    let amount = account.pre_balance.or(account.post_balance).unwrap_or(0);
+    let lamports = pending.get_rent_exempt_reserve(&account.address).map(|x| amount.saturating_add(x));
+
+    Some(UnwrapSolEvent {
+        account: Some(account.clone()),
+        destination: close_account.destination.clone(),
+        amount,
+        lamports,
+    })
+}
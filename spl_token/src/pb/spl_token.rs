@@ -15,8 +15,153 @@ pub struct SplTokenTransactionEvents {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SplTokenBlockBalanceChanges {
+    #[prost(message, repeated, tag="1")]
+    pub transactions: ::prost::alloc::vec::Vec<SplTokenTransactionBalanceChanges>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SplTokenTransactionBalanceChanges {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="2")]
+    pub changes: ::prost::alloc::vec::Vec<TokenBalanceChange>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenBalanceChange {
+    #[prost(string, tag="1")]
+    pub account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub program_id: ::prost::alloc::string::String,
+    #[prost(uint32, tag="5")]
+    pub decimals: u32,
+    #[prost(uint64, tag="6")]
+    pub pre_amount: u64,
+    #[prost(uint64, tag="7")]
+    pub post_amount: u64,
+    #[prost(int64, tag="8")]
+    pub delta: i64,
+    /// Delta implied by the decoded transfers, mints and burns. Unset if the transaction couldn't be decoded.
+    #[prost(int64, optional, tag="9")]
+    pub instruction_delta: ::core::option::Option<i64>,
+    /// Whether the instruction delta accounts for the whole balance change.
+    #[prost(bool, tag="10")]
+    pub reconciled: bool,
+}
+/// Token account state as tracked by `store_token_accounts`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenAccountState {
+    #[prost(string, tag="1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="4")]
+    pub delegate: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="5")]
+    pub close_authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, tag="6")]
+    pub closed: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SplTokenBlockMintSupply {
+    #[prost(message, repeated, tag="1")]
+    pub mints: ::prost::alloc::vec::Vec<MintSupply>,
+}
+/// Supply snapshot of a mint at the end of a block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MintSupply {
+    #[prost(message, optional, tag="1")]
+    pub state: ::core::option::Option<MintState>,
+    /// Amounts minted and burned in the block.
+    #[prost(uint64, tag="2")]
+    pub minted: u64,
+    #[prost(uint64, tag="3")]
+    pub burned: u64,
+}
+/// Mint state as tracked by `store_mint_supply` and `store_mint_authorities`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MintState {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(uint32, optional, tag="2")]
+    pub decimals: ::core::option::Option<u32>,
+    /// Net supply since the start block, only exact if the mint was initialized after it.
+    #[prost(uint64, tag="3")]
+    pub supply: u64,
+    /// Whether the mint was initialized after the start block.
+    #[prost(bool, tag="4")]
+    pub initialized: bool,
+    #[prost(string, optional, tag="5")]
+    pub mint_authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="6")]
+    pub freeze_authority: ::core::option::Option<::prost::alloc::string::String>,
+    /// Whether the authority is known to be unset, either revoked or never set.
+    #[prost(bool, tag="7")]
+    pub mint_authority_revoked: bool,
+    #[prost(bool, tag="8")]
+    pub freeze_authority_revoked: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SplTokenBlockTopMovers {
+    #[prost(message, repeated, tag="1")]
+    pub mints: ::prost::alloc::vec::Vec<MintTopMovers>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MintTopMovers {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    /// Largest holders among those whose balance changed in the block, by balance at the end of it.
+    #[prost(message, repeated, tag="2")]
+    pub holders: ::prost::alloc::vec::Vec<HolderBalance>,
+    /// Share of the mint supply held by these holders, unset if the supply isn't known. Holders that didn't
+    /// move in the block aren't ranked, so this isn't the share held by the mint's largest holders.
+    #[prost(double, optional, tag="3")]
+    pub supply_share: ::core::option::Option<f64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HolderBalance {
+    #[prost(string, tag="1")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub balance: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SplTokenBlockHolderChanges {
+    #[prost(message, repeated, tag="1")]
+    pub mints: ::prost::alloc::vec::Vec<HolderCountChange>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HolderCountChange {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(int64, tag="2")]
+    pub previous_holder_count: i64,
+    #[prost(int64, tag="3")]
+    pub holder_count: i64,
+    /// Herfindahl index of the holder balances at the end of the block, between 0 and 1.
+    #[prost(double, optional, tag="4")]
+    pub concentration: ::core::option::Option<f64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SplTokenEvent {
-    #[prost(oneof="spl_token_event::Event", tags="1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14")]
+    #[prost(oneof="spl_token_event::Event", tags="1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30")]
     pub event: ::core::option::Option<spl_token_event::Event>,
 }
 /// Nested message and enum types in `SplTokenEvent`.
@@ -52,6 +197,38 @@ pub mod spl_token_event {
         ThawAccount(super::ThawAccountEvent),
         #[prost(message, tag="14")]
         SyncNative(super::SyncNativeEvent),
+        #[prost(message, tag="15")]
+        InitializeTransferFeeConfig(super::InitializeTransferFeeConfigEvent),
+        #[prost(message, tag="16")]
+        SetTransferFee(super::SetTransferFeeEvent),
+        #[prost(message, tag="17")]
+        WithdrawWithheldTokens(super::WithdrawWithheldTokensEvent),
+        #[prost(message, tag="18")]
+        HarvestWithheldTokens(super::HarvestWithheldTokensEvent),
+        #[prost(message, tag="19")]
+        InterestBearingMint(super::InterestBearingMintEvent),
+        #[prost(message, tag="20")]
+        MetadataPointer(super::MetadataPointerEvent),
+        #[prost(message, tag="21")]
+        InitializeTokenMetadata(super::InitializeTokenMetadataEvent),
+        #[prost(message, tag="22")]
+        UpdateTokenMetadataField(super::UpdateTokenMetadataFieldEvent),
+        #[prost(message, tag="23")]
+        RemoveTokenMetadataKey(super::RemoveTokenMetadataKeyEvent),
+        #[prost(message, tag="24")]
+        UpdateTokenMetadataAuthority(super::UpdateTokenMetadataAuthorityEvent),
+        #[prost(message, tag="25")]
+        TransferHook(super::TransferHookEvent),
+        #[prost(message, tag="26")]
+        ConfidentialTransfer(super::ConfidentialTransferEvent),
+        #[prost(message, tag="27")]
+        DefaultAccountState(super::DefaultAccountStateEvent),
+        #[prost(message, tag="28")]
+        CpiGuard(super::CpiGuardEvent),
+        #[prost(message, tag="29")]
+        WrapSol(super::WrapSolEvent),
+        #[prost(message, tag="30")]
+        UnwrapSol(super::UnwrapSolEvent),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -93,6 +270,23 @@ pub struct TransferEvent {
     pub authority: ::prost::alloc::string::String,
     #[prost(uint64, tag="4")]
     pub amount: u64,
+    /// Token-2022 transfer fee withheld in the destination account. For Token-2022 transfers other than
+    /// TransferCheckedWithFee it's derived from the destination's balance change when no other Token
+    /// instruction of the transaction refers to the destination, and unset otherwise: the fee depends on
+    /// the mint's transfer fee config, which isn't known to the transaction.
+    #[prost(uint64, optional, tag="5")]
+    pub fee_withheld: ::core::option::Option<u64>,
+    /// Amount actually received by the destination, i.e. `amount - fee_withheld`.
+    #[prost(uint64, optional, tag="6")]
+    pub net_amount: ::core::option::Option<u64>,
+    /// Mint decimals and decimal-adjusted amount, unset if the decimals aren't known.
+    #[prost(uint32, optional, tag="7")]
+    pub decimals: ::core::option::Option<u32>,
+    #[prost(string, optional, tag="8")]
+    pub ui_amount: ::core::option::Option<::prost::alloc::string::String>,
+    /// Co-signers of the authority when it's a multisig, among the accounts that signed the transaction.
+    #[prost(string, repeated, tag="9")]
+    pub multisig_signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -103,6 +297,10 @@ pub struct ApproveEvent {
     pub delegate: ::prost::alloc::string::String,
     #[prost(uint64, tag="3")]
     pub amount: u64,
+    #[prost(string, tag="4")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="5")]
+    pub multisig_signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -133,6 +331,12 @@ pub struct MintToEvent {
     pub destination: ::core::option::Option<TokenAccount>,
     #[prost(uint64, tag="4")]
     pub amount: u64,
+    #[prost(uint32, optional, tag="5")]
+    pub decimals: ::core::option::Option<u32>,
+    #[prost(string, optional, tag="6")]
+    pub ui_amount: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="7")]
+    pub multisig_signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -143,6 +347,12 @@ pub struct BurnEvent {
     pub authority: ::prost::alloc::string::String,
     #[prost(uint64, tag="2")]
     pub amount: u64,
+    #[prost(uint32, optional, tag="4")]
+    pub decimals: ::core::option::Option<u32>,
+    #[prost(string, optional, tag="5")]
+    pub ui_amount: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="6")]
+    pub multisig_signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -178,10 +388,206 @@ pub struct InitializeImmutableOwnerEvent {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SyncNativeEvent {
-    /// TODO: amount
     #[prost(message, optional, tag="1")]
     pub account: ::core::option::Option<TokenAccount>,
 }
+/// Native SOL wrapped into a native mint account, emitted after its `SyncNativeEvent`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WrapSolEvent {
+    #[prost(message, optional, tag="1")]
+    pub account: ::core::option::Option<TokenAccount>,
+    /// Last account that sent lamports to wrap, unset if the amount was taken from balances.
+    #[prost(string, optional, tag="2")]
+    pub source: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, tag="3")]
+    pub amount: u64,
+}
+/// Native mint account closed, emitted after its `CloseAccountEvent`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnwrapSolEvent {
+    #[prost(message, optional, tag="1")]
+    pub account: ::core::option::Option<TokenAccount>,
+    #[prost(string, tag="2")]
+    pub destination: ::prost::alloc::string::String,
+    /// Wrapped amount returned as native SOL.
+    #[prost(uint64, tag="3")]
+    pub amount: u64,
+    /// Lamports returned to the destination including rent, unset if the account's rent-exempt reserve isn't
+    /// known, i.e. it was neither created in the transaction nor existed before it.
+    #[prost(uint64, optional, tag="4")]
+    pub lamports: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeTransferFeeConfigEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub transfer_fee_config_authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="3")]
+    pub withdraw_withheld_authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag="4")]
+    pub transfer_fee_basis_points: u32,
+    #[prost(uint64, tag="5")]
+    pub maximum_fee: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetTransferFeeEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(uint32, tag="3")]
+    pub transfer_fee_basis_points: u32,
+    #[prost(uint64, tag="4")]
+    pub maximum_fee: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithdrawWithheldTokensEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub destination: ::core::option::Option<TokenAccount>,
+    #[prost(string, tag="3")]
+    pub authority: ::prost::alloc::string::String,
+    /// Empty when withdrawing the fees withheld in the mint.
+    #[prost(string, repeated, tag="4")]
+    pub sources: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HarvestWithheldTokensEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="2")]
+    pub sources: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InterestBearingMintEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub rate_authority: ::core::option::Option<::prost::alloc::string::String>,
+    /// Basis points
+    #[prost(int32, tag="3")]
+    pub rate: i32,
+    #[prost(bool, tag="4")]
+    pub initialize: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MetadataPointerEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="3")]
+    pub metadata_address: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, tag="4")]
+    pub initialize: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeTokenMetadataEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub mint_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub uri: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateTokenMetadataFieldEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub update_authority: ::prost::alloc::string::String,
+    /// name, symbol, uri or an additional metadata key
+    #[prost(string, tag="3")]
+    pub field: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub value: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveTokenMetadataKeyEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(bool, tag="4")]
+    pub idempotent: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateTokenMetadataAuthorityEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub new_authority: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferHookEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="3")]
+    pub program_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, tag="4")]
+    pub initialize: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConfidentialTransferEvent {
+    #[prost(enumeration="ConfidentialExtension", tag="1")]
+    pub extension: i32,
+    #[prost(uint32, tag="2")]
+    pub instruction: u32,
+    #[prost(string, tag="3")]
+    pub account: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DefaultAccountStateEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(enumeration="AccountState", tag="2")]
+    pub state: i32,
+    #[prost(string, optional, tag="3")]
+    pub freeze_authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, tag="4")]
+    pub initialize: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CpiGuardEvent {
+    #[prost(string, tag="1")]
+    pub account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(bool, tag="3")]
+    pub enabled: bool,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TokenAccount {
@@ -231,4 +637,62 @@ impl AuthorityType {
         }
     }
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AccountState {
+    Uninitialized = 0,
+    Initialized = 1,
+    Frozen = 2,
+}
+impl AccountState {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            AccountState::Uninitialized => "Uninitialized",
+            AccountState::Initialized => "Initialized",
+            AccountState::Frozen => "Frozen",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Uninitialized" => Some(Self::Uninitialized),
+            "Initialized" => Some(Self::Initialized),
+            "Frozen" => Some(Self::Frozen),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ConfidentialExtension {
+    ConfidentialTransfer = 0,
+    ConfidentialTransferFee = 1,
+    ConfidentialMintBurn = 2,
+}
+impl ConfidentialExtension {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ConfidentialExtension::ConfidentialTransfer => "ConfidentialTransfer",
+            ConfidentialExtension::ConfidentialTransferFee => "ConfidentialTransferFee",
+            ConfidentialExtension::ConfidentialMintBurn => "ConfidentialMintBurn",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ConfidentialTransfer" => Some(Self::ConfidentialTransfer),
+            "ConfidentialTransferFee" => Some(Self::ConfidentialTransferFee),
+            "ConfidentialMintBurn" => Some(Self::ConfidentialMintBurn),
+            _ => None,
+        }
+    }
+}
 // @@protoc_insertion_point(module)
@@ -0,0 +1,359 @@
+use substreams_solana_utils::pubkey::Pubkey;
+
+/// Token-2022 instructions that are not part of the instruction set shared with the Token program.
+#[derive(Debug)]
+pub enum Token2022Instruction {
+    InitializeTransferFeeConfig {
+        transfer_fee_config_authority: Option<Pubkey>,
+        withdraw_withheld_authority: Option<Pubkey>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    },
+    TransferCheckedWithFee {
+        amount: u64,
+        decimals: u8,
+        fee: u64,
+    },
+    WithdrawWithheldTokensFromMint,
+    WithdrawWithheldTokensFromAccounts {
+        num_token_accounts: u8,
+    },
+    HarvestWithheldTokensToMint,
+    SetTransferFee {
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    },
+    Confidential {
+        extension: ConfidentialExtension,
+        instruction: u8,
+    },
+    InitializeDefaultAccountState {
+        state: u8,
+    },
+    UpdateDefaultAccountState {
+        state: u8,
+    },
+    InitializeInterestBearingMint {
+        rate_authority: Option<Pubkey>,
+        rate: i16,
+    },
+    UpdateInterestRate {
+        rate: i16,
+    },
+    EnableCpiGuard,
+    DisableCpiGuard,
+    InitializeTransferHook {
+        authority: Option<Pubkey>,
+        program_id: Option<Pubkey>,
+    },
+    UpdateTransferHook {
+        program_id: Option<Pubkey>,
+    },
+    InitializeMetadataPointer {
+        authority: Option<Pubkey>,
+        metadata_address: Option<Pubkey>,
+    },
+    UpdateMetadataPointer {
+        metadata_address: Option<Pubkey>,
+    },
+    InitializeTokenMetadata {
+        name: String,
+        symbol: String,
+        uri: String,
+    },
+    UpdateTokenMetadataField {
+        field: TokenMetadataField,
+        value: String,
+    },
+    RemoveTokenMetadataKey {
+        idempotent: bool,
+        key: String,
+    },
+    UpdateTokenMetadataAuthority {
+        new_authority: Option<Pubkey>,
+    },
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConfidentialExtension {
+    ConfidentialTransfer,
+    ConfidentialTransferFee,
+    ConfidentialMintBurn,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenMetadataField {
+    Name,
+    Symbol,
+    Uri,
+    Key(String),
+}
+
+impl TokenMetadataField {
+    pub fn name(&self) -> String {
+        match self {
+            Self::Name => "name".to_string(),
+            Self::Symbol => "symbol".to_string(),
+            Self::Uri => "uri".to_string(),
+            Self::Key(key) => key.clone(),
+        }
+    }
+}
+
+const TRANSFER_FEE_EXTENSION: u8 = 26;
+const CONFIDENTIAL_TRANSFER_EXTENSION: u8 = 27;
+const DEFAULT_ACCOUNT_STATE_EXTENSION: u8 = 28;
+const INTEREST_BEARING_MINT_EXTENSION: u8 = 33;
+const CPI_GUARD_EXTENSION: u8 = 34;
+const TRANSFER_HOOK_EXTENSION: u8 = 36;
+const CONFIDENTIAL_TRANSFER_FEE_EXTENSION: u8 = 37;
+const METADATA_POINTER_EXTENSION: u8 = 39;
+const CONFIDENTIAL_MINT_BURN_EXTENSION: u8 = 42;
+
+const INITIALIZE_TOKEN_METADATA: [u8; 8] = [210, 225, 30, 162, 88, 184, 77, 141];
+const UPDATE_TOKEN_METADATA_FIELD: [u8; 8] = [221, 233, 49, 45, 181, 202, 220, 200];
+const REMOVE_TOKEN_METADATA_KEY: [u8; 8] = [234, 18, 32, 56, 89, 141, 37, 181];
+const UPDATE_TOKEN_METADATA_AUTHORITY: [u8; 8] = [215, 228, 166, 228, 84, 100, 86, 123];
+
+impl Token2022Instruction {
+    /// Returns whether the data is a Token-2022 specific instruction, as opposed to one of the
+    /// instructions shared with the Token program.
+    pub fn is_extension(input: &[u8]) -> bool {
+        match input.first() {
+            Some(tag) => *tag > 24,
+            None => false,
+        }
+    }
+
+    pub fn unpack(input: &[u8]) -> Result<Self, &'static str> {
+        if input.len() >= 8 {
+            let (discriminator, rest) = input.split_at(8);
+            match discriminator {
+                x if x == INITIALIZE_TOKEN_METADATA => {
+                    let (name, rest) = Self::unpack_string(rest)?;
+                    let (symbol, rest) = Self::unpack_string(rest)?;
+                    let (uri, _rest) = Self::unpack_string(rest)?;
+                    return Ok(Self::InitializeTokenMetadata { name, symbol, uri });
+                },
+                x if x == UPDATE_TOKEN_METADATA_FIELD => {
+                    let (field, rest) = Self::unpack_token_metadata_field(rest)?;
+                    let (value, _rest) = Self::unpack_string(rest)?;
+                    return Ok(Self::UpdateTokenMetadataField { field, value });
+                },
+                x if x == REMOVE_TOKEN_METADATA_KEY => {
+                    let (idempotent, rest) = Self::unpack_u8(rest)?;
+                    let (key, _rest) = Self::unpack_string(rest)?;
+                    return Ok(Self::RemoveTokenMetadataKey { idempotent: idempotent != 0, key });
+                },
+                x if x == UPDATE_TOKEN_METADATA_AUTHORITY => {
+                    let (new_authority, _rest) = Self::unpack_optional_nonzero_pubkey(rest)?;
+                    return Ok(Self::UpdateTokenMetadataAuthority { new_authority });
+                },
+                _ => (),
+            }
+        }
+
+        let (&tag, rest) = input.split_first().ok_or("Invalid instruction data")?;
+        Ok(match tag {
+            TRANSFER_FEE_EXTENSION => {
+                let (&instruction, rest) = rest.split_first().ok_or("Invalid instruction data")?;
+                match instruction {
+                    0 => {
+                        let (transfer_fee_config_authority, rest) = Self::unpack_pubkey_option(rest)?;
+                        let (withdraw_withheld_authority, rest) = Self::unpack_pubkey_option(rest)?;
+                        let (transfer_fee_basis_points, rest) = Self::unpack_u16(rest)?;
+                        let (maximum_fee, _rest) = Self::unpack_u64(rest)?;
+                        Self::InitializeTransferFeeConfig {
+                            transfer_fee_config_authority,
+                            withdraw_withheld_authority,
+                            transfer_fee_basis_points,
+                            maximum_fee,
+                        }
+                    },
+                    1 => {
+                        let (amount, rest) = Self::unpack_u64(rest)?;
+                        let (decimals, rest) = Self::unpack_u8(rest)?;
+                        let (fee, _rest) = Self::unpack_u64(rest)?;
+                        Self::TransferCheckedWithFee { amount, decimals, fee }
+                    },
+                    2 => Self::WithdrawWithheldTokensFromMint,
+                    3 => {
+                        let (num_token_accounts, _rest) = Self::unpack_u8(rest)?;
+                        Self::WithdrawWithheldTokensFromAccounts { num_token_accounts }
+                    },
+                    4 => Self::HarvestWithheldTokensToMint,
+                    5 => {
+                        let (transfer_fee_basis_points, rest) = Self::unpack_u16(rest)?;
+                        let (maximum_fee, _rest) = Self::unpack_u64(rest)?;
+                        Self::SetTransferFee { transfer_fee_basis_points, maximum_fee }
+                    },
+                    _ => Self::Unknown,
+                }
+            },
+            CONFIDENTIAL_TRANSFER_EXTENSION |
+            CONFIDENTIAL_TRANSFER_FEE_EXTENSION |
+            CONFIDENTIAL_MINT_BURN_EXTENSION => {
+                let extension = match tag {
+                    CONFIDENTIAL_TRANSFER_EXTENSION => ConfidentialExtension::ConfidentialTransfer,
+                    CONFIDENTIAL_TRANSFER_FEE_EXTENSION => ConfidentialExtension::ConfidentialTransferFee,
+                    _ => ConfidentialExtension::ConfidentialMintBurn,
+                };
+                let (instruction, _rest) = Self::unpack_u8(rest)?;
+                Self::Confidential { extension, instruction }
+            },
+            DEFAULT_ACCOUNT_STATE_EXTENSION => {
+                let (instruction, rest) = Self::unpack_u8(rest)?;
+                let (state, _rest) = Self::unpack_u8(rest)?;
+                match instruction {
+                    0 => Self::InitializeDefaultAccountState { state },
+                    1 => Self::UpdateDefaultAccountState { state },
+                    _ => Self::Unknown,
+                }
+            },
+            INTEREST_BEARING_MINT_EXTENSION => {
+                let (instruction, rest) = Self::unpack_u8(rest)?;
+                match instruction {
+                    0 => {
+                        let (rate_authority, rest) = Self::unpack_optional_nonzero_pubkey(rest)?;
+                        let (rate, _rest) = Self::unpack_i16(rest)?;
+                        Self::InitializeInterestBearingMint { rate_authority, rate }
+                    },
+                    1 => {
+                        let (rate, _rest) = Self::unpack_i16(rest)?;
+                        Self::UpdateInterestRate { rate }
+                    },
+                    _ => Self::Unknown,
+                }
+            },
+            CPI_GUARD_EXTENSION => {
+                let (instruction, _rest) = Self::unpack_u8(rest)?;
+                match instruction {
+                    0 => Self::EnableCpiGuard,
+                    1 => Self::DisableCpiGuard,
+                    _ => Self::Unknown,
+                }
+            },
+            TRANSFER_HOOK_EXTENSION => {
+                let (instruction, rest) = Self::unpack_u8(rest)?;
+                match instruction {
+                    0 => {
+                        let (authority, rest) = Self::unpack_optional_nonzero_pubkey(rest)?;
+                        let (program_id, _rest) = Self::unpack_optional_nonzero_pubkey(rest)?;
+                        Self::InitializeTransferHook { authority, program_id }
+                    },
+                    1 => {
+                        let (program_id, _rest) = Self::unpack_optional_nonzero_pubkey(rest)?;
+                        Self::UpdateTransferHook { program_id }
+                    },
+                    _ => Self::Unknown,
+                }
+            },
+            METADATA_POINTER_EXTENSION => {
+                let (instruction, rest) = Self::unpack_u8(rest)?;
+                match instruction {
+                    0 => {
+                        let (authority, rest) = Self::unpack_optional_nonzero_pubkey(rest)?;
+                        let (metadata_address, _rest) = Self::unpack_optional_nonzero_pubkey(rest)?;
+                        Self::InitializeMetadataPointer { authority, metadata_address }
+                    },
+                    1 => {
+                        let (metadata_address, _rest) = Self::unpack_optional_nonzero_pubkey(rest)?;
+                        Self::UpdateMetadataPointer { metadata_address }
+                    },
+                    _ => Self::Unknown,
+                }
+            },
+            _ => Self::Unknown,
+        })
+    }
+
+    fn unpack_u8(input: &[u8]) -> Result<(u8, &[u8]), &'static str> {
+        let (&value, rest) = input.split_first().ok_or("Invalid instruction data")?;
+        Ok((value, rest))
+    }
+
+    fn unpack_u16(input: &[u8]) -> Result<(u16, &[u8]), &'static str> {
+        if input.len() < 2 {
+            return Err("Invalid instruction data");
+        }
+        let (value, rest) = input.split_at(2);
+        Ok((u16::from_le_bytes([value[0], value[1]]), rest))
+    }
+
+    fn unpack_i16(input: &[u8]) -> Result<(i16, &[u8]), &'static str> {
+        let (value, rest) = Self::unpack_u16(input)?;
+        Ok((value as i16, rest))
+    }
+
+    fn unpack_u64(input: &[u8]) -> Result<(u64, &[u8]), &'static str> {
+        if input.len() < 8 {
+            return Err("Invalid instruction data");
+        }
+        let (value, rest) = input.split_at(8);
+        let value = value
+            .try_into()
+            .map(u64::from_le_bytes)
+            .map_err(|_| "Invalid instruction data")?;
+        Ok((value, rest))
+    }
+
+    fn unpack_pubkey(input: &[u8]) -> Result<(Pubkey, &[u8]), &'static str> {
+        if input.len() < 32 {
+            return Err("Invalid instruction data");
+        }
+        let (key, rest) = input.split_at(32);
+        let key: [u8; 32] = key.try_into().map_err(|_| "Invalid instruction data")?;
+        Ok((Pubkey(key), rest))
+    }
+
+    /// `COption<Pubkey>` as packed by the transfer fee extension: a tag byte, followed by the key if set.
+    fn unpack_pubkey_option(input: &[u8]) -> Result<(Option<Pubkey>, &[u8]), &'static str> {
+        match input.split_first() {
+            Some((&0, rest)) => Ok((None, rest)),
+            Some((&1, rest)) => {
+                let (key, rest) = Self::unpack_pubkey(rest)?;
+                Ok((Some(key), rest))
+            },
+            _ => Err("Invalid instruction data"),
+        }
+    }
+
+    /// `OptionalNonZeroPubkey`: always 32 bytes, the zero key meaning `None`.
+    fn unpack_optional_nonzero_pubkey(input: &[u8]) -> Result<(Option<Pubkey>, &[u8]), &'static str> {
+        let (key, rest) = Self::unpack_pubkey(input)?;
+        if key.0 == [0; 32] {
+            Ok((None, rest))
+        } else {
+            Ok((Some(key), rest))
+        }
+    }
+
+    fn unpack_string(input: &[u8]) -> Result<(String, &[u8]), &'static str> {
+        if input.len() < 4 {
+            return Err("Invalid instruction data");
+        }
+        let (len, rest) = input.split_at(4);
+        let len = u32::from_le_bytes([len[0], len[1], len[2], len[3]]) as usize;
+        if rest.len() < len {
+            return Err("Invalid instruction data");
+        }
+        let (value, rest) = rest.split_at(len);
+        let value = String::from_utf8(value.to_vec()).map_err(|_| "Invalid instruction data")?;
+        Ok((value, rest))
+    }
+
+    fn unpack_token_metadata_field(input: &[u8]) -> Result<(TokenMetadataField, &[u8]), &'static str> {
+        let (tag, rest) = Self::unpack_u8(input)?;
+        match tag {
+            0 => Ok((TokenMetadataField::Name, rest)),
+            1 => Ok((TokenMetadataField::Symbol, rest)),
+            2 => Ok((TokenMetadataField::Uri, rest)),
+            3 => {
+                let (key, rest) = Self::unpack_string(rest)?;
+                Ok((TokenMetadataField::Key(key), rest))
+            },
+            _ => Err("Invalid instruction data"),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod constants;
+pub use constants::TOKEN_2022_PROGRAM_ID;
+pub mod instruction;
+pub mod transfer_fee;
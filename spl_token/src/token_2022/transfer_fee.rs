@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+
+use crate::pb::spl_token::TransferEvent;
+
+/// Balance change of every token account referred to by exactly one Token program instruction account in the
+/// transaction, from its `pre_token_balances` and `post_token_balances`. Changes of accounts referred to more
+/// than once can't be attributed to a single instruction, so they're left out.
+pub fn get_single_instruction_deltas<I: IntoIterator<Item = String>>(
+    transaction: &ConfirmedTransaction,
+    token_instruction_accounts: I,
+) -> HashMap<String, i128> {
+    let mut references: HashMap<String, usize> = HashMap::new();
+    for account in token_instruction_accounts {
+        *references.entry(account).or_default() += 1;
+    }
+
+    let accounts: Vec<String> = transaction
+        .resolved_accounts()
+        .iter()
+        .map(|x| bs58::encode(x).into_string())
+        .collect();
+    let mut deltas: HashMap<String, i128> = HashMap::new();
+    if let Some(meta) = transaction.meta.as_ref() {
+        let balances = meta.pre_token_balances.iter().map(|x| (x, -1)).chain(meta.post_token_balances.iter().map(|x| (x, 1)));
+        for (balance, sign) in balances {
+            let account = match accounts.get(balance.account_index as usize) {
+                Some(account) if references.get(account) == Some(&1) => account,
+                _ => continue,
+            };
+            let amount: u64 = balance.ui_token_amount.as_ref().and_then(|x| x.amount.parse().ok()).unwrap_or(0);
+            *deltas.entry(account.clone()).or_default() += sign * amount as i128;
+        }
+    }
+    deltas
+}
+
+/// Derives the fee of a Token-2022 transfer that doesn't state it from the balance change of its destination,
+/// when that transfer is the only instruction referring to the destination.
+pub fn set_derived_fee(transfer: &mut TransferEvent, single_instruction_deltas: &HashMap<String, i128>) {
+    if transfer.fee_withheld.is_some() {
+        return;
+    }
+    let received = match transfer.destination.as_ref().and_then(|x| single_instruction_deltas.get(&x.address)) {
+        Some(&received) if (0..=transfer.amount as i128).contains(&received) => received,
+        _ => return,
+    };
+    transfer.fee_withheld = Some(transfer.amount - received as u64);
+    transfer.net_amount = Some(received as u64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pb::spl_token::TokenAccount;
+
+    fn transfer(destination: &str, amount: u64) -> TransferEvent {
+        TransferEvent {
+            destination: Some(TokenAccount { address: destination.to_string(), ..Default::default() }),
+            amount,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_set_derived_fee() {
+        let deltas = HashMap::from([("destination".to_string(), 9_900i128)]);
+
+        let mut event = transfer("destination", 10_000);
+        set_derived_fee(&mut event, &deltas);
+        assert_eq!(event.fee_withheld, Some(100));
+        assert_eq!(event.net_amount, Some(9_900));
+
+        // Fees stated by the instruction are kept.
+        let mut event = TransferEvent { fee_withheld: Some(50), net_amount: Some(9_950), ..transfer("destination", 10_000) };
+        set_derived_fee(&mut event, &deltas);
+        assert_eq!(event.fee_withheld, Some(50));
+
+        // Destinations referred to by other instructions, or that received more than the amount, stay unknown.
+        for mut event in [transfer("other", 10_000), transfer("destination", 9_000)] {
+            set_derived_fee(&mut event, &deltas);
+            assert_eq!(event.fee_withheld, None);
+            assert_eq!(event.net_amount, None);
+        }
+    }
+}
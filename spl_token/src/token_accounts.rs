@@ -0,0 +1,96 @@
+use substreams::store::{StoreGetString, StoreNew, StoreSetString};
+
+use crate::fields::{get_field, set_field};
+use crate::pb::spl_token::*;
+use crate::pb::spl_token::spl_token_event::Event;
+
+const MINT: &str = "mint";
+const OWNER: &str = "owner";
+const DELEGATE: &str = "delegate";
+const CLOSE_AUTHORITY: &str = "close_authority";
+const CLOSED: &str = "closed";
+
+/// Token account registry, kept one field per key (see [`crate::fields`]). Unset authorities are
+/// stored as empty strings. Use [`get_token_account`] to read a whole account.
+#[substreams::handlers::store]
+fn store_token_accounts(events: SplTokenBlockEvents, store: StoreSetString) {
+    for transaction in events.transactions.iter() {
+        for event in transaction.events.iter() {
+            match &event.event {
+                Some(Event::InitializeAccount(initialize_account)) => {
+                    if let Some(account) = &initialize_account.account {
+                        set_field(&store, &account.address, MINT, &account.mint);
+                        set_field(&store, &account.address, OWNER, &account.owner);
+                        set_field(&store, &account.address, DELEGATE, "");
+                        set_field(&store, &account.address, CLOSE_AUTHORITY, "");
+                        set_field(&store, &account.address, CLOSED, &false.to_string());
+                    }
+                },
+                Some(Event::SetAuthority(set_authority)) => {
+                    // For account authorities, the `mint` field holds the token account address.
+                    let address = &set_authority.mint;
+                    let new_authority = set_authority.new_authority.clone().unwrap_or_default();
+                    match set_authority.authority_type() {
+                        AuthorityType::AccountOwner => {
+                            // Changing the owner also clears the delegate.
+                            set_field(&store, address, OWNER, &new_authority);
+                            set_field(&store, address, DELEGATE, "");
+                        },
+                        AuthorityType::CloseAccount => {
+                            set_field(&store, address, CLOSE_AUTHORITY, &new_authority);
+                        },
+                        _ => (),
+                    }
+                },
+                Some(Event::Approve(approve)) => {
+                    if let Some(source) = &approve.source {
+                        set_owner_and_mint(&store, source);
+                        set_field(&store, &source.address, DELEGATE, &approve.delegate);
+                    }
+                },
+                Some(Event::Revoke(revoke)) => {
+                    if let Some(source) = &revoke.source {
+                        set_owner_and_mint(&store, source);
+                        set_field(&store, &source.address, DELEGATE, "");
+                    }
+                },
+                Some(Event::CloseAccount(close_account)) => {
+                    if let Some(source) = &close_account.source {
+                        set_owner_and_mint(&store, source);
+                        set_field(&store, &source.address, CLOSED, &true.to_string());
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Reads a token account from `store_token_accounts`, or `None` if it was never seen.
+pub fn get_token_account(store: &StoreGetString, address: &str) -> Option<TokenAccountState> {
+    let mint = get_field(store, address, MINT);
+    let owner = get_field(store, address, OWNER);
+    if mint.is_none() && owner.is_none() {
+        return None;
+    }
+    let non_empty = |value: Option<String>| value.filter(|x| !x.is_empty());
+
+    Some(TokenAccountState {
+        address: address.to_string(),
+        mint: mint.unwrap_or_default(),
+        owner: owner.unwrap_or_default(),
+        delegate: non_empty(get_field(store, address, DELEGATE)),
+        close_authority: non_empty(get_field(store, address, CLOSE_AUTHORITY)),
+        closed: get_field(store, address, CLOSED).is_some_and(|x| x == "true"),
+    })
+}
+
+/// Resolves the owner of a token account, e.g. when the current transaction doesn't describe it.
+pub fn get_token_account_owner(store: &StoreGetString, address: &str) -> Option<String> {
+    get_field(store, address, OWNER).filter(|x| !x.is_empty())
+}
+
+fn set_owner_and_mint(store: &StoreSetString, account: &TokenAccount) {
+    set_field(store, &account.address, MINT, &account.mint);
+    set_field(store, &account.address, OWNER, &account.owner);
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use substreams::errors::Error;
+use substreams::store::{StoreGetString, StoreNew, StoreSetString};
+use spl_token_substream::fields::{get_field, set_field};
+
+use crate::pb::system_program::*;
+use crate::pb::system_program::system_program_event::Event;
+
+const OWNER: &str = "owner";
+const SPACE: &str = "space";
+const CREATED_SLOT: &str = "created_slot";
+
+/// Owner program, space and creation slot of every account created or assigned through the System
+/// program, kept one field per key (see [`spl_token_substream::fields`]). Use [`get_account_owner`]
+/// to read an account.
+#[substreams::handlers::store]
+fn store_account_owners(events: SystemProgramBlockEvents, store: StoreSetString) {
+    let slot = events.slot.to_string();
+    for transaction in events.transactions.iter() {
+        for event in transaction.events.iter() {
+            match &event.event {
+                Some(Event::CreateAccount(create_account)) => {
+                    set_field(&store, &create_account.new_account, OWNER, &create_account.owner);
+                    set_field(&store, &create_account.new_account, SPACE, &create_account.space.to_string());
+                    set_field(&store, &create_account.new_account, CREATED_SLOT, &slot);
+                },
+                Some(Event::CreateAccountWithSeed(create_account)) => {
+                    set_field(&store, &create_account.created_account, OWNER, &create_account.owner);
+                    set_field(&store, &create_account.created_account, SPACE, &create_account.space.to_string());
+                    set_field(&store, &create_account.created_account, CREATED_SLOT, &slot);
+                },
+                // Allocating data to a System account is how already funded accounts, e.g. PDAs, get created.
+                Some(Event::Allocate(allocate)) => {
+                    set_field(&store, &allocate.account, SPACE, &allocate.space.to_string());
+                    set_field(&store, &allocate.account, CREATED_SLOT, &slot);
+                },
+                Some(Event::AllocateWithSeed(allocate)) => {
+                    set_field(&store, &allocate.allocated_account, OWNER, &allocate.owner);
+                    set_field(&store, &allocate.allocated_account, SPACE, &allocate.space.to_string());
+                    set_field(&store, &allocate.allocated_account, CREATED_SLOT, &slot);
+                },
+                Some(Event::Assign(assign)) => {
+                    set_field(&store, &assign.assigned_account, OWNER, &assign.owner);
+                },
+                Some(Event::AssignWithSeed(assign)) => {
+                    set_field(&store, &assign.assigned_account, OWNER, &assign.owner);
+                },
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Accounts created in the block, grouped by owner program.
+///
+/// Accounts created by `Allocate` and `Assign` are counted when both happen in the same transaction.
+/// Their rent was transferred beforehand, so only `CreateAccount` deposits are included in `rent_lamports`.
+#[substreams::handlers::map]
+fn program_account_creations(events: SystemProgramBlockEvents) -> Result<ProgramAccountCreationsBlock, Error> {
+    let mut programs: Vec<ProgramAccountCreations> = Vec::new();
+    let mut add_creation = |owner: &str, space: u64, lamports: u64| {
+        let index = match programs.iter().position(|x| x.owner == owner) {
+            Some(index) => index,
+            None => {
+                programs.push(ProgramAccountCreations { owner: owner.to_string(), ..Default::default() });
+                programs.len() - 1
+            },
+        };
+        let creations = &mut programs[index];
+        creations.accounts_created += 1;
+        creations.space = creations.space.saturating_add(space);
+        creations.rent_lamports = creations.rent_lamports.saturating_add(lamports);
+    };
+
+    for transaction in events.transactions.iter() {
+        // Space allocated to System accounts in the transaction, waiting for their assignment.
+        let mut allocated: HashMap<&str, u64> = HashMap::new();
+        for event in transaction.events.iter() {
+            match &event.event {
+                Some(Event::CreateAccount(create_account)) => {
+                    add_creation(&create_account.owner, create_account.space, create_account.lamports);
+                },
+                Some(Event::CreateAccountWithSeed(create_account)) => {
+                    add_creation(&create_account.owner, create_account.space, create_account.lamports);
+                },
+                Some(Event::AllocateWithSeed(allocate)) => {
+                    add_creation(&allocate.owner, allocate.space, 0);
+                },
+                Some(Event::Allocate(allocate)) => {
+                    allocated.insert(&allocate.account, allocate.space);
+                },
+                Some(Event::Assign(assign)) => {
+                    if let Some(space) = allocated.remove(assign.assigned_account.as_str()) {
+                        add_creation(&assign.owner, space, 0);
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
+    Ok(ProgramAccountCreationsBlock { slot: events.slot, programs })
+}
+
+/// Reads an account from `store_account_owners`, or `None` if it was never seen.
+pub fn get_account_owner(store: &StoreGetString, account: &str) -> Option<AccountOwner> {
+    let owner = get_field(store, account, OWNER);
+    let space = get_field(store, account, SPACE);
+    if owner.is_none() && space.is_none() {
+        return None;
+    }
+
+    Some(AccountOwner {
+        account: account.to_string(),
+        owner: owner.unwrap_or_default(),
+        space: space.and_then(|x| x.parse().ok()),
+        created_slot: get_field(store, account, CREATED_SLOT).and_then(|x| x.parse().ok()),
+    })
+}
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use anyhow::Error;
+use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction};
+
+use substreams_solana_utils as utils;
+use utils::instruction::{get_structured_instructions, StructuredInstructions};
+use utils::transaction::get_context;
+use utils::spl_token::TokenInstruction;
+use utils::system_program::SYSTEM_PROGRAM_ID;
+
+use crate::pb::system_program::*;
+use crate::pb::system_program::system_program_event::Event;
+
+#[substreams::handlers::map]
+fn sol_balance_changes(block: Block) -> Result<SolBlockBalanceChanges, Error> {
+    let mut transactions: Vec<SolTransactionBalanceChanges> = Vec::new();
+    for (i, transaction) in block.transactions.iter().enumerate() {
+        let changes = parse_balance_changes(transaction)?;
+        if !changes.is_empty() {
+            transactions.push(SolTransactionBalanceChanges {
+                signature: utils::transaction::get_signature(transaction),
+                transaction_index: i as u32,
+                changes,
+            });
+        }
+    }
+    Ok(SolBlockBalanceChanges { slot: block.slot, transactions })
+}
+
+/// Parts of an account's lamport delta explained by the decoded transaction.
+#[derive(Default)]
+struct ExplainedDelta {
+    system_transfer: i128,
+    fee: i128,
+    close: i128,
+}
+
+/// Lamport balance changes of a transaction, built from `pre_balances` and `post_balances`, which also
+/// cover the addresses loaded from lookup tables.
+///
+/// Accounts whose balance didn't change are skipped. Each change is tagged with the System transfers,
+/// fee and token account closes that contribute to it, and whether they account for all of it. Anything
+/// else, like programs debiting accounts they own, is left unexplained.
+pub fn parse_balance_changes(transaction: &ConfirmedTransaction) -> Result<Vec<SolBalanceChange>, Error> {
+    let meta = transaction.meta.as_ref().unwrap();
+    let accounts: Vec<String> = transaction
+        .resolved_accounts()
+        .iter()
+        .map(|x| bs58::encode(x).into_string())
+        .collect();
+
+    let mut explained: HashMap<String, ExplainedDelta> = HashMap::new();
+    // Balances as the instructions are replayed, to know how many lamports a close returns.
+    let mut balances: HashMap<String, i128> = accounts
+        .iter()
+        .zip(meta.pre_balances.iter())
+        .map(|(account, balance)| (account.clone(), *balance as i128))
+        .collect();
+
+    // The fee is paid by the first account, even if the transaction failed.
+    if let Some(fee_payer) = accounts.first() {
+        explained.entry(fee_payer.clone()).or_default().fee -= meta.fee as i128;
+        *balances.entry(fee_payer.clone()).or_default() -= meta.fee as i128;
+    }
+
+    if meta.err.is_none() {
+        let context = get_context(transaction)?;
+        let instructions = get_structured_instructions(transaction)?;
+        for instruction in instructions.flattened().iter() {
+            if instruction.program_id() == SYSTEM_PROGRAM_ID {
+                let (source, destination, lamports) = match crate::parse_instruction(instruction, &context) {
+                    Ok(Some(Event::Transfer(transfer))) => (transfer.funding_account, transfer.recipient_account, transfer.lamports),
+                    Ok(Some(Event::TransferWithSeed(transfer))) => (transfer.funding_account, transfer.recipient_account, transfer.lamports),
+                    Ok(Some(Event::CreateAccount(create))) => (create.funding_account, create.new_account, create.lamports),
+                    Ok(Some(Event::CreateAccountWithSeed(create))) => (create.funding_account, create.created_account, create.lamports),
+                    Ok(Some(Event::WithdrawNonceAccount(withdraw))) => (withdraw.nonce_account, withdraw.recipient_account, withdraw.lamports),
+                    _ => continue,
+                };
+                explained.entry(source.clone()).or_default().system_transfer -= lamports as i128;
+                explained.entry(destination.clone()).or_default().system_transfer += lamports as i128;
+                *balances.entry(source).or_default() -= lamports as i128;
+                *balances.entry(destination).or_default() += lamports as i128;
+            } else if spl_token_substream::is_token_program(instruction.program_id()) {
+                if let Ok(TokenInstruction::CloseAccount) = TokenInstruction::unpack(&instruction.data()) {
+                    let closed = instruction.accounts()[0].to_string();
+                    let destination = instruction.accounts()[1].to_string();
+                    let lamports = balances.insert(closed.clone(), 0).unwrap_or(0).max(0);
+                    explained.entry(closed).or_default().close -= lamports;
+                    explained.entry(destination.clone()).or_default().close += lamports;
+                    *balances.entry(destination).or_default() += lamports;
+                }
+            }
+        }
+    }
+
+    let mut changes: Vec<SolBalanceChange> = Vec::new();
+    for (i, account) in accounts.into_iter().enumerate() {
+        let pre_balance = meta.pre_balances.get(i).copied().unwrap_or(0);
+        let post_balance = meta.post_balances.get(i).copied().unwrap_or(0);
+        if pre_balance == post_balance {
+            continue;
+        }
+        let delta = post_balance as i128 - pre_balance as i128;
+
+        let mut causes: Vec<i32> = Vec::new();
+        let mut explained_delta: i128 = 0;
+        if let Some(parts) = explained.get(&account) {
+            for (cause, part) in [
+                (SolBalanceChangeCause::SystemTransfer, parts.system_transfer),
+                (SolBalanceChangeCause::Fee, parts.fee),
+                (SolBalanceChangeCause::Close, parts.close),
+            ] {
+                if part != 0 {
+                    causes.push(cause.into());
+                    explained_delta += part;
+                }
+            }
+        }
+
+        changes.push(SolBalanceChange {
+            account,
+            pre_balance,
+            post_balance,
+            delta: delta as i64,
+            causes,
+            explained: explained_delta == delta,
+        });
+    }
+    Ok(changes)
+}
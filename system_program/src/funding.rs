@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+
+use substreams::errors::Error;
+use substreams::store::{StoreGet, StoreGetProto, StoreNew, StoreSetIfNotExists, StoreSetIfNotExistsProto};
+
+use substreams_solana_utils as utils;
+use utils::system_program::SYSTEM_PROGRAM_ID;
+
+use crate::pb::system_program::*;
+use crate::pb::system_program::system_program_event::Event;
+
+/// Number of funding hops `get_cluster` follows at most. Every hop is a store lookup, so long funding
+/// chains, e.g. wallets that each fund the next one, are cut short and rooted at the last wallet reached.
+pub const MAX_CLUSTER_DEPTH: u32 = 64;
+
+/// First inbound SOL transfer of every wallet, i.e. the first System transfer or system-owned account
+/// creation sending lamports to an account that had none and that the transaction doesn't hand over
+/// to another program.
+#[substreams::handlers::store]
+fn store_wallet_funders(events: SystemProgramBlockEvents, store: StoreSetIfNotExistsProto<WalletFunding>) {
+    for transaction in events.transactions.iter() {
+        let program_accounts = get_program_owned_accounts(&transaction.events);
+        for event in transaction.events.iter() {
+            if let Some(funding) = get_wallet_funding(event, &program_accounts, &transaction.signature, events.slot) {
+                store.set_if_not_exists(0, &funding.wallet, &funding);
+            }
+        }
+    }
+}
+
+/// Cluster of every funded wallet, i.e. the root of its funding tree, resolved once when the wallet
+/// is first funded.
+#[substreams::handlers::store]
+fn store_wallet_clusters(
+    events: SystemProgramBlockEvents,
+    funders: StoreGetProto<WalletFunding>,
+    store: StoreSetIfNotExistsProto<WalletCluster>,
+) {
+    for transaction in events.transactions.iter() {
+        let program_accounts = get_program_owned_accounts(&transaction.events);
+        for event in transaction.events.iter() {
+            if let Some(funding) = get_wallet_funding(event, &program_accounts, &transaction.signature, events.slot) {
+                if is_first_funding(&funders, &funding) {
+                    store.set_if_not_exists(0, &funding.wallet, &get_cluster(&funders, &funding.funder));
+                }
+            }
+        }
+    }
+}
+
+/// Wallets funded for the first time in the block, with the cluster of their funder.
+#[substreams::handlers::map]
+fn wallet_funded(
+    events: SystemProgramBlockEvents,
+    funders: StoreGetProto<WalletFunding>,
+    clusters: StoreGetProto<WalletCluster>,
+) -> Result<WalletFundedBlockEvents, Error> {
+    let mut funded: Vec<WalletFundedEvent> = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    for transaction in events.transactions.iter() {
+        let program_accounts = get_program_owned_accounts(&transaction.events);
+        for event in transaction.events.iter() {
+            let funding = match get_wallet_funding(event, &program_accounts, &transaction.signature, events.slot) {
+                Some(funding) => funding,
+                None => continue,
+            };
+            if !is_first_funding(&funders, &funding) || !seen.insert(funding.wallet.clone()) {
+                continue;
+            }
+
+            let cluster = clusters.get_last(&funding.wallet);
+            funded.push(WalletFundedEvent {
+                cluster_id: cluster.as_ref().map(|x| x.root.clone()),
+                cluster_depth: cluster.as_ref().map_or(0, |x| x.depth),
+                cluster_truncated: cluster.is_some_and(|x| x.truncated),
+                funding: Some(funding),
+            });
+        }
+    }
+    Ok(WalletFundedBlockEvents { slot: events.slot, events: funded })
+}
+
+/// Follows funders from `funder` up to the first wallet without a known funding, i.e. funded before
+/// the start block, which roots the cluster of the wallet `funder` funded. The walk stops after
+/// [`MAX_CLUSTER_DEPTH`] hops, in which case the cluster is flagged as truncated.
+pub fn get_cluster(funders: &StoreGetProto<WalletFunding>, funder: &str) -> WalletCluster {
+    let mut root = funder.to_string();
+    let mut depth = 1;
+    let mut truncated = false;
+    // A wallet is funded by one that already held lamports, so funders can't form a cycle. Guard
+    // against it anyway, e.g. for wallets funding each other in the same transaction.
+    let mut visited: HashSet<String> = HashSet::new();
+    while visited.insert(root.clone()) {
+        let funding = match funders.get_last(&root) {
+            Some(funding) => funding,
+            None => break,
+        };
+        if depth >= MAX_CLUSTER_DEPTH {
+            truncated = true;
+            break;
+        }
+        root = funding.funder;
+        depth += 1;
+    }
+    WalletCluster { root, depth, truncated }
+}
+
+/// Whether `funding` is the one kept by `store_wallet_funders`, i.e. the first of its wallet.
+fn is_first_funding(funders: &StoreGetProto<WalletFunding>, funding: &WalletFunding) -> bool {
+    funders
+        .get_last(&funding.wallet)
+        .is_some_and(|first| first.signature == funding.signature && first.slot == funding.slot)
+}
+
+/// Accounts the transaction creates for, or assigns to, a program other than the System program,
+/// e.g. token accounts of wrapped SOL or PDAs. Token accounts initialized by the transaction are among
+/// them, as an account that held no lamports has to be created or assigned before its initialization.
+fn get_program_owned_accounts(events: &[SystemProgramEvent]) -> HashSet<&str> {
+    let system_program = SYSTEM_PROGRAM_ID.to_string();
+    events
+        .iter()
+        .filter_map(|event| match &event.event {
+            Some(Event::CreateAccount(create_account)) => Some((&create_account.new_account, &create_account.owner)),
+            Some(Event::CreateAccountWithSeed(create_account)) => Some((&create_account.created_account, &create_account.owner)),
+            Some(Event::AllocateWithSeed(allocate)) => Some((&allocate.allocated_account, &allocate.owner)),
+            Some(Event::Assign(assign)) => Some((&assign.assigned_account, &assign.owner)),
+            Some(Event::AssignWithSeed(assign)) => Some((&assign.assigned_account, &assign.owner)),
+            _ => None,
+        })
+        .filter(|(_, owner)| **owner != system_program)
+        .map(|(account, _)| account.as_str())
+        .collect()
+}
+
+fn get_wallet_funding(event: &SystemProgramEvent, program_accounts: &HashSet<&str>, signature: &str, slot: u64) -> Option<WalletFunding> {
+    let (funder, wallet, lamports) = match &event.event {
+        Some(Event::Transfer(transfer)) => {
+            // Only transfers to accounts that held no lamports before the transaction create a wallet.
+            if transfer.recipient_account_balance.as_ref().map_or(true, |x| x.pre_balance != 0) {
+                return None;
+            }
+            (&transfer.funding_account, &transfer.recipient_account, transfer.lamports)
+        },
+        Some(Event::CreateAccount(create_account)) => {
+            (&create_account.funding_account, &create_account.new_account, create_account.lamports)
+        },
+        _ => return None,
+    };
+    if lamports == 0 || funder == wallet || program_accounts.contains(wallet.as_str()) {
+        return None;
+    }
+
+    Some(WalletFunding {
+        wallet: wallet.clone(),
+        funder: funder.clone(),
+        lamports,
+        slot,
+        signature: signature.to_string(),
+    })
+}
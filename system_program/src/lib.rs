@@ -14,6 +14,12 @@ pub mod pb;
 use pb::system_program::*;
 use pb::system_program::system_program_event::Event;
 
+pub mod balance_changes;
+pub mod nonce;
+pub mod funding;
+pub mod whales;
+pub mod accounts;
+
 #[substreams::handlers::map]
 fn system_program_events(block: Block) -> Result<SystemProgramBlockEvents, Error> {
     let transactions = parse_block(&block)?;
@@ -25,10 +31,13 @@ pub fn parse_block(block: &Block) -> Result<Vec<SystemProgramTransactionEvents>,
     for (i, transaction) in block.transactions.iter().enumerate() {
         let events = parse_transaction(transaction)?;
         if !events.is_empty() {
+            let nonce_account = nonce::get_durable_nonce_account(&events);
             block_events.push(SystemProgramTransactionEvents {
                 signature: utils::transaction::get_signature(transaction),
                 transaction_index: i as u32,
                 events,
+                durable_nonce: nonce_account.is_some(),
+                nonce_account,
             });
         }
     }
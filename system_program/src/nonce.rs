@@ -0,0 +1,108 @@
+use sha2::{Digest, Sha256};
+use substreams::store::{StoreGetString, StoreNew, StoreSetString};
+use spl_token_substream::fields::{get_field, set_field};
+use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction};
+
+use crate::pb::system_program::*;
+use crate::pb::system_program::system_program_event::Event;
+
+const AUTHORITY: &str = "authority";
+const DURABLE_NONCE: &str = "durable_nonce";
+const BALANCE: &str = "balance";
+const STATUS: &str = "status";
+
+/// Nonce account registry, kept one field per key (see [`spl_token_substream::fields`]). Use
+/// [`get_nonce_account`] to read a whole account.
+#[substreams::handlers::store]
+fn store_nonce_accounts(block: Block, events: SystemProgramBlockEvents, store: StoreSetString) {
+    // Nonces are derived from the blockhash the bank exposes while processing the block, i.e. its parent's.
+    let durable_nonce = get_durable_nonce(&block.previous_blockhash);
+
+    for transaction_events in events.transactions.iter() {
+        let transaction = match block.transactions.get(transaction_events.transaction_index as usize) {
+            Some(transaction) => transaction,
+            None => continue,
+        };
+        for event in transaction_events.events.iter() {
+            let nonce_account = match &event.event {
+                Some(Event::InitializeNonceAccount(initialize)) => {
+                    set_field(&store, &initialize.nonce_account, AUTHORITY, &initialize.nonce_authority);
+                    set_field(&store, &initialize.nonce_account, STATUS, &NonceAccountStatus::Initialized.as_str_name());
+                    if let Some(durable_nonce) = &durable_nonce {
+                        set_field(&store, &initialize.nonce_account, DURABLE_NONCE, durable_nonce);
+                    }
+                    &initialize.nonce_account
+                },
+                Some(Event::AdvanceNonceAccount(advance)) => {
+                    if let Some(durable_nonce) = &durable_nonce {
+                        set_field(&store, &advance.nonce_account, DURABLE_NONCE, durable_nonce);
+                    }
+                    &advance.nonce_account
+                },
+                Some(Event::AuthorizeNonceAccount(authorize)) => {
+                    set_field(&store, &authorize.nonce_account, AUTHORITY, &authorize.new_nonce_authority);
+                    &authorize.nonce_account
+                },
+                Some(Event::WithdrawNonceAccount(withdraw)) => &withdraw.nonce_account,
+                Some(Event::UpgradeNonceAccount(upgrade)) => &upgrade.nonce_account,
+                _ => continue,
+            };
+
+            if let Some(balance) = get_post_balance(transaction, nonce_account) {
+                set_field(&store, nonce_account, BALANCE, &balance.to_string());
+                // Withdrawing the whole balance deletes the account.
+                if balance == 0 {
+                    set_field(&store, nonce_account, STATUS, &NonceAccountStatus::Closed.as_str_name());
+                }
+            }
+        }
+    }
+}
+
+/// Reads a nonce account from `store_nonce_accounts`, or `None` if it was never seen.
+pub fn get_nonce_account(store: &StoreGetString, address: &str) -> Option<NonceAccount> {
+    let authority = get_field(store, address, AUTHORITY);
+    let balance = get_field(store, address, BALANCE);
+    if authority.is_none() && balance.is_none() {
+        return None;
+    }
+    let status = get_field(store, address, STATUS)
+        .and_then(|x| NonceAccountStatus::from_str_name(&x))
+        .unwrap_or(NonceAccountStatus::Unknown);
+
+    Some(NonceAccount {
+        address: address.to_string(),
+        authority,
+        durable_nonce: get_field(store, address, DURABLE_NONCE),
+        balance: balance.and_then(|x| x.parse().ok()),
+        status: status.into(),
+    })
+}
+
+/// Nonce account advanced by the transaction's first instruction, which makes it a durable nonce transaction.
+pub fn get_durable_nonce_account(events: &[SystemProgramEvent]) -> Option<String> {
+    match events.first() {
+        Some(SystemProgramEvent { instruction_index: 0, event: Some(Event::AdvanceNonceAccount(advance)) }) => {
+            Some(advance.nonce_account.clone())
+        },
+        _ => None,
+    }
+}
+
+/// Durable nonce stored by nonce instructions processed on top of the given blockhash.
+pub fn get_durable_nonce(blockhash: &str) -> Option<String> {
+    let blockhash = bs58::decode(blockhash).into_vec().ok()?;
+    let hash = Sha256::new()
+        .chain_update(b"DURABLE_NONCE")
+        .chain_update(&blockhash)
+        .finalize();
+    Some(bs58::encode(hash).into_string())
+}
+
+fn get_post_balance(transaction: &ConfirmedTransaction, address: &str) -> Option<u64> {
+    let index = transaction
+        .resolved_accounts()
+        .iter()
+        .position(|x| bs58::encode(x).into_string() == address)?;
+    transaction.meta.as_ref()?.post_balances.get(index).copied()
+}
@@ -16,6 +16,47 @@ pub struct SystemProgramTransactionEvents {
     pub transaction_index: u32,
     #[prost(message, repeated, tag="3")]
     pub events: ::prost::alloc::vec::Vec<SystemProgramEvent>,
+    /// Whether the first instruction advances a nonce account, i.e. the transaction uses a durable nonce.
+    #[prost(bool, tag="4")]
+    pub durable_nonce: bool,
+    #[prost(string, optional, tag="5")]
+    pub nonce_account: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SolBlockBalanceChanges {
+    #[prost(uint64, tag="1")]
+    pub slot: u64,
+    #[prost(message, repeated, tag="2")]
+    pub transactions: ::prost::alloc::vec::Vec<SolTransactionBalanceChanges>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SolTransactionBalanceChanges {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub transaction_index: u32,
+    #[prost(message, repeated, tag="3")]
+    pub changes: ::prost::alloc::vec::Vec<SolBalanceChange>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SolBalanceChange {
+    #[prost(string, tag="1")]
+    pub account: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub pre_balance: u64,
+    #[prost(uint64, tag="3")]
+    pub post_balance: u64,
+    #[prost(int64, tag="4")]
+    pub delta: i64,
+    /// Decoded operations contributing to the change.
+    #[prost(enumeration="SolBalanceChangeCause", repeated, tag="5")]
+    pub causes: ::prost::alloc::vec::Vec<i32>,
+    /// Whether the decoded operations account for the whole change.
+    #[prost(bool, tag="6")]
+    pub explained: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -210,6 +251,142 @@ pub struct UpgradeNonceAccountEvent {
     #[prost(string, tag="1")]
     pub nonce_account: ::prost::alloc::string::String,
 }
+/// Nonce account state as tracked by `store_nonce_accounts`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NonceAccount {
+    #[prost(string, tag="1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="3")]
+    pub durable_nonce: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, optional, tag="4")]
+    pub balance: ::core::option::Option<u64>,
+    #[prost(enumeration="NonceAccountStatus", tag="5")]
+    pub status: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WalletFundedBlockEvents {
+    #[prost(uint64, tag="1")]
+    pub slot: u64,
+    #[prost(message, repeated, tag="2")]
+    pub events: ::prost::alloc::vec::Vec<WalletFundedEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WalletFundedEvent {
+    #[prost(message, optional, tag="1")]
+    pub funding: ::core::option::Option<WalletFunding>,
+    /// Root of the funding tree of the wallet, i.e. its furthest known ancestor.
+    #[prost(string, optional, tag="2")]
+    pub cluster_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Number of funding hops between the cluster id and the wallet.
+    #[prost(uint32, tag="3")]
+    pub cluster_depth: u32,
+    /// Whether the funding tree was cut short, see `WalletCluster.truncated`.
+    #[prost(bool, tag="4")]
+    pub cluster_truncated: bool,
+}
+/// First inbound SOL transfer of a wallet, as tracked by `store_wallet_funders`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WalletFunding {
+    #[prost(string, tag="1")]
+    pub wallet: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub funder: ::prost::alloc::string::String,
+    #[prost(uint64, tag="3")]
+    pub lamports: u64,
+    #[prost(uint64, tag="4")]
+    pub slot: u64,
+    #[prost(string, tag="5")]
+    pub signature: ::prost::alloc::string::String,
+}
+/// Cluster of a funded wallet, as tracked by `store_wallet_clusters`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WalletCluster {
+    /// First wallet up the funding tree without a known funding.
+    #[prost(string, tag="1")]
+    pub root: ::prost::alloc::string::String,
+    /// Number of funding hops between the root and the wallet.
+    #[prost(uint32, tag="2")]
+    pub depth: u32,
+    /// Whether the root was reached after the maximum number of hops rather than at a wallet without a
+    /// known funding, in which case it isn't the root of the funding tree.
+    #[prost(bool, tag="3")]
+    pub truncated: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WhaleTransferBlockEvents {
+    #[prost(uint64, tag="1")]
+    pub slot: u64,
+    #[prost(message, repeated, tag="2")]
+    pub transfers: ::prost::alloc::vec::Vec<WhaleTransfer>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WhaleTransfer {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(enumeration="WhaleTransferKind", tag="2")]
+    pub kind: i32,
+    /// Unset for SOL transfers.
+    #[prost(string, optional, tag="3")]
+    pub mint: ::core::option::Option<::prost::alloc::string::String>,
+    /// Wallets sending and receiving the amount, i.e. owners of the token accounts for tokens.
+    #[prost(string, optional, tag="4")]
+    pub source: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="5")]
+    pub destination: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="6")]
+    pub source_account: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="7")]
+    pub destination_account: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, tag="8")]
+    pub amount: u64,
+    #[prost(uint32, tag="9")]
+    pub decimals: u32,
+    #[prost(string, tag="10")]
+    pub ui_amount: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProgramAccountCreationsBlock {
+    #[prost(uint64, tag="1")]
+    pub slot: u64,
+    #[prost(message, repeated, tag="2")]
+    pub programs: ::prost::alloc::vec::Vec<ProgramAccountCreations>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProgramAccountCreations {
+    #[prost(string, tag="1")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub accounts_created: u32,
+    #[prost(uint64, tag="3")]
+    pub space: u64,
+    /// Lamports deposited by `CreateAccount` instructions.
+    #[prost(uint64, tag="4")]
+    pub rent_lamports: u64,
+}
+/// Account ownership as tracked by `store_account_owners`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountOwner {
+    #[prost(string, tag="1")]
+    pub account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag="3")]
+    pub space: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="4")]
+    pub created_slot: ::core::option::Option<u64>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AccountBalance {
@@ -218,4 +395,94 @@ pub struct AccountBalance {
     #[prost(uint64, tag="2")]
     pub post_balance: u64,
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SolBalanceChangeCause {
+    SystemTransfer = 0,
+    Fee = 1,
+    Close = 2,
+}
+impl SolBalanceChangeCause {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SolBalanceChangeCause::SystemTransfer => "SystemTransfer",
+            SolBalanceChangeCause::Fee => "Fee",
+            SolBalanceChangeCause::Close => "Close",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SystemTransfer" => Some(Self::SystemTransfer),
+            "Fee" => Some(Self::Fee),
+            "Close" => Some(Self::Close),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NonceAccountStatus {
+    Unknown = 0,
+    Initialized = 1,
+    Closed = 2,
+}
+impl NonceAccountStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            NonceAccountStatus::Unknown => "Unknown",
+            NonceAccountStatus::Initialized => "Initialized",
+            NonceAccountStatus::Closed => "Closed",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Unknown" => Some(Self::Unknown),
+            "Initialized" => Some(Self::Initialized),
+            "Closed" => Some(Self::Closed),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum WhaleTransferKind {
+    SolTransfer = 0,
+    TokenTransfer = 1,
+    TokenMint = 2,
+    TokenBurn = 3,
+}
+impl WhaleTransferKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            WhaleTransferKind::SolTransfer => "SolTransfer",
+            WhaleTransferKind::TokenTransfer => "TokenTransfer",
+            WhaleTransferKind::TokenMint => "TokenMint",
+            WhaleTransferKind::TokenBurn => "TokenBurn",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SolTransfer" => Some(Self::SolTransfer),
+            "TokenTransfer" => Some(Self::TokenTransfer),
+            "TokenMint" => Some(Self::TokenMint),
+            "TokenBurn" => Some(Self::TokenBurn),
+            _ => None,
+        }
+    }
+}
 // @@protoc_insertion_point(module)
@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Error};
+use substreams::store::{StoreGetInt64, StoreGetString};
+
+use spl_token_substream::decimals::{get_stored_decimals, to_ui_amount, SOL_DECIMALS};
+use spl_token_substream::pb::spl_token::{SplTokenBlockEvents, TokenAccount};
+use spl_token_substream::pb::spl_token::spl_token_event::Event as SplTokenEvent;
+use spl_token_substream::token_accounts::get_token_account_owner;
+
+use crate::pb::system_program::*;
+use crate::pb::system_program::system_program_event::Event;
+
+/// Thresholds of `whale_transfers`, in decimal-adjusted amounts.
+#[derive(Default)]
+pub struct WhaleThresholds {
+    pub sol: Option<f64>,
+    pub mints: HashMap<String, f64>,
+}
+
+impl WhaleThresholds {
+    /// Parses `sol=<amount>&<mint>=<amount>&...`, e.g. `sol=1000&EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v=1000000`.
+    pub fn parse(params: &str) -> Result<Self, Error> {
+        let mut thresholds = Self::default();
+        for param in params.split('&').map(|x| x.trim()).filter(|x| !x.is_empty()) {
+            let (key, value) = param
+                .split_once('=')
+                .ok_or_else(|| anyhow!("Invalid whale threshold {}", param))?;
+            let value: f64 = value.parse().map_err(|_| anyhow!("Invalid whale threshold {}", param))?;
+            match key {
+                "sol" => thresholds.sol = Some(value),
+                mint => {
+                    thresholds.mints.insert(mint.to_string(), value);
+                },
+            }
+        }
+        Ok(thresholds)
+    }
+
+    fn exceeds(&self, mint: Option<&str>, amount: u64, decimals: u32) -> bool {
+        let threshold = match mint {
+            None => self.sol,
+            Some(mint) => self.mints.get(mint).copied(),
+        };
+        threshold.is_some_and(|x| amount as f64 / 10f64.powi(decimals as i32) >= x)
+    }
+}
+
+/// System transfers, and token transfers, mints and burns, whose amount reaches the threshold of their
+/// asset given as parameter. Assets without a threshold are ignored.
+#[substreams::handlers::map]
+fn whale_transfers(
+    params: String,
+    events: SystemProgramBlockEvents,
+    token_events: SplTokenBlockEvents,
+    token_accounts: StoreGetString,
+    mint_decimals: StoreGetInt64,
+) -> Result<WhaleTransferBlockEvents, Error> {
+    let thresholds = WhaleThresholds::parse(&params)?;
+
+    let mut transfers: Vec<WhaleTransfer> = Vec::new();
+    if thresholds.sol.is_some() {
+        for transaction in events.transactions.iter() {
+            for event in transaction.events.iter() {
+                let (source, destination, lamports) = match &event.event {
+                    Some(Event::Transfer(transfer)) => (&transfer.funding_account, &transfer.recipient_account, transfer.lamports),
+                    Some(Event::TransferWithSeed(transfer)) => (&transfer.funding_account, &transfer.recipient_account, transfer.lamports),
+                    _ => continue,
+                };
+                if thresholds.exceeds(None, lamports, SOL_DECIMALS) {
+                    transfers.push(WhaleTransfer {
+                        signature: transaction.signature.clone(),
+                        kind: WhaleTransferKind::SolTransfer.into(),
+                        mint: None,
+                        source: Some(source.clone()),
+                        destination: Some(destination.clone()),
+                        source_account: None,
+                        destination_account: None,
+                        amount: lamports,
+                        decimals: SOL_DECIMALS,
+                        ui_amount: to_ui_amount(lamports, SOL_DECIMALS),
+                    });
+                }
+            }
+        }
+    }
+
+    if !thresholds.mints.is_empty() {
+        for transaction in token_events.transactions.iter() {
+            for event in transaction.events.iter() {
+                let (kind, mint, source, destination, amount, decimals) = match &event.event {
+                    Some(SplTokenEvent::Transfer(transfer)) => {
+                        let mint = transfer.source.as_ref().map(|x| x.mint.as_str()).unwrap_or_default();
+                        (WhaleTransferKind::TokenTransfer, mint, transfer.source.as_ref(), transfer.destination.as_ref(), transfer.amount, transfer.decimals)
+                    },
+                    Some(SplTokenEvent::MintTo(mint_to)) => {
+                        (WhaleTransferKind::TokenMint, mint_to.mint.as_str(), None, mint_to.destination.as_ref(), mint_to.amount, mint_to.decimals)
+                    },
+                    Some(SplTokenEvent::Burn(burn)) => {
+                        let mint = burn.source.as_ref().map(|x| x.mint.as_str()).unwrap_or_default();
+                        (WhaleTransferKind::TokenBurn, mint, burn.source.as_ref(), None, burn.amount, burn.decimals)
+                    },
+                    _ => continue,
+                };
+                // Amounts can't be compared to thresholds without the mint decimals.
+                let decimals = match decimals.or_else(|| get_stored_decimals(&mint_decimals, mint)) {
+                    Some(decimals) => decimals,
+                    None => continue,
+                };
+                if thresholds.exceeds(Some(mint), amount, decimals) {
+                    transfers.push(WhaleTransfer {
+                        signature: transaction.signature.clone(),
+                        kind: kind.into(),
+                        mint: Some(mint.to_string()),
+                        source: source.and_then(|x| get_owner(&token_accounts, x)),
+                        destination: destination.and_then(|x| get_owner(&token_accounts, x)),
+                        source_account: source.map(|x| x.address.clone()),
+                        destination_account: destination.map(|x| x.address.clone()),
+                        amount,
+                        decimals,
+                        ui_amount: to_ui_amount(amount, decimals),
+                    });
+                }
+            }
+        }
+    }
+    Ok(WhaleTransferBlockEvents { slot: events.slot, transfers })
+}
+
+/// Owner of a token account, from the event or else from `store_token_accounts`.
+fn get_owner(token_accounts: &StoreGetString, account: &TokenAccount) -> Option<String> {
+    if account.owner.is_empty() {
+        get_token_account_owner(token_accounts, &account.address)
+    } else {
+        Some(account.owner.clone())
+    }
+}